@@ -1,4 +1,9 @@
-use crate::{AsGdRes, AsGdResArray, ExtractGd};
+use godot::prelude::*;
+
+use crate::{
+    AsGdRes, AsGdResArray, AsGdResDictElement, AsGdResPacked, ExtractContext, ExtractError,
+    ExtractGd, ToGdRes, ToGdResArray, TryExtractGd, apply_by_value, impl_from_gd_res_by_value,
+};
 
 //////////////
 // impls for core copyable types
@@ -15,6 +20,12 @@ macro_rules! impl_extract_gd_copyable {
                     *self
                 }
             }
+
+            impl TryExtractGd for $t {
+                fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+                    Ok(*self)
+                }
+            }
         )*
     };
     () => {
@@ -26,12 +37,50 @@ impl_extract_gd_copyable! {
     i8, i16, i32, i64,
     u8, u16, u32,
     f32, f64,
-    bool
+    bool,
+    Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
+    Color, Rect2, Rect2i, Transform2D, Transform3D, Basis, Quaternion, Aabb, Plane, Projection
+}
+
+/// `Vec`s of vectors and colors use the matching `Packed*Array` on the Godot side,
+/// as do `#[as_gd_res(packed)]` fields of numbers. Extraction copies the whole
+/// slice at once.
+macro_rules! impl_extract_gd_packed_array {
+    ($($array:ty => $t:ty),*) => {
+        $(
+            impl ExtractGd for $array {
+                type Extracted = Vec<$t>;
+                fn extract(&self) -> Self::Extracted {
+                    self.to_vec()
+                }
+            }
+
+            impl TryExtractGd for $array {
+                fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+                    Ok(self.to_vec())
+                }
+            }
+        )*
+    };
 }
 
-/// NOTE: Option<$t> is not supported for numeric types, so not implemented here.
+impl_extract_gd_packed_array! {
+    PackedByteArray => u8,
+    PackedInt32Array => i32,
+    PackedInt64Array => i64,
+    PackedFloat32Array => f32,
+    PackedFloat64Array => f64,
+    PackedVector2Array => Vector2,
+    PackedVector3Array => Vector3,
+    PackedVector4Array => Vector4,
+    PackedColorArray => Color
+}
+
+/// `Option<$t>` of numbers and `bool`s is exported as a toggle plus a value by
+/// `#[derive(AsGdRes)]` (see [`crate::to_gd_toggle`]). Builtins like `Vector2` don't
+/// support `Option`.
 macro_rules! impl_as_res_gd_for_copyable {
-    ($($t:ty),*) => {
+    ($($t:ty => $array:ty),*) => {
         $(
             impl AsGdRes for $t
             where
@@ -44,12 +93,56 @@ macro_rules! impl_as_res_gd_for_copyable {
             where
                 $t: Copy,
             {
-                type GdArray = ::godot::prelude::Array<$t>;
+                type GdArray = $array;
+            }
+
+            impl ToGdRes for $t {
+                fn to_gd_res(&self) -> Self::ResType {
+                    *self
+                }
+
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    apply_by_value(res, *self)
+                }
+            }
+
+            impl ToGdResArray for $t {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    values.into_iter().copied().collect()
+                }
+
+                fn apply_to_gd_array<'a>(
+                    values: impl IntoIterator<Item = &'a Self>,
+                    res: &mut Self::GdArray,
+                ) -> bool {
+                    apply_by_value(res, Self::to_gd_array(values))
+                }
             }
+
+            impl AsGdResDictElement for $t {
+                type GdElement = Self;
+
+                fn to_gd_element(&self) -> Self::GdElement {
+                    *self
+                }
+
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    _ctx: &mut ExtractContext,
+                ) -> Result<Self, ExtractError> {
+                    Ok(*element)
+                }
+
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    apply_by_value(res, *self)
+                }
+            }
+
+            impl_from_gd_res_by_value!($t);
         )*
     };
-    () => {
-
+    ($($t:ty),*) => {
+        impl_as_res_gd_for_copyable!($($t => Array<$t>),*);
     };
 }
 
@@ -57,5 +150,40 @@ impl_as_res_gd_for_copyable! {
     i8, i16, i32, i64,
     u8, u16, u32,
     f32, f64,
-    bool
+    bool,
+    Vector2i, Vector3i, Vector4i,
+    Rect2, Rect2i, Transform2D, Transform3D, Basis, Quaternion, Aabb, Plane, Projection
+}
+
+impl_as_res_gd_for_copyable! {
+    Vector2 => PackedVector2Array,
+    Vector3 => PackedVector3Array,
+    Vector4 => PackedVector4Array,
+    Color => PackedColorArray
+}
+
+macro_rules! impl_as_gd_res_packed {
+    ($($t:ty => $array:ty),*) => {
+        $(
+            impl AsGdResPacked for $t {
+                type GdPacked = $array;
+
+                fn to_gd_packed(values: &[Self]) -> Self::GdPacked {
+                    values.iter().copied().collect()
+                }
+
+                fn from_gd_packed(res: &Self::GdPacked) -> Vec<Self> {
+                    res.to_vec()
+                }
+            }
+        )*
+    };
+}
+
+impl_as_gd_res_packed! {
+    u8 => PackedByteArray,
+    i32 => PackedInt32Array,
+    i64 => PackedInt64Array,
+    f32 => PackedFloat32Array,
+    f64 => PackedFloat64Array
 }
@@ -1,17 +1,47 @@
+#[cfg(feature = "godot")]
 use std::str::FromStr;
 
-use crate::{AsGdRes, AsGdResArray, AsGdResOpt, ExtractGd, impl_wrapped_builtin_as_gd_res};
+#[cfg(feature = "godot")]
+use crate::{
+    AsGdRes, AsGdResArray, AsGdResDictElement, AsGdResOpt, ExtractContext, ExtractError, ExtractGd,
+    ToGdRes, ToGdResArray, ToGdResOpt, TryExtractGd, apply_by_value, impl_from_gd_res_by_value,
+    impl_wrapped_builtin_as_gd_res,
+};
 
-use crate::impl_wrapped_as_gd_res;
+#[cfg(feature = "godot")]
+use crate::{
+    impl_from_gd_res_by_element, impl_wrapped_as_gd_res, impl_wrapped_dict_element,
+    impl_wrapped_to_gd_res,
+};
+#[cfg(feature = "godot")]
 use godot::classes::Curve;
+#[cfg(feature = "godot")]
 use godot::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "easy_hash", derive(easy_hash::EasyHash))]
 pub struct PackedScenePath(pub String);
 
+#[cfg(feature = "godot")]
 impl_wrapped_as_gd_res!(PackedScenePath, PackedScene);
+#[cfg(feature = "godot")]
+impl_wrapped_to_gd_res!(PackedScenePath, PackedScenePath::load);
+#[cfg(feature = "godot")]
+impl_wrapped_dict_element!(PackedScenePath, PackedScene, PackedScenePath::load);
+#[cfg(feature = "godot")]
+impl_from_gd_res_by_element!(PackedScenePath);
 
+#[cfg(feature = "godot")]
+impl PackedScenePath {
+    /// Loads the scene at this path.
+    ///
+    /// Panics if the scene cannot be loaded.
+    pub fn load(&self) -> Gd<PackedScene> {
+        load::<PackedScene>(&self.0)
+    }
+}
+
+#[cfg(feature = "godot")]
 impl ExtractGd for Gd<PackedScene> {
     type Extracted = PackedScenePath;
     fn extract(&self) -> Self::Extracted {
@@ -20,9 +50,16 @@ impl ExtractGd for Gd<PackedScene> {
     }
 }
 
+#[cfg(feature = "godot")]
+impl TryExtractGd for Gd<PackedScene> {
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        Ok(self.extract())
+    }
+}
+
 const CURVE_SAMPLE_POINTS: usize = 64;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "easy_hash", derive(easy_hash::EasyHash))]
 pub struct RustCurve {
     baked: [f32; CURVE_SAMPLE_POINTS],
@@ -68,6 +105,24 @@ impl RustCurve {
         self.min
     }
 
+    /// Bakes a curve by calling `sample` at evenly spaced offsets in `[0, 1]`.
+    pub(crate) fn from_sample_fn(min: f32, max: f32, sample: impl Fn(f32) -> f32) -> Self {
+        let mut baked = [0.0; CURVE_SAMPLE_POINTS];
+        let mut integral = 0.0;
+        let dx = 1.0 / (CURVE_SAMPLE_POINTS as f32 - 1.0);
+        for (i, slot) in baked.iter_mut().enumerate() {
+            let y = sample(i as f32 / (CURVE_SAMPLE_POINTS as f32 - 1.0));
+            *slot = y;
+            integral += y * dx;
+        }
+        RustCurve {
+            baked,
+            integral,
+            max,
+            min,
+        }
+    }
+
     /// Creates a new RustCurve for testing purposes.
     /// This constructor is only available in test builds.
     #[cfg(test)]
@@ -86,31 +141,139 @@ impl RustCurve {
     }
 }
 
+#[cfg(feature = "godot")]
 impl_wrapped_as_gd_res!(RustCurve, Curve);
+#[cfg(feature = "godot")]
+impl_wrapped_to_gd_res!(RustCurve, RustCurve::to_curve);
+#[cfg(feature = "godot")]
+impl_wrapped_dict_element!(RustCurve, Curve, RustCurve::to_curve);
+#[cfg(feature = "godot")]
+impl_from_gd_res_by_element!(RustCurve);
 
+#[cfg(feature = "godot")]
+impl RustCurve {
+    /// Builds a Godot `Curve` with one point per baked sample, spread evenly over
+    /// the domain, with linear tangents between them.
+    pub fn to_curve(&self) -> Gd<Curve> {
+        let mut curve = Curve::new_gd();
+        curve.set_min_domain(self.min);
+        curve.set_max_domain(self.max);
+        // Points outside the value range would be clamped by `add_point`.
+        let (lo, hi) = self.value_range();
+        curve.set_min_value(lo);
+        curve.set_max_value(hi);
+
+        for (i, (x, y, left, right)) in self.curve_points().into_iter().enumerate() {
+            curve.add_point(Vector2::new(x, y));
+            curve.set_point_left_tangent(i as i32, left);
+            curve.set_point_right_tangent(i as i32, right);
+        }
+        curve
+    }
+}
+
+impl RustCurve {
+    /// The smallest value range, containing `[0, 1]`, that holds all baked samples.
+    pub(crate) fn value_range(&self) -> (f32, f32) {
+        self.baked
+            .iter()
+            .fold((0.0f32, 1.0f32), |(lo, hi), y| (lo.min(*y), hi.max(*y)))
+    }
+
+    /// The points of the curve built by [`RustCurve::to_curve`], as
+    /// `(x, y, left_tangent, right_tangent)`.
+    pub(crate) fn curve_points(&self) -> Vec<(f32, f32, f32, f32)> {
+        let dx = (self.max - self.min) / (CURVE_SAMPLE_POINTS as f32 - 1.0);
+        let slope = |a: usize, b: usize| {
+            if dx > 0.0 {
+                (self.baked[b] - self.baked[a]) / dx
+            } else {
+                0.0
+            }
+        };
+        (0..CURVE_SAMPLE_POINTS)
+            .map(|i| {
+                let left = if i > 0 { slope(i - 1, i) } else { 0.0 };
+                let right = if i + 1 < CURVE_SAMPLE_POINTS {
+                    slope(i, i + 1)
+                } else {
+                    0.0
+                };
+                (self.min + i as f32 * dx, self.baked[i], left, right)
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "godot")]
 impl ExtractGd for Gd<Curve> {
     type Extracted = RustCurve;
 
     fn extract(&self) -> Self::Extracted {
-        let mut baked = [0.0; CURVE_SAMPLE_POINTS];
-        let mut integral = 0.0;
-        let dx = 1.0 / (CURVE_SAMPLE_POINTS as f32 - 1.0);
-        for i in 0..CURVE_SAMPLE_POINTS {
-            let y = self.sample(i as f32 / (CURVE_SAMPLE_POINTS as f32 - 1.0));
-            baked[i] = y;
-            integral += y * dx;
-        }
-        RustCurve {
-            baked,
-            integral,
-            max: self.get_max_domain(),
-            min: self.get_min_domain(),
-        }
+        RustCurve::from_sample_fn(self.get_min_domain(), self.get_max_domain(), |t| {
+            self.sample(t)
+        })
+    }
+}
+
+#[cfg(feature = "godot")]
+impl TryExtractGd for Gd<Curve> {
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        Ok(self.extract())
     }
 }
 
+#[cfg(feature = "godot")]
 impl_wrapped_builtin_as_gd_res!(String, GString);
+#[cfg(feature = "godot")]
+impl_from_gd_res_by_value!(String);
 
+#[cfg(feature = "godot")]
+impl ToGdRes for String {
+    fn to_gd_res(&self) -> Self::ResType {
+        GString::from(self.as_str())
+    }
+
+    fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+        apply_by_value(res, self.to_gd_res())
+    }
+}
+
+#[cfg(feature = "godot")]
+impl ToGdResArray for String {
+    fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+        values.into_iter().map(|v| v.to_gd_res()).collect()
+    }
+
+    fn apply_to_gd_array<'a>(
+        values: impl IntoIterator<Item = &'a Self>,
+        res: &mut Self::GdArray,
+    ) -> bool {
+        apply_by_value(res, Self::to_gd_array(values))
+    }
+}
+
+#[cfg(feature = "godot")]
+impl AsGdResDictElement for String {
+    type GdElement = GString;
+
+    fn to_gd_element(&self) -> Self::GdElement {
+        self.to_gd_res()
+    }
+
+    fn try_from_gd_element(
+        element: &Self::GdElement,
+        _ctx: &mut ExtractContext,
+    ) -> Result<Self, ExtractError> {
+        Ok(element.extract())
+    }
+
+    fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+        apply_by_value(res, self.to_gd_res())
+    }
+}
+
+#[cfg(feature = "godot")]
 impl ExtractGd for GString {
     type Extracted = String;
     fn extract(&self) -> Self::Extracted {
@@ -118,11 +281,23 @@ impl ExtractGd for GString {
     }
 }
 
-#[derive(Clone, Debug)]
+#[cfg(feature = "godot")]
+impl TryExtractGd for GString {
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        Ok(self.extract())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "easy_hash", derive(easy_hash::EasyHash))]
 pub struct NodePathString(pub String);
+
+#[cfg(feature = "godot")]
 impl_wrapped_builtin_as_gd_res!(NodePathString, NodePath);
+#[cfg(feature = "godot")]
+impl_from_gd_res_by_value!(NodePathString);
 
+#[cfg(feature = "godot")]
 impl NodePathString {
     pub fn to_node_path(&self) -> NodePath {
         NodePath::from_str(&self.0).unwrap()
@@ -130,9 +305,62 @@ impl NodePathString {
     }
 }
 
+#[cfg(feature = "godot")]
+impl ToGdRes for NodePathString {
+    fn to_gd_res(&self) -> Self::ResType {
+        self.to_node_path()
+    }
+
+    fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+        apply_by_value(res, self.to_gd_res())
+    }
+}
+
+#[cfg(feature = "godot")]
+impl ToGdResArray for NodePathString {
+    fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+        values.into_iter().map(|v| v.to_node_path()).collect()
+    }
+
+    fn apply_to_gd_array<'a>(
+        values: impl IntoIterator<Item = &'a Self>,
+        res: &mut Self::GdArray,
+    ) -> bool {
+        apply_by_value(res, Self::to_gd_array(values))
+    }
+}
+
+#[cfg(feature = "godot")]
+impl AsGdResDictElement for NodePathString {
+    type GdElement = NodePath;
+
+    fn to_gd_element(&self) -> Self::GdElement {
+        self.to_node_path()
+    }
+
+    fn try_from_gd_element(
+        element: &Self::GdElement,
+        _ctx: &mut ExtractContext,
+    ) -> Result<Self, ExtractError> {
+        Ok(element.extract())
+    }
+
+    fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+        apply_by_value(res, self.to_node_path())
+    }
+}
+
+#[cfg(feature = "godot")]
 impl ExtractGd for NodePath {
     type Extracted = NodePathString;
     fn extract(&self) -> Self::Extracted {
         NodePathString(self.to_string())
     }
 }
+
+#[cfg(feature = "godot")]
+impl TryExtractGd for NodePath {
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        Ok(self.extract())
+    }
+}
@@ -0,0 +1,68 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use godot::obj::InstanceId;
+
+use crate::ExtractError;
+
+/// State for one extraction of a resource graph.
+///
+/// The context detects cycles, which are reported as [`ExtractError::cycle`]
+/// instead of overflowing the stack. It also makes every `Arc<T>` field
+/// extracted from the same resource share one allocation. Reuse a context across
+/// several extractions to share sub-resources between all of them:
+///
+/// ```ignore
+/// let mut ctx = ExtractContext::new();
+/// let items: Vec<Arc<Item>> = resources
+///     .iter()
+///     .map(|res| FromGdRes::try_from_gd_res_with(res, &mut ctx))
+///     .collect::<Result<_, _>>()?;
+/// ```
+#[derive(Default)]
+pub struct ExtractContext {
+    // Resources currently being extracted, outermost first.
+    visiting: Vec<InstanceId>,
+    // The `Arc<T>` extracted for each resource, keyed by the `TypeId` of `T`.
+    shared: HashMap<(InstanceId, TypeId), Box<dyn Any>>,
+}
+
+impl ExtractContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `extract` for the object `id`. Fails if `id` is already being
+    /// extracted further up, i.e. the object contains itself.
+    pub fn visit<T>(
+        &mut self,
+        id: InstanceId,
+        extract: impl FnOnce(&mut Self) -> Result<T, ExtractError>,
+    ) -> Result<T, ExtractError> {
+        if self.visiting.contains(&id) {
+            return Err(ExtractError::cycle());
+        }
+        self.visiting.push(id);
+        let extracted = extract(self);
+        self.visiting.pop();
+        extracted
+    }
+
+    /// Returns the `Arc` previously extracted for the object `id`, or runs
+    /// `extract` and keeps the result for later calls.
+    pub fn share<T: 'static>(
+        &mut self,
+        id: InstanceId,
+        extract: impl FnOnce(&mut Self) -> Result<T, ExtractError>,
+    ) -> Result<Arc<T>, ExtractError> {
+        let key = (id, TypeId::of::<T>());
+        if let Some(shared) = self.shared.get(&key) {
+            let shared = shared.downcast_ref::<Arc<T>>().expect("keyed by type");
+            return Ok(shared.clone());
+        }
+        let shared = Arc::new(extract(self)?);
+        self.shared.insert(key, Box::new(shared.clone()));
+        Ok(shared)
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+
+/// One step of the field path recorded in an [`ExtractError`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A named field of a `#[derive(AsGdRes)]` struct, e.g. `array_simple`
+    Field(String),
+    /// An element of an array, e.g. `[2]`
+    Index(usize),
+    /// The value for a key of a dictionary, e.g. `[fire]`
+    Key(String),
+}
+
+/// What went wrong at the innermost point of a failed extraction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExtractErrorKind {
+    /// An `OnEditor<_>` value was never set in the editor.
+    Unset,
+    /// An array contained a null (or otherwise unconvertible) element.
+    NullElement,
+    /// A resource contains itself, directly or through other resources.
+    Cycle,
+    /// Any other reason, described by the message.
+    Invalid(String),
+}
+
+impl fmt::Display for ExtractErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtractErrorKind::Unset => write!(f, "required value is not set"),
+            ExtractErrorKind::NullElement => write!(f, "array element is null"),
+            ExtractErrorKind::Cycle => write!(f, "resource contains itself"),
+            ExtractErrorKind::Invalid(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// Error returned by [`crate::TryExtractGd::try_extract`].
+///
+/// Errors are created at the value that failed and are annotated while they
+/// bubble up through the resource tree, so by the time they reach the caller
+/// they carry the full field path (e.g. `Complicated.array_simple[2].path_to_node`)
+/// and the `res://` path of the innermost saved resource containing the failure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtractError {
+    kind: ExtractErrorKind,
+    type_name: Option<String>,
+    // Stored innermost-first, since segments are added while unwinding.
+    path_rev: Vec<PathSegment>,
+    resource_path: Option<String>,
+}
+
+impl ExtractError {
+    pub fn new(kind: ExtractErrorKind) -> Self {
+        Self {
+            kind,
+            type_name: None,
+            path_rev: Vec::new(),
+            resource_path: None,
+        }
+    }
+
+    pub fn unset() -> Self {
+        Self::new(ExtractErrorKind::Unset)
+    }
+
+    pub fn null_element() -> Self {
+        Self::new(ExtractErrorKind::NullElement)
+    }
+
+    pub fn cycle() -> Self {
+        Self::new(ExtractErrorKind::Cycle)
+    }
+
+    pub fn invalid(msg: impl Into<String>) -> Self {
+        Self::new(ExtractErrorKind::Invalid(msg.into()))
+    }
+
+    /// Prepends a field name to the path.
+    pub fn in_field(mut self, field: &str) -> Self {
+        self.path_rev.push(PathSegment::Field(field.to_string()));
+        self
+    }
+
+    /// Prepends an array index to the path.
+    pub fn at_index(mut self, index: usize) -> Self {
+        self.path_rev.push(PathSegment::Index(index));
+        self
+    }
+
+    /// Prepends a dictionary key to the path.
+    pub fn at_key(mut self, key: &str) -> Self {
+        self.path_rev.push(PathSegment::Key(key.to_string()));
+        self
+    }
+
+    /// Records that the error happened inside a resource for the Rust type `type_name`.
+    ///
+    /// The type name is overwritten at every level, so the outermost type ends up as
+    /// the root of the path. The resource path is only kept from the innermost
+    /// resource that has one (resources created in code have an empty path).
+    pub fn in_resource(mut self, type_name: &str, resource_path: &str) -> Self {
+        self.type_name = Some(type_name.to_string());
+        if self.resource_path.is_none() && !resource_path.is_empty() {
+            self.resource_path = Some(resource_path.to_string());
+        }
+        self
+    }
+
+    pub fn kind(&self) -> &ExtractErrorKind {
+        &self.kind
+    }
+
+    /// The name of the outermost Rust type that was being extracted, if known.
+    pub fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
+
+    /// The `res://` path of the resource in which extraction failed, if it was saved to disk.
+    pub fn resource_path(&self) -> Option<&str> {
+        self.resource_path.as_deref()
+    }
+
+    /// Path segments from the outermost to the innermost value.
+    pub fn segments(&self) -> impl Iterator<Item = &PathSegment> {
+        self.path_rev.iter().rev()
+    }
+
+    /// The full field path, e.g. `Complicated.array_simple[2].path_to_node`.
+    pub fn field_path(&self) -> String {
+        let mut out = self.type_name.clone().unwrap_or_default();
+        for segment in self.segments() {
+            match segment {
+                PathSegment::Field(name) => {
+                    if !out.is_empty() {
+                        out.push('.');
+                    }
+                    out.push_str(name);
+                }
+                PathSegment::Index(i) => out.push_str(&format!("[{}]", i)),
+                PathSegment::Key(key) => out.push_str(&format!("[{}]", key)),
+            }
+        }
+        out
+    }
+}
+
+impl fmt::Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to extract `{}`", self.field_path())?;
+        if let Some(path) = &self.resource_path {
+            write!(f, " (in {})", path)?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+impl std::error::Error for ExtractError {}
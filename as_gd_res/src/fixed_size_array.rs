@@ -0,0 +1,22 @@
+//! Length handling for `[T; N]` fields, which are stored as a Godot array of any
+//! length.
+
+use crate::ExtractError;
+
+/// Converts the elements read for a `[T; N]` field, failing unless there are
+/// exactly `N` of them.
+pub fn array_from_vec<T, const N: usize>(values: Vec<T>) -> Result<[T; N], ExtractError> {
+    let len = values.len();
+    values
+        .try_into()
+        .map_err(|_| ExtractError::invalid(format!("expected {} array elements, found {}", N, len)))
+}
+
+/// Converts the elements read for a `#[as_gd_res(pad)]` `[T; N]` field, filling
+/// missing elements with `T::default()` and dropping extra ones.
+pub fn pad_array<T: Default, const N: usize>(mut values: Vec<T>) -> [T; N] {
+    values.resize_with(N, T::default);
+    values
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("resized to the array length"))
+}
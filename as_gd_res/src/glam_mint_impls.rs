@@ -0,0 +1,199 @@
+//! `glam` and `mint` types as fields, stored as the Godot builtin with the same
+//! layout (`glam::Vec2` as `Vector2`, `glam::Mat3` as `Basis`, ...).
+
+use godot::prelude::*;
+
+use crate::{
+    AsGdRes, AsGdResArray, AsGdResDictElement, ExtractContext, ExtractError, ExtractGd, FromGdRes,
+    FromGdResArray, ToGdRes, ToGdResArray, apply_by_value,
+};
+
+/// Implements the field traits for a type that converts to and from the Godot
+/// builtin `$gd`, with `$array` as the Godot-side type of a `Vec`.
+macro_rules! impl_as_gd_res_via_builtin {
+    ($($t:ty => $gd:ty, $array:ty, $to_gd:expr, $from_gd:expr;)*) => {
+        $(
+            impl AsGdRes for $t {
+                type ResType = $gd;
+            }
+
+            impl AsGdResArray for $t {
+                type GdArray = $array;
+            }
+
+            impl ToGdRes for $t {
+                fn to_gd_res(&self) -> Self::ResType {
+                    $to_gd(self)
+                }
+
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    apply_by_value(res, $to_gd(self))
+                }
+            }
+
+            impl FromGdRes for $t {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    _ctx: &mut ExtractContext,
+                ) -> Result<Self, ExtractError> {
+                    Ok($from_gd(res))
+                }
+            }
+
+            impl ToGdResArray for $t {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    values.into_iter().map($to_gd).collect()
+                }
+
+                fn apply_to_gd_array<'a>(
+                    values: impl IntoIterator<Item = &'a Self>,
+                    res: &mut Self::GdArray,
+                ) -> bool {
+                    apply_by_value(res, Self::to_gd_array(values))
+                }
+            }
+
+            impl FromGdResArray for $t {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    _ctx: &mut ExtractContext,
+                ) -> Result<Vec<Self>, ExtractError> {
+                    Ok(res.extract().iter().map($from_gd).collect())
+                }
+            }
+
+            impl AsGdResDictElement for $t {
+                type GdElement = $gd;
+
+                fn to_gd_element(&self) -> Self::GdElement {
+                    $to_gd(self)
+                }
+
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    _ctx: &mut ExtractContext,
+                ) -> Result<Self, ExtractError> {
+                    Ok($from_gd(element))
+                }
+
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    apply_by_value(res, $to_gd(self))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "glam")]
+mod glam_types {
+    use glam::{Affine2, Affine3A, IVec2, IVec3, IVec4, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+
+    use super::*;
+
+    fn vector2(v: Vec2) -> Vector2 {
+        Vector2::new(v.x, v.y)
+    }
+
+    fn vec2(v: Vector2) -> Vec2 {
+        Vec2::new(v.x, v.y)
+    }
+
+    fn vector3(v: Vec3) -> Vector3 {
+        Vector3::new(v.x, v.y, v.z)
+    }
+
+    fn vec3(v: Vector3) -> Vec3 {
+        Vec3::new(v.x, v.y, v.z)
+    }
+
+    fn vector4(v: Vec4) -> Vector4 {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+
+    fn vec4(v: Vector4) -> Vec4 {
+        Vec4::new(v.x, v.y, v.z, v.w)
+    }
+
+    // Both store the basis vectors as columns.
+    fn basis(m: Mat3) -> Basis {
+        Basis::from_cols(vector3(m.x_axis), vector3(m.y_axis), vector3(m.z_axis))
+    }
+
+    fn mat3(b: Basis) -> Mat3 {
+        Mat3::from_cols(vec3(b.col_a()), vec3(b.col_b()), vec3(b.col_c()))
+    }
+
+    impl_as_gd_res_via_builtin! {
+        Vec2 => Vector2, PackedVector2Array, |v: &Vec2| vector2(*v), |g: &Vector2| vec2(*g);
+        Vec3 => Vector3, PackedVector3Array, |v: &Vec3| vector3(*v), |g: &Vector3| vec3(*g);
+        Vec4 => Vector4, PackedVector4Array, |v: &Vec4| vector4(*v), |g: &Vector4| vec4(*g);
+        IVec2 => Vector2i, Array<Vector2i>,
+            |v: &IVec2| Vector2i::new(v.x, v.y),
+            |g: &Vector2i| IVec2::new(g.x, g.y);
+        IVec3 => Vector3i, Array<Vector3i>,
+            |v: &IVec3| Vector3i::new(v.x, v.y, v.z),
+            |g: &Vector3i| IVec3::new(g.x, g.y, g.z);
+        IVec4 => Vector4i, Array<Vector4i>,
+            |v: &IVec4| Vector4i::new(v.x, v.y, v.z, v.w),
+            |g: &Vector4i| IVec4::new(g.x, g.y, g.z, g.w);
+        Quat => Quaternion, Array<Quaternion>,
+            |v: &Quat| Quaternion::new(v.x, v.y, v.z, v.w),
+            |g: &Quaternion| Quat::from_xyzw(g.x, g.y, g.z, g.w);
+        Mat3 => Basis, Array<Basis>, |v: &Mat3| basis(*v), |g: &Basis| mat3(*g);
+        Affine2 => Transform2D, Array<Transform2D>,
+            |v: &Affine2| Transform2D::from_cols(
+                vector2(v.matrix2.x_axis),
+                vector2(v.matrix2.y_axis),
+                vector2(v.translation),
+            ),
+            |g: &Transform2D| Affine2::from_cols(vec2(g.a), vec2(g.b), vec2(g.origin));
+        Affine3A => Transform3D, Array<Transform3D>,
+            |v: &Affine3A| Transform3D::new(basis(v.matrix3.into()), vector3(v.translation.into())),
+            |g: &Transform3D| Affine3A::from_mat3_translation(mat3(g.basis), vec3(g.origin));
+        Mat4 => Projection, Array<Projection>,
+            |v: &Mat4| Projection::new([
+                vector4(v.x_axis),
+                vector4(v.y_axis),
+                vector4(v.z_axis),
+                vector4(v.w_axis),
+            ]),
+            |g: &Projection| Mat4::from_cols(
+                vec4(g.cols[0]),
+                vec4(g.cols[1]),
+                vec4(g.cols[2]),
+                vec4(g.cols[3]),
+            );
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint_types {
+    use super::*;
+
+    impl_as_gd_res_via_builtin! {
+        mint::Vector2<f32> => Vector2, PackedVector2Array,
+            |v: &mint::Vector2<f32>| Vector2::new(v.x, v.y),
+            |g: &Vector2| mint::Vector2 { x: g.x, y: g.y };
+        mint::Vector3<f32> => Vector3, PackedVector3Array,
+            |v: &mint::Vector3<f32>| Vector3::new(v.x, v.y, v.z),
+            |g: &Vector3| mint::Vector3 { x: g.x, y: g.y, z: g.z };
+        mint::Vector4<f32> => Vector4, PackedVector4Array,
+            |v: &mint::Vector4<f32>| Vector4::new(v.x, v.y, v.z, v.w),
+            |g: &Vector4| mint::Vector4 { x: g.x, y: g.y, z: g.z, w: g.w };
+        mint::Vector2<i32> => Vector2i, Array<Vector2i>,
+            |v: &mint::Vector2<i32>| Vector2i::new(v.x, v.y),
+            |g: &Vector2i| mint::Vector2 { x: g.x, y: g.y };
+        mint::Vector3<i32> => Vector3i, Array<Vector3i>,
+            |v: &mint::Vector3<i32>| Vector3i::new(v.x, v.y, v.z),
+            |g: &Vector3i| mint::Vector3 { x: g.x, y: g.y, z: g.z };
+        mint::Vector4<i32> => Vector4i, Array<Vector4i>,
+            |v: &mint::Vector4<i32>| Vector4i::new(v.x, v.y, v.z, v.w),
+            |g: &Vector4i| mint::Vector4 { x: g.x, y: g.y, z: g.z, w: g.w };
+        mint::Quaternion<f32> => Quaternion, Array<Quaternion>,
+            |v: &mint::Quaternion<f32>| Quaternion::new(v.v.x, v.v.y, v.v.z, v.s),
+            |g: &Quaternion| mint::Quaternion {
+                v: mint::Vector3 { x: g.x, y: g.y, z: g.z },
+                s: g.w,
+            };
+    }
+}
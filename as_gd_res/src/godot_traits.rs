@@ -0,0 +1,1491 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use godot::global::PropertyHint;
+use godot::meta::PropertyHintInfo;
+use godot::obj::{Bounds, Gd, GodotClass, bounds};
+use godot::prelude::*;
+
+use crate::{ExtractContext, ExtractError};
+
+pub trait AsGdRes: Clone {
+    type ResType: ExtractGd;
+}
+
+/// The resource class that `#[derive(AsGdRes)]` generates for a struct, e.g.
+/// `MoneyDataResource` for `MoneyData`. Enums use it as the resource of a
+/// single-tuple variant holding the struct.
+pub trait AsGdResClass: AsGdRes {
+    type GdClass: GodotClass;
+}
+
+/// Builds the Godot-side value for a Rust value; the inverse of [`ExtractGd`].
+///
+/// For `#[derive(AsGdRes)]` types this creates the full resource tree, including
+/// nested sub-resources, arrays and `DynGd` enum variants.
+pub trait ToGdRes: AsGdRes {
+    fn to_gd_res(&self) -> Self::ResType;
+
+    /// Writes `self` onto an existing Godot-side value, reusing sub-resources
+    /// where it can. Returns `true` if the value held in `res` itself changed,
+    /// in which case the resource owning `res` must emit `changed`.
+    ///
+    /// The default implementation always replaces the value.
+    fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+        *res = self.to_gd_res();
+        true
+    }
+}
+
+// pub trait AsGdRes2: Clone {
+//     type ResType: ExtractGd + ?Sized;
+//     fn extract_from(resource: Self::ResType) -> Self;
+// }
+
+/// A unit-only enum exported as a Godot enum property.
+///
+/// `#[derive(AsGdEnumSimple)]` also implements `AsGdRes`, `ToGdRes` and
+/// `AsGdResDictElement` for the enum, with `GdEnumType` as the `ResType` (or
+/// [`GdEnumInt`] with `#[as_gd_enum(repr = int)]`). These are not blanket impls over
+/// `AsGdEnumSimple`, as those would conflict with the impls for `Box<T>`.
+pub trait AsGdEnumSimple: Clone + Copy + Default + Debug + PartialEq + Eq {
+    type GdEnumType: ExtractGd;
+
+    /// The `PropertyHint::ENUM` hint string of the variants, e.g. `"Fire,Water"`, or
+    /// `"Fire:0,Water:5"` with `#[as_gd_enum(repr = int)]`.
+    const ENUM_HINT: &'static str;
+
+    /// What Godot stores for each variant: a `STRING`, or an `INT` with
+    /// `#[as_gd_enum(repr = int)]`.
+    const STORED_TYPE: VariantType;
+}
+
+pub trait ExtractGd {
+    type Extracted;
+    fn extract(&self) -> Self::Extracted;
+}
+
+/// Fallible counterpart of [`ExtractGd`].
+///
+/// Where `extract` panics on an unset `OnEditor<_>` or a null array element,
+/// `try_extract` returns an [`ExtractError`] carrying the field path and the
+/// `res://` path of the resource where extraction failed.
+pub trait TryExtractGd: ExtractGd {
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError>;
+
+    /// Extracts with a context shared by the whole resource graph, to detect
+    /// cycles and share `Arc`s (see [`ExtractContext`]).
+    ///
+    /// Only needs to be implemented by values that contain resources.
+    fn try_extract_with(&self, _ctx: &mut ExtractContext) -> Result<Self::Extracted, ExtractError> {
+        self.try_extract()
+    }
+}
+
+/// Extracts a Rust value from its Godot-side value; the counterpart of [`ToGdRes`].
+///
+/// Unlike [`TryExtractGd`], this is keyed on the Rust type, so Rust types that share
+/// a Godot-side type (`T` and `Arc<T>`, or `HashMap` and `BTreeMap`) can extract
+/// differently. Derived types extract their fields through this trait.
+pub trait FromGdRes: AsGdRes {
+    fn try_from_gd_res_with(
+        res: &Self::ResType,
+        ctx: &mut ExtractContext,
+    ) -> Result<Self, ExtractError>;
+
+    fn try_from_gd_res(res: &Self::ResType) -> Result<Self, ExtractError> {
+        Self::try_from_gd_res_with(res, &mut ExtractContext::new())
+    }
+
+    /// Like [`FromGdRes::try_from_gd_res`], but panics on errors.
+    fn from_gd_res(res: &Self::ResType) -> Self {
+        Self::try_from_gd_res(res).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Extracts into an `Arc` that is shared with every other `Arc` extracted from
+    /// the same resource with `ctx`. Values that aren't resources get a new `Arc`.
+    fn try_arc_from_gd_res(
+        res: &Self::ResType,
+        ctx: &mut ExtractContext,
+    ) -> Result<Arc<Self>, ExtractError> {
+        Self::try_from_gd_res_with(res, ctx).map(Arc::new)
+    }
+}
+
+/// Implements `FromGdRes` and `FromGdResArray` for a type whose `ResType` and
+/// `GdArray` extract to the type itself and a `Vec` of it, and hold no resources.
+#[macro_export]
+macro_rules! impl_from_gd_res_by_value {
+    ($t:ty) => {
+        impl $crate::FromGdRes for $t {
+            fn try_from_gd_res_with(
+                res: &Self::ResType,
+                _ctx: &mut $crate::ExtractContext,
+            ) -> Result<Self, $crate::ExtractError> {
+                $crate::TryExtractGd::try_extract(res)
+            }
+        }
+
+        impl $crate::FromGdResArray for $t {
+            fn try_from_gd_array(
+                res: &Self::GdArray,
+                _ctx: &mut $crate::ExtractContext,
+            ) -> Result<Vec<Self>, $crate::ExtractError> {
+                $crate::TryExtractGd::try_extract(res)
+            }
+        }
+    };
+}
+
+//////////////
+// godot-rust smart pointers
+//////////////
+
+pub trait ExtractGdHelper<D: bounds::Declarer> {
+    type InnerExtracted;
+    fn extract_inner(&self) -> Self::InnerExtracted;
+}
+
+impl<T> ExtractGdHelper<bounds::DeclUser> for Gd<T>
+where
+    T: GodotClass + Bounds<Declarer = bounds::DeclUser> + ExtractGd,
+{
+    type InnerExtracted = <T as ExtractGd>::Extracted;
+    fn extract_inner(&self) -> Self::InnerExtracted {
+        T::extract(&self.bind())
+    }
+}
+
+pub trait ExtractGdEngineFn {
+    type GdType;
+    type Extracted;
+    fn extract_inner(gd: Self::GdType) -> Self::Extracted;
+}
+
+impl<T> ExtractGdHelper<bounds::DeclEngine> for Gd<T>
+where
+    T: GodotClass + Bounds<Declarer = bounds::DeclEngine> + ExtractGd + ExtractGdEngineFn,
+{
+    type InnerExtracted = <T as ExtractGd>::Extracted;
+    fn extract_inner(&self) -> Self::InnerExtracted {
+        T::extract(&self)
+    }
+}
+
+////////
+
+/////// Gd //////////
+
+impl<T> ExtractGd for Gd<T>
+where
+    T: GodotClass + Bounds, // T has Bounds::Declarer associated type
+    Gd<T>: ExtractGdHelper<<T as Bounds>::Declarer>,
+{
+    type Extracted = <Gd<T> as ExtractGdHelper<T::Declarer>>::InnerExtracted;
+    fn extract(&self) -> Self::Extracted {
+        // Delegate to the corresponding helper impl:
+        <Gd<T> as ExtractGdHelper<T::Declarer>>::extract_inner(self)
+    }
+}
+
+// Engine classes implement `TryExtractGd` for their concrete `Gd<_>` directly
+// (see `engine_type_impls`), so only user classes need the generic impl.
+impl<T> TryExtractGd for Gd<T>
+where
+    T: GodotClass + Bounds<Declarer = bounds::DeclUser> + TryExtractGd,
+{
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        self.try_extract_with(&mut ExtractContext::new())
+    }
+
+    fn try_extract_with(&self, ctx: &mut ExtractContext) -> Result<Self::Extracted, ExtractError> {
+        ctx.visit(self.instance_id(), |ctx| {
+            T::try_extract_with(&self.bind(), ctx)
+        })
+    }
+}
+
+/////// DynGd //////////
+
+impl<T: ?Sized> ExtractGd for DynGd<Resource, T>
+where
+    T: ExtractGd,
+{
+    type Extracted = T::Extracted;
+    fn extract(&self) -> Self::Extracted {
+        self.dyn_bind().extract()
+    }
+}
+
+impl<T: ?Sized> TryExtractGd for DynGd<Resource, T>
+where
+    T: TryExtractGd,
+{
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        self.try_extract_with(&mut ExtractContext::new())
+    }
+
+    fn try_extract_with(&self, ctx: &mut ExtractContext) -> Result<Self::Extracted, ExtractError> {
+        ctx.visit(self.instance_id(), |ctx| {
+            self.dyn_bind().try_extract_with(ctx)
+        })
+    }
+}
+
+/////// OnEditor //////////
+
+impl<T> ExtractGd for OnEditor<T>
+where
+    T: ExtractGd,
+{
+    type Extracted = T::Extracted;
+    fn extract(&self) -> Self::Extracted {
+        T::extract(&self)
+    }
+}
+
+impl<T> TryExtractGd for OnEditor<T>
+where
+    T: TryExtractGd,
+{
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        self.try_extract_with(&mut ExtractContext::new())
+    }
+
+    fn try_extract_with(&self, ctx: &mut ExtractContext) -> Result<Self::Extracted, ExtractError> {
+        try_from_on_editor(self, ctx, T::try_extract_with)
+    }
+}
+
+/// Converts the value of an `OnEditor` with `convert`, failing if it is unset.
+pub fn try_from_on_editor<G, U>(
+    res: &OnEditor<G>,
+    ctx: &mut ExtractContext,
+    convert: impl FnOnce(&G, &mut ExtractContext) -> Result<U, ExtractError>,
+) -> Result<U, ExtractError> {
+    if res.is_invalid() {
+        return Err(ExtractError::unset());
+    }
+    convert(&**res, ctx)
+}
+
+/// Wraps a value in an already-initialized `OnEditor`.
+pub fn on_editor<T>(value: T) -> OnEditor<T>
+where
+    OnEditor<T>: Default,
+{
+    let mut on_editor = OnEditor::default();
+    on_editor.init(value);
+    on_editor
+}
+
+/// Applies an object-backed value onto an `OnEditor` field, building a new
+/// object only if the field is unset.
+pub fn apply_to_on_editor<T, G>(
+    value: &T,
+    res: &mut OnEditor<G>,
+    build: impl Fn(&T) -> G,
+    apply: impl Fn(&T, &mut G) -> bool,
+) -> bool
+where
+    OnEditor<G>: Default,
+{
+    if res.is_invalid() {
+        *res = on_editor(build(value));
+        return true;
+    }
+    apply(value, &mut **res)
+}
+
+/// Replaces `res` with `new` if they differ. Used for builtin (non-object) values.
+pub fn apply_by_value<G: PartialEq>(res: &mut G, new: G) -> bool {
+    if *res == new {
+        return false;
+    }
+    *res = new;
+    true
+}
+
+/////// OPTION //////////
+pub trait AsGdResOpt: Clone + Sized {
+    type GdOption: ExtractGd + Export;
+}
+
+pub trait ToGdResOpt: AsGdResOpt {
+    fn to_gd_opt(value: Option<&Self>) -> Self::GdOption;
+
+    /// See [`ToGdRes::apply_to_gd_res`].
+    fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+        *res = Self::to_gd_opt(value);
+        true
+    }
+}
+
+/// Applies an optional object-backed value, reusing the existing object if
+/// both the value and `res` are `Some`.
+pub fn apply_to_option<T, G>(
+    value: Option<&T>,
+    res: &mut Option<G>,
+    build: impl Fn(&T) -> G,
+    apply: impl Fn(&T, &mut G) -> bool,
+) -> bool {
+    match (value, res.as_mut()) {
+        (Some(v), Some(gd)) => apply(v, gd),
+        (None, None) => false,
+        (value, _) => {
+            *res = value.map(build);
+            true
+        }
+    }
+}
+
+impl<T> AsGdRes for Option<T>
+where
+    T: AsGdResOpt + Sized,
+{
+    type ResType = T::GdOption;
+}
+
+impl<T> ToGdRes for Option<T>
+where
+    T: ToGdResOpt,
+{
+    fn to_gd_res(&self) -> Self::ResType {
+        T::to_gd_opt(self.as_ref())
+    }
+
+    fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+        T::apply_to_gd_opt(self.as_ref(), res)
+    }
+}
+
+pub trait FromGdResOpt: AsGdResOpt {
+    fn try_from_gd_opt(
+        res: &Self::GdOption,
+        ctx: &mut ExtractContext,
+    ) -> Result<Option<Self>, ExtractError>;
+
+    /// See [`FromGdRes::try_arc_from_gd_res`].
+    fn try_arc_from_gd_opt(
+        res: &Self::GdOption,
+        ctx: &mut ExtractContext,
+    ) -> Result<Option<Arc<Self>>, ExtractError> {
+        Ok(Self::try_from_gd_opt(res, ctx)?.map(Arc::new))
+    }
+}
+
+impl<T> FromGdRes for Option<T>
+where
+    T: FromGdResOpt,
+{
+    fn try_from_gd_res_with(
+        res: &Self::ResType,
+        ctx: &mut ExtractContext,
+    ) -> Result<Self, ExtractError> {
+        T::try_from_gd_opt(res, ctx)
+    }
+}
+
+/// Converts an optional value with `convert`.
+pub fn try_from_option<G, U>(
+    res: &Option<G>,
+    ctx: &mut ExtractContext,
+    convert: impl FnOnce(&G, &mut ExtractContext) -> Result<U, ExtractError>,
+) -> Result<Option<U>, ExtractError> {
+    res.as_ref().map(|gd| convert(gd, ctx)).transpose()
+}
+
+impl<T> ExtractGd for Option<T>
+where
+    T: ExtractGd,
+{
+    type Extracted = Option<T::Extracted>;
+    fn extract(&self) -> Self::Extracted {
+        self.as_ref().map(|v| v.extract())
+    }
+}
+
+impl<T> TryExtractGd for Option<T>
+where
+    T: TryExtractGd,
+{
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        self.try_extract_with(&mut ExtractContext::new())
+    }
+
+    fn try_extract_with(&self, ctx: &mut ExtractContext) -> Result<Self::Extracted, ExtractError> {
+        try_from_option(self, ctx, T::try_extract_with)
+    }
+}
+
+/// `Option<T>` fields of numbers, `bool`s, `String`s and `NodePathString`s are
+/// exported as a `{field}_enabled` toggle plus a typed `{field}` property, which
+/// keeps its last value while the toggle is off. This builds the value property.
+pub fn to_gd_toggle<T: ToGdRes>(value: Option<&T>) -> T::ResType
+where
+    T::ResType: Default,
+{
+    value.map(T::to_gd_res).unwrap_or_default()
+}
+
+/// Applies an `Option<T>` toggle field, see [`to_gd_toggle`]. `None` only turns the
+/// toggle off.
+pub fn apply_to_gd_toggle<T: ToGdRes>(
+    value: Option<&T>,
+    enabled: &mut bool,
+    res: &mut T::ResType,
+) -> bool {
+    let toggled = apply_by_value(enabled, value.is_some());
+    toggled | value.is_some_and(|v| v.apply_to_gd_res(res))
+}
+
+/// Extracts an `Option<T>` toggle field, see [`to_gd_toggle`]. `None` if the toggle
+/// is off, whatever the value property holds.
+pub fn try_from_gd_toggle<T: FromGdRes>(
+    enabled: bool,
+    res: &T::ResType,
+    ctx: &mut ExtractContext,
+) -> Result<Option<T>, ExtractError> {
+    if !enabled {
+        return Ok(None);
+    }
+    T::try_from_gd_res_with(res, ctx).map(Some)
+}
+
+/// Tuples are stored as an `Array<Variant>`, whose elements are converted by
+/// `tuple_impls`, not here.
+impl ExtractGd for Variant {
+    type Extracted = Variant;
+    fn extract(&self) -> Self::Extracted {
+        self.clone()
+    }
+}
+
+impl TryExtractGd for Variant {
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        Ok(self.clone())
+    }
+}
+
+/////// Vec <-> Array //////////
+pub trait AsGdResArray: Clone {
+    type GdArray: ExtractGd + Export;
+}
+
+/// Takes the values by reference, so a `Vec<Rc<T>>` can be converted without
+/// cloning the pointees.
+pub trait ToGdResArray: AsGdResArray {
+    fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray
+    where
+        Self: 'a;
+
+    /// See [`ToGdRes::apply_to_gd_res`].
+    fn apply_to_gd_array<'a>(
+        values: impl IntoIterator<Item = &'a Self>,
+        res: &mut Self::GdArray,
+    ) -> bool
+    where
+        Self: 'a,
+    {
+        *res = Self::to_gd_array(values);
+        true
+    }
+}
+
+pub trait FromGdResArray: AsGdResArray {
+    fn try_from_gd_array(
+        res: &Self::GdArray,
+        ctx: &mut ExtractContext,
+    ) -> Result<Vec<Self>, ExtractError>;
+
+    /// See [`FromGdRes::try_arc_from_gd_res`].
+    fn try_arc_from_gd_array(
+        res: &Self::GdArray,
+        ctx: &mut ExtractContext,
+    ) -> Result<Vec<Arc<Self>>, ExtractError> {
+        Ok(Self::try_from_gd_array(res, ctx)?
+            .into_iter()
+            .map(Arc::new)
+            .collect())
+    }
+}
+
+/// Reads an array element as a `Variant` first, returning `None` for nulls
+/// instead of panicking like `Array::get` does for object element types.
+pub fn array_element<T: godot::meta::Element>(array: &Array<T>, index: usize) -> Option<T> {
+    array
+        .to_variant()
+        .call("get", &[(index as i64).to_variant()])
+        .try_to::<T>()
+        .ok()
+}
+
+/// Applies a slice of object-backed values onto an array, reusing the existing
+/// object at each index. The array is only replaced if its length changes or an
+/// element had to be rebuilt.
+pub fn apply_to_array<'a, T: 'a, G>(
+    values: impl IntoIterator<Item = &'a T>,
+    res: &mut Array<G>,
+    build: impl Fn(&T) -> G,
+    apply: impl Fn(&T, &mut G) -> bool,
+) -> bool
+where
+    G: godot::meta::Element,
+{
+    let mut replaced = false;
+    let elements: Vec<G> = values
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| match array_element(res, i) {
+            Some(mut gd) => {
+                replaced |= apply(v, &mut gd);
+                gd
+            }
+            None => {
+                replaced = true;
+                build(v)
+            }
+        })
+        .collect();
+    replaced |= res.len() != elements.len();
+    if replaced {
+        *res = elements.into_iter().collect();
+    }
+    replaced
+}
+
+/// A number that Godot has a `Packed*Array` for. `Vec<T>` fields marked
+/// `#[as_gd_res(packed)]` use it instead of a typed `Array`.
+pub trait AsGdResPacked: Copy {
+    type GdPacked: PartialEq;
+
+    fn to_gd_packed(values: &[Self]) -> Self::GdPacked;
+    fn from_gd_packed(res: &Self::GdPacked) -> Vec<Self>;
+}
+
+/// The Godot-side type of a `Vec<T>` field marked `#[as_gd_res(packed)]`.
+pub type GdPackedOf<T> = <T as AsGdResPacked>::GdPacked;
+
+/// `ToGdRes::to_gd_res` for `#[as_gd_res(packed)]` fields.
+pub fn to_gd_packed_of<T: AsGdResPacked>(values: &[T]) -> GdPackedOf<T> {
+    T::to_gd_packed(values)
+}
+
+/// `ToGdRes::apply_to_gd_res` for `#[as_gd_res(packed)]` fields.
+pub fn apply_to_gd_packed_of<T: AsGdResPacked>(values: &[T], res: &mut GdPackedOf<T>) -> bool {
+    apply_by_value(res, T::to_gd_packed(values))
+}
+
+/// `FromGdRes::try_from_gd_res_with` for `#[as_gd_res(packed)]` fields, which
+/// can't fail.
+pub fn from_gd_packed_of<T: AsGdResPacked>(res: &GdPackedOf<T>) -> Vec<T> {
+    T::from_gd_packed(res)
+}
+
+impl<T> AsGdRes for Vec<T>
+where
+    T: AsGdResArray,
+{
+    type ResType = T::GdArray;
+}
+
+impl<T> ToGdRes for Vec<T>
+where
+    T: ToGdResArray,
+{
+    fn to_gd_res(&self) -> Self::ResType {
+        T::to_gd_array(self)
+    }
+
+    fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+        T::apply_to_gd_array(self, res)
+    }
+}
+
+impl<T> FromGdRes for Vec<T>
+where
+    T: FromGdResArray,
+{
+    fn try_from_gd_res_with(
+        res: &Self::ResType,
+        ctx: &mut ExtractContext,
+    ) -> Result<Self, ExtractError> {
+        T::try_from_gd_array(res, ctx)
+    }
+}
+
+/////// [T; N] <-> Array //////////
+
+// Stored like a `Vec<T>`; extraction fails unless the array has exactly `N`
+// elements (see `#[as_gd_res(pad)]` for padding instead). Godot has no hint to
+// fix an array's length, but applying a value always resizes it back to `N`.
+impl<T, const N: usize> AsGdRes for [T; N]
+where
+    T: AsGdResArray,
+{
+    type ResType = T::GdArray;
+}
+
+impl<T, const N: usize> ToGdRes for [T; N]
+where
+    T: ToGdResArray,
+{
+    fn to_gd_res(&self) -> Self::ResType {
+        T::to_gd_array(self)
+    }
+
+    fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+        T::apply_to_gd_array(self, res)
+    }
+}
+
+impl<T, const N: usize> FromGdRes for [T; N]
+where
+    T: FromGdResArray,
+{
+    fn try_from_gd_res_with(
+        res: &Self::ResType,
+        ctx: &mut ExtractContext,
+    ) -> Result<Self, ExtractError> {
+        crate::array_from_vec(T::try_from_gd_array(res, ctx)?)
+    }
+}
+
+/// The initial value of a `[T; N]` field in a new resource: an array of `len`
+/// default elements, or nulls for resources, so the inspector shows every slot.
+pub fn sized_gd_array<T>(len: usize) -> T::GdArray
+where
+    T: AsGdResArray,
+    T::GdArray: Default + Var,
+    <T::GdArray as GodotConvert>::Via: ToGodot + FromGodot,
+{
+    // `resize` through the variant works for typed and packed arrays alike, and
+    // leaves object elements null instead of needing a value to fill them with.
+    let mut res = T::GdArray::default();
+    let array = res.get_property().to_variant();
+    array.call("resize", &[(len as i64).to_variant()]);
+    res.set_property(array.to());
+    res
+}
+
+/// Converts each element of an array of builtins with `convert`.
+pub fn try_from_array<G, U>(
+    res: &Array<G>,
+    ctx: &mut ExtractContext,
+    mut convert: impl FnMut(&G, &mut ExtractContext) -> Result<U, ExtractError>,
+) -> Result<Vec<U>, ExtractError>
+where
+    G: godot::meta::Element,
+{
+    res.iter_shared()
+        .enumerate()
+        .map(|(i, value)| convert(&value, ctx).map_err(|e| e.at_index(i)))
+        .collect()
+}
+
+/// Converts each element of an array of objects with `convert`. Null elements
+/// are errors.
+pub fn try_from_object_array<G, U>(
+    res: &Array<G>,
+    ctx: &mut ExtractContext,
+    mut convert: impl FnMut(&G, &mut ExtractContext) -> Result<U, ExtractError>,
+) -> Result<Vec<U>, ExtractError>
+where
+    G: godot::meta::Element,
+{
+    // Typed arrays of objects may still hold nulls, and converting those to
+    // `G` panics in `iter_shared()`.
+    (0..res.len())
+        .map(|i| {
+            let value =
+                array_element(res, i).ok_or_else(|| ExtractError::null_element().at_index(i))?;
+            convert(&value, ctx).map_err(|e| e.at_index(i))
+        })
+        .collect()
+}
+
+impl<T> ExtractGd for Array<T>
+where
+    T: ExtractGd + godot::meta::Element,
+{
+    type Extracted = Vec<T::Extracted>;
+    fn extract(&self) -> Self::Extracted {
+        self.iter_shared().map(|v| v.extract()).collect()
+    }
+}
+
+impl<T> TryExtractGd for Array<T>
+where
+    T: TryExtractGd + godot::meta::Element,
+{
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        self.try_extract_with(&mut ExtractContext::new())
+    }
+
+    fn try_extract_with(&self, ctx: &mut ExtractContext) -> Result<Self::Extracted, ExtractError> {
+        try_from_array(self, ctx, T::try_extract_with)
+    }
+}
+
+/////// HashMap/BTreeMap <-> Dictionary //////////
+
+/// A type that can be a key or a value of a typed `Dictionary`.
+///
+/// Simple enums are stored by variant name, since their Godot-side enum types
+/// cannot be collection elements (see [`simple_enum_to_gd_element`]).
+pub trait AsGdResDictElement: Clone {
+    type GdElement: TryExtractGd + godot::meta::Element + PartialEq;
+
+    fn to_gd_element(&self) -> Self::GdElement;
+
+    fn try_from_gd_element(
+        element: &Self::GdElement,
+        ctx: &mut ExtractContext,
+    ) -> Result<Self, ExtractError>;
+
+    /// See [`FromGdRes::try_arc_from_gd_res`]. Resources implement this with
+    /// [`try_arc_from_object`].
+    fn try_arc_from_gd_element(
+        element: &Self::GdElement,
+        ctx: &mut ExtractContext,
+    ) -> Result<Arc<Self>, ExtractError> {
+        Self::try_from_gd_element(element, ctx).map(Arc::new)
+    }
+
+    /// See [`ToGdRes::apply_to_gd_res`].
+    fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+        *res = self.to_gd_element();
+        true
+    }
+
+    /// The element a new resource starts with, e.g. in each slot of a tuple field.
+    /// Defaults to what Godot fills a typed array with when resizing it: the
+    /// builtin's default value, or null for objects.
+    fn default_gd_element() -> Variant {
+        let array = Array::<Self::GdElement>::new().to_variant();
+        array.call("resize", &[1.to_variant()]);
+        array.call("get", &[0.to_variant()])
+    }
+}
+
+/// Extracts a resource into an `Arc` that is shared by every use of the resource
+/// within `ctx`.
+pub fn try_arc_from_object<T>(
+    element: &T::GdElement,
+    ctx: &mut ExtractContext,
+) -> Result<Arc<T>, ExtractError>
+where
+    T: AsGdResDictElement + 'static,
+{
+    match element.to_variant().object_id() {
+        Some(id) => ctx.share(id, |ctx| T::try_from_gd_element(element, ctx)),
+        None => T::try_from_gd_element(element, ctx).map(Arc::new),
+    }
+}
+
+/// Implements `FromGdRes`, `FromGdResOpt` and `FromGdResArray` for a resource type,
+/// whose `ResType`, `GdOption` and `GdArray` are an `OnEditor`, `Option` and `Array`
+/// of its `AsGdResDictElement::GdElement`.
+#[macro_export]
+macro_rules! impl_from_gd_res_by_element {
+    ($t:ty) => {
+        impl $crate::FromGdRes for $t {
+            fn try_from_gd_res_with(
+                res: &Self::ResType,
+                ctx: &mut $crate::ExtractContext,
+            ) -> Result<Self, $crate::ExtractError> {
+                $crate::try_from_on_editor(
+                    res,
+                    ctx,
+                    <Self as $crate::AsGdResDictElement>::try_from_gd_element,
+                )
+            }
+
+            fn try_arc_from_gd_res(
+                res: &Self::ResType,
+                ctx: &mut $crate::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, $crate::ExtractError> {
+                $crate::try_from_on_editor(
+                    res,
+                    ctx,
+                    <Self as $crate::AsGdResDictElement>::try_arc_from_gd_element,
+                )
+            }
+        }
+
+        impl $crate::FromGdResOpt for $t {
+            fn try_from_gd_opt(
+                res: &Self::GdOption,
+                ctx: &mut $crate::ExtractContext,
+            ) -> Result<Option<Self>, $crate::ExtractError> {
+                $crate::try_from_option(
+                    res,
+                    ctx,
+                    <Self as $crate::AsGdResDictElement>::try_from_gd_element,
+                )
+            }
+
+            fn try_arc_from_gd_opt(
+                res: &Self::GdOption,
+                ctx: &mut $crate::ExtractContext,
+            ) -> Result<Option<::std::sync::Arc<Self>>, $crate::ExtractError> {
+                $crate::try_from_option(
+                    res,
+                    ctx,
+                    <Self as $crate::AsGdResDictElement>::try_arc_from_gd_element,
+                )
+            }
+        }
+
+        impl $crate::FromGdResArray for $t {
+            fn try_from_gd_array(
+                res: &Self::GdArray,
+                ctx: &mut $crate::ExtractContext,
+            ) -> Result<Vec<Self>, $crate::ExtractError> {
+                $crate::try_from_object_array(
+                    res,
+                    ctx,
+                    <Self as $crate::AsGdResDictElement>::try_from_gd_element,
+                )
+            }
+
+            fn try_arc_from_gd_array(
+                res: &Self::GdArray,
+                ctx: &mut $crate::ExtractContext,
+            ) -> Result<Vec<::std::sync::Arc<Self>>, $crate::ExtractError> {
+                $crate::try_from_object_array(
+                    res,
+                    ctx,
+                    <Self as $crate::AsGdResDictElement>::try_arc_from_gd_element,
+                )
+            }
+        }
+    };
+}
+
+/// The Godot-side type of a `Vec` of an `AsGdEnumSimple` enum: a typed array of what
+/// Godot stores for each variant, exported with the enum's dropdown for every element.
+pub struct GdEnumArray<T: AsGdResDictElement> {
+    pub array: Array<T::GdElement>,
+}
+
+impl<T: AsGdResDictElement> GdEnumArray<T> {
+    pub fn new(array: Array<T::GdElement>) -> Self {
+        Self { array }
+    }
+}
+
+impl<T: AsGdResDictElement> Clone for GdEnumArray<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.array.clone())
+    }
+}
+
+impl<T: AsGdResDictElement> PartialEq for GdEnumArray<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.array == other.array
+    }
+}
+
+impl<T: AsGdResDictElement> Default for GdEnumArray<T> {
+    fn default() -> Self {
+        Self::new(Array::new())
+    }
+}
+
+impl<T: AsGdResDictElement> Debug for GdEnumArray<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.array.fmt(f)
+    }
+}
+
+impl<T: AsGdResDictElement> GodotConvert for GdEnumArray<T> {
+    type Via = Array<T::GdElement>;
+}
+
+impl<T: AsGdEnumSimple + AsGdResDictElement> Var for GdEnumArray<T> {
+    fn get_property(&self) -> Self::Via {
+        self.array.clone()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        self.array = value;
+    }
+
+    fn var_hint() -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: PropertyHint::ARRAY_TYPE,
+            hint_string: enum_array_hint_string(T::STORED_TYPE, T::ENUM_HINT).into(),
+        }
+    }
+}
+
+impl<T: AsGdEnumSimple + AsGdResDictElement> Export for GdEnumArray<T> {}
+
+impl<T: AsGdResDictElement> ExtractGd for GdEnumArray<T> {
+    type Extracted = Vec<T>;
+    fn extract(&self) -> Self::Extracted {
+        self.try_extract().unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: AsGdResDictElement> TryExtractGd for GdEnumArray<T> {
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        self.try_extract_with(&mut ExtractContext::new())
+    }
+
+    fn try_extract_with(&self, ctx: &mut ExtractContext) -> Result<Self::Extracted, ExtractError> {
+        try_from_array(&self.array, ctx, T::try_from_gd_element)
+    }
+}
+
+/// The discriminant that `Option::None` is stored as with `#[as_gd_enum(repr = int)]`.
+pub const NONE_DISCRIMINANT: i64 = -1;
+
+/// The Godot-side type of an `#[as_gd_enum(repr = int)]` enum field: the stored
+/// discriminant, exported with the enum's dropdown. Unlike the Godot-side enum, it
+/// keeps discriminants that aren't variants, e.g. from a `.tres` file written for
+/// a newer version of the enum, so that extracting reports them.
+pub struct GdEnumInt<T> {
+    pub value: i64,
+    _enum: PhantomData<T>,
+}
+
+/// The Godot-side type of an `Option` of an `#[as_gd_enum(repr = int)]` enum, like
+/// [`GdEnumInt`] with an extra `None` choice stored as [`NONE_DISCRIMINANT`].
+pub struct GdEnumIntOpt<T> {
+    pub value: i64,
+    _enum: PhantomData<T>,
+}
+
+/// The stored discriminant of a variant of an `#[as_gd_enum(repr = int)]` enum.
+fn simple_enum_to_discriminant<T>(value: T) -> i64
+where
+    T: AsGdEnumSimple,
+    T::GdEnumType: From<T> + ToGodot + GodotConvert<Via = i64>,
+{
+    T::GdEnumType::from(value).to_godot()
+}
+
+/// Reads a stored discriminant of an `#[as_gd_enum(repr = int)]` enum.
+fn simple_enum_from_discriminant<T>(value: i64) -> Result<T, ExtractError>
+where
+    T: AsGdEnumSimple,
+    T::GdEnumType: Into<T> + FromGodot + GodotConvert<Via = i64>,
+{
+    T::GdEnumType::try_from_godot(value)
+        .map(Into::into)
+        .map_err(|_| {
+            ExtractError::invalid(format!(
+                "expected a `{}` discriminant, found `{}`",
+                std::any::type_name::<T>(),
+                value
+            ))
+        })
+}
+
+macro_rules! impl_gd_enum_int {
+    ($name:ident, $extracted:ty, $hint:expr, $from:expr, $to:expr) => {
+        impl<T> $name<T> {
+            pub fn from_discriminant(value: i64) -> Self {
+                Self {
+                    value,
+                    _enum: PhantomData,
+                }
+            }
+        }
+
+        impl<T> $name<T>
+        where
+            T: AsGdEnumSimple,
+            T::GdEnumType: From<T> + ToGodot + GodotConvert<Via = i64>,
+        {
+            pub fn new(value: $extracted) -> Self {
+                Self::from_discriminant($to(value))
+            }
+        }
+
+        impl<T> Clone for $name<T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T> Copy for $name<T> {}
+
+        impl<T> PartialEq for $name<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value == other.value
+            }
+        }
+
+        impl<T> Debug for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.value.fmt(f)
+            }
+        }
+
+        impl<T> Default for $name<T>
+        where
+            T: AsGdEnumSimple,
+            T::GdEnumType: From<T> + ToGodot + GodotConvert<Via = i64>,
+        {
+            fn default() -> Self {
+                Self::new(Default::default())
+            }
+        }
+
+        impl<T> GodotConvert for $name<T> {
+            type Via = i64;
+        }
+
+        impl<T: AsGdEnumSimple> Var for $name<T> {
+            fn get_property(&self) -> Self::Via {
+                self.value
+            }
+
+            fn set_property(&mut self, value: Self::Via) {
+                self.value = value;
+            }
+
+            fn var_hint() -> PropertyHintInfo {
+                enum_property_hint(&$hint(T::ENUM_HINT))
+            }
+        }
+
+        impl<T: AsGdEnumSimple> Export for $name<T> {}
+
+        impl<T> ExtractGd for $name<T>
+        where
+            T: AsGdEnumSimple,
+            T::GdEnumType: Into<T> + FromGodot + GodotConvert<Via = i64>,
+        {
+            type Extracted = $extracted;
+            fn extract(&self) -> Self::Extracted {
+                self.try_extract().unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl<T> TryExtractGd for $name<T>
+        where
+            T: AsGdEnumSimple,
+            T::GdEnumType: Into<T> + FromGodot + GodotConvert<Via = i64>,
+        {
+            fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+                $from(self.value)
+            }
+        }
+    };
+}
+
+impl_gd_enum_int!(
+    GdEnumInt,
+    T,
+    |hint: &str| hint.to_string(),
+    simple_enum_from_discriminant,
+    simple_enum_to_discriminant
+);
+
+impl_gd_enum_int!(
+    GdEnumIntOpt,
+    Option<T>,
+    |hint: &str| format!("None:{},{}", NONE_DISCRIMINANT, hint),
+    |value| match value {
+        NONE_DISCRIMINANT => Ok(None),
+        value => simple_enum_from_discriminant(value).map(Some),
+    },
+    |value: Option<T>| value.map_or(NONE_DISCRIMINANT, simple_enum_to_discriminant)
+);
+
+/// The `PropertyHint::ARRAY_TYPE` hint string of an array whose `stored_type`
+/// elements have the enum hint `enum_hint`, e.g. `"4/2:Fire,Water"`.
+pub fn enum_array_hint_string(stored_type: VariantType, enum_hint: &str) -> String {
+    format!(
+        "{}/{}:{}",
+        stored_type.ord(),
+        PropertyHint::ENUM.ord(),
+        enum_hint
+    )
+}
+
+/// The `PropertyHint::ENUM` export of a `#[derive(AsGdEnumSimple)]` enum with
+/// aliases, whose Godot-side enum implements `Var` by hand to accept them.
+pub fn enum_property_hint(enum_hint: &str) -> PropertyHintInfo {
+    PropertyHintInfo {
+        hint: PropertyHint::ENUM,
+        hint_string: enum_hint.into(),
+    }
+}
+
+/// `AsGdResDictElement::to_gd_element` for `#[derive(AsGdEnumSimple)]` enums: the
+/// variant name, or the discriminant with `#[as_gd_enum(repr = int)]`.
+pub fn simple_enum_to_gd_element<T, E>(value: T) -> E
+where
+    T: AsGdEnumSimple,
+    T::GdEnumType: From<T> + ToGodot,
+    E: FromGodot,
+{
+    T::GdEnumType::from(value).to_variant().to::<E>()
+}
+
+/// `AsGdResDictElement::try_from_gd_element` for `#[derive(AsGdEnumSimple)]` enums.
+/// Names that aren't variants of the Godot-side enum are parsed with `FromStr`, which
+/// also accepts aliases.
+pub fn simple_enum_from_gd_element<T, E>(element: &E) -> Result<T, ExtractError>
+where
+    T: AsGdEnumSimple + std::str::FromStr,
+    T::GdEnumType: Into<T> + FromGodot,
+    E: ToGodot + std::fmt::Display,
+{
+    element
+        .to_variant()
+        .try_to::<T::GdEnumType>()
+        .map(Into::into)
+        .or_else(|_| element.to_string().parse::<T>())
+        .map_err(|_| {
+            ExtractError::invalid(format!(
+                "`{}` is not a variant of `{}`",
+                element,
+                std::any::type_name::<T>()
+            ))
+        })
+}
+
+/// Reads the entries of a dictionary through `Variant`s, returning `None` for null
+/// values instead of panicking like typed access does for object value types.
+pub fn dictionary_entries<K, V>(dict: &Dictionary<K, V>) -> Vec<(K, Option<V>)>
+where
+    K: godot::meta::Element,
+    V: godot::meta::Element,
+{
+    let variant = dict.to_variant();
+    let keys = variant.call("keys", &[]).to::<Array<K>>();
+    keys.iter_shared()
+        .map(|key| {
+            let value = variant.call("get", &[key.to_variant()]).try_to::<V>().ok();
+            (key, value)
+        })
+        .collect()
+}
+
+/// Converts a dictionary into a map. Errors are reported at the key of the
+/// failing entry.
+fn try_map_from_dictionary<K, V, M>(
+    dict: &Dictionary<K::GdElement, V::GdElement>,
+    ctx: &mut ExtractContext,
+) -> Result<M, ExtractError>
+where
+    K: AsGdResDictElement,
+    V: AsGdResDictElement,
+    M: FromIterator<(K, V)>,
+{
+    dictionary_entries(dict)
+        .into_iter()
+        .map(|(key, value)| {
+            let at_key = |e: ExtractError| e.at_key(&key.to_variant().to_string());
+            let value = value.ok_or_else(|| at_key(ExtractError::unset()))?;
+            Ok((
+                K::try_from_gd_element(&key, ctx).map_err(at_key)?,
+                V::try_from_gd_element(&value, ctx).map_err(at_key)?,
+            ))
+        })
+        .collect()
+}
+
+/// Applies map entries onto a dictionary, reusing the existing value for each key.
+/// The dictionary is only replaced if its keys change or a value had to be rebuilt.
+pub fn apply_to_dictionary<'a, K, V>(
+    entries: impl ExactSizeIterator<Item = (&'a K, &'a V)>,
+    res: &mut Dictionary<K::GdElement, V::GdElement>,
+) -> bool
+where
+    K: AsGdResDictElement + 'a,
+    V: AsGdResDictElement + 'a,
+{
+    let mut existing = dictionary_entries(res);
+    let mut replaced = existing.len() != entries.len();
+    let elements: Vec<(K::GdElement, V::GdElement)> = entries
+        .map(|(k, v)| {
+            let key = k.to_gd_element();
+            let old = existing
+                .iter_mut()
+                .find(|(old_key, _)| *old_key == key)
+                .and_then(|(_, old)| old.take());
+            let value = match old {
+                Some(mut gd) => {
+                    replaced |= v.apply_to_gd_element(&mut gd);
+                    gd
+                }
+                None => {
+                    replaced = true;
+                    v.to_gd_element()
+                }
+            };
+            (key, value)
+        })
+        .collect();
+    if replaced {
+        *res = elements.into_iter().collect();
+    }
+    replaced
+}
+
+impl<K, V> ExtractGd for Dictionary<K, V>
+where
+    K: ExtractGd + godot::meta::Element,
+    V: ExtractGd + godot::meta::Element,
+{
+    type Extracted = Vec<(K::Extracted, V::Extracted)>;
+    fn extract(&self) -> Self::Extracted {
+        self.iter_shared()
+            .map(|(k, v)| (k.extract(), v.extract()))
+            .collect()
+    }
+}
+
+impl<K, V> TryExtractGd for Dictionary<K, V>
+where
+    K: TryExtractGd + godot::meta::Element,
+    V: TryExtractGd + godot::meta::Element,
+{
+    fn try_extract(&self) -> Result<Self::Extracted, ExtractError> {
+        self.try_extract_with(&mut ExtractContext::new())
+    }
+
+    fn try_extract_with(&self, ctx: &mut ExtractContext) -> Result<Self::Extracted, ExtractError> {
+        dictionary_entries(self)
+            .into_iter()
+            .map(|(key, value)| {
+                let at_key = |e: ExtractError| e.at_key(&key.to_variant().to_string());
+                let value = value.ok_or_else(|| at_key(ExtractError::unset()))?;
+                Ok((
+                    key.try_extract_with(ctx).map_err(at_key)?,
+                    value.try_extract_with(ctx).map_err(at_key)?,
+                ))
+            })
+            .collect()
+    }
+}
+
+macro_rules! impl_map_as_gd_res {
+    ($map:ident, $($key_bound:path),*) => {
+        impl<K, V> AsGdRes for $map<K, V>
+        where
+            K: AsGdResDictElement $(+ $key_bound)*,
+            V: AsGdResDictElement,
+        {
+            type ResType = Dictionary<K::GdElement, V::GdElement>;
+        }
+
+        impl<K, V> ToGdRes for $map<K, V>
+        where
+            K: AsGdResDictElement $(+ $key_bound)*,
+            V: AsGdResDictElement,
+        {
+            fn to_gd_res(&self) -> Self::ResType {
+                self.iter()
+                    .map(|(k, v)| (k.to_gd_element(), v.to_gd_element()))
+                    .collect()
+            }
+
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                apply_to_dictionary(self.iter(), res)
+            }
+        }
+
+        impl<K, V> FromGdRes for $map<K, V>
+        where
+            K: AsGdResDictElement $(+ $key_bound)*,
+            V: AsGdResDictElement,
+        {
+            fn try_from_gd_res_with(
+                res: &Self::ResType,
+                ctx: &mut ExtractContext,
+            ) -> Result<Self, ExtractError> {
+                try_map_from_dictionary(res, ctx)
+            }
+        }
+    };
+}
+
+impl_map_as_gd_res!(HashMap, Eq, Hash);
+impl_map_as_gd_res!(BTreeMap, Ord);
+
+/////// Box/Rc/Arc //////////
+
+/// Pointers use the Godot-side type of the value they point to, so e.g.
+/// `Option<Box<DialogueNode>>` is exported as `Option<Gd<DialogueNodeResource>>`.
+macro_rules! impl_pointer_as_gd_res {
+    ($($p:ident),*) => {
+        $(
+            impl<T: AsGdRes> AsGdRes for $p<T> {
+                type ResType = T::ResType;
+            }
+
+            impl<T: ToGdRes> ToGdRes for $p<T> {
+                fn to_gd_res(&self) -> Self::ResType {
+                    (**self).to_gd_res()
+                }
+
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    (**self).apply_to_gd_res(res)
+                }
+            }
+
+            impl<T: AsGdResOpt> AsGdResOpt for $p<T> {
+                type GdOption = T::GdOption;
+            }
+
+            impl<T: ToGdResOpt> ToGdResOpt for $p<T> {
+                fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                    T::to_gd_opt(value.map(|v| &**v))
+                }
+
+                fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                    T::apply_to_gd_opt(value.map(|v| &**v), res)
+                }
+            }
+
+            impl<T: AsGdResArray> AsGdResArray for $p<T> {
+                type GdArray = T::GdArray;
+            }
+
+            impl<T: ToGdResArray> ToGdResArray for $p<T> {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray
+                where
+                    Self: 'a,
+                {
+                    T::to_gd_array(values.into_iter().map(|v| &**v))
+                }
+
+                fn apply_to_gd_array<'a>(
+                    values: impl IntoIterator<Item = &'a Self>,
+                    res: &mut Self::GdArray,
+                ) -> bool
+                where
+                    Self: 'a,
+                {
+                    T::apply_to_gd_array(values.into_iter().map(|v| &**v), res)
+                }
+            }
+        )*
+    };
+}
+
+impl_pointer_as_gd_res!(Box, Rc, Arc);
+
+macro_rules! impl_pointer_from_gd_res {
+    ($($p:ident),*) => {
+        $(
+            impl<T: FromGdRes> FromGdRes for $p<T> {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    ctx: &mut ExtractContext,
+                ) -> Result<Self, ExtractError> {
+                    T::try_from_gd_res_with(res, ctx).map($p::new)
+                }
+            }
+
+            impl<T: FromGdResOpt> FromGdResOpt for $p<T> {
+                fn try_from_gd_opt(
+                    res: &Self::GdOption,
+                    ctx: &mut ExtractContext,
+                ) -> Result<Option<Self>, ExtractError> {
+                    Ok(T::try_from_gd_opt(res, ctx)?.map($p::new))
+                }
+            }
+
+            impl<T: FromGdResArray> FromGdResArray for $p<T> {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    ctx: &mut ExtractContext,
+                ) -> Result<Vec<Self>, ExtractError> {
+                    Ok(T::try_from_gd_array(res, ctx)?
+                        .into_iter()
+                        .map($p::new)
+                        .collect())
+                }
+            }
+
+            impl<T: AsGdResDictElement> AsGdResDictElement for $p<T> {
+                type GdElement = T::GdElement;
+
+                fn to_gd_element(&self) -> Self::GdElement {
+                    (**self).to_gd_element()
+                }
+
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    ctx: &mut ExtractContext,
+                ) -> Result<Self, ExtractError> {
+                    T::try_from_gd_element(element, ctx).map($p::new)
+                }
+
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    (**self).apply_to_gd_element(res)
+                }
+
+                fn default_gd_element() -> Variant {
+                    T::default_gd_element()
+                }
+            }
+        )*
+    };
+}
+
+impl_pointer_from_gd_res!(Box, Rc);
+
+// `Arc`s extracted from the same resource are shared, see `FromGdRes::try_arc_from_gd_res`.
+impl<T: FromGdRes> FromGdRes for Arc<T> {
+    fn try_from_gd_res_with(
+        res: &Self::ResType,
+        ctx: &mut ExtractContext,
+    ) -> Result<Self, ExtractError> {
+        T::try_arc_from_gd_res(res, ctx)
+    }
+}
+
+impl<T: FromGdResOpt> FromGdResOpt for Arc<T> {
+    fn try_from_gd_opt(
+        res: &Self::GdOption,
+        ctx: &mut ExtractContext,
+    ) -> Result<Option<Self>, ExtractError> {
+        T::try_arc_from_gd_opt(res, ctx)
+    }
+}
+
+impl<T: FromGdResArray> FromGdResArray for Arc<T> {
+    fn try_from_gd_array(
+        res: &Self::GdArray,
+        ctx: &mut ExtractContext,
+    ) -> Result<Vec<Self>, ExtractError> {
+        T::try_arc_from_gd_array(res, ctx)
+    }
+}
+
+impl<T: AsGdResDictElement> AsGdResDictElement for Arc<T> {
+    type GdElement = T::GdElement;
+
+    fn to_gd_element(&self) -> Self::GdElement {
+        (**self).to_gd_element()
+    }
+
+    fn try_from_gd_element(
+        element: &Self::GdElement,
+        ctx: &mut ExtractContext,
+    ) -> Result<Self, ExtractError> {
+        T::try_arc_from_gd_element(element, ctx)
+    }
+
+    fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+        (**self).apply_to_gd_element(res)
+    }
+
+    fn default_gd_element() -> Variant {
+        T::default_gd_element()
+    }
+}
@@ -16,6 +16,107 @@ macro_rules! impl_wrapped_as_gd_res {
     () => {};
 }
 
+/// Implements `ToGdRes`, `ToGdResOpt` and `ToGdResArray` for a type registered with
+/// `impl_wrapped_as_gd_res!`, given a function that builds the Godot object from a
+/// reference to the Rust value.
+///
+/// Applying a value onto an existing object keeps the object if it already
+/// extracts to an equal value, and replaces it otherwise.
+#[macro_export]
+macro_rules! impl_wrapped_to_gd_res {
+    ($t_rust:ty, $to_gd:expr) => {
+        impl ToGdRes for $t_rust {
+            fn to_gd_res(&self) -> Self::ResType {
+                $crate::on_editor($to_gd(self))
+            }
+
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                $crate::apply_to_on_editor(self, res, $to_gd, |v, gd| {
+                    if gd.extract() == *v {
+                        return false;
+                    }
+                    *gd = $to_gd(v);
+                    true
+                })
+            }
+        }
+
+        impl ToGdResOpt for $t_rust {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map($to_gd)
+            }
+
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                $crate::apply_to_option(value, res, $to_gd, |v, gd| {
+                    if gd.extract() == *v {
+                        return false;
+                    }
+                    *gd = $to_gd(v);
+                    true
+                })
+            }
+        }
+
+        impl ToGdResArray for $t_rust {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map($to_gd).collect()
+            }
+
+            fn apply_to_gd_array<'a>(
+                values: impl IntoIterator<Item = &'a Self>,
+                res: &mut Self::GdArray,
+            ) -> bool {
+                $crate::apply_to_array(values, res, $to_gd, |v, gd| {
+                    if gd.extract() == *v {
+                        return false;
+                    }
+                    *gd = $to_gd(v);
+                    true
+                })
+            }
+        }
+    };
+    () => {};
+}
+
+/// Implements `AsGdResDictElement` for a type registered with `impl_wrapped_as_gd_res!`,
+/// so it can be a `HashMap`/`BTreeMap` value.
+#[macro_export]
+macro_rules! impl_wrapped_dict_element {
+    ($t_rust:ty, $t_gd:ty, $to_gd:expr) => {
+        impl $crate::AsGdResDictElement for $t_rust {
+            type GdElement = Gd<$t_gd>;
+
+            fn to_gd_element(&self) -> Self::GdElement {
+                $to_gd(self)
+            }
+
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut $crate::ExtractContext,
+            ) -> Result<Self, $crate::ExtractError> {
+                $crate::TryExtractGd::try_extract_with(element, ctx)
+            }
+
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut $crate::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, $crate::ExtractError> {
+                $crate::try_arc_from_object(element, ctx)
+            }
+
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                if res.extract() == *self {
+                    return false;
+                }
+                *res = $to_gd(self);
+                true
+            }
+        }
+    };
+    () => {};
+}
+
 /// Implements `AsGdRes` for a Godot builtin type.
 /// Note that this means will NOT be nullable
 #[macro_export]
@@ -0,0 +1,29 @@
+/// Variant metadata for `#[derive(AsGdEnumSimple)]` enums.
+///
+/// The derive implements this along with `FromStr` and `Display`, which parse and
+/// write the same names as [`SimpleEnumVariants::name`]. It doesn't need Godot, so it
+/// is also available without the `godot` feature.
+///
+/// ```ignore
+/// for element in Element::VARIANTS {
+///     println!("{}: {}", element.index(), element);
+/// }
+/// assert_eq!("Water".parse::<Element>()?, Element::Water);
+/// ```
+pub trait SimpleEnumVariants: Copy + 'static {
+    /// Every variant, in declaration order.
+    const VARIANTS: &'static [Self];
+
+    /// The name Godot and `.tres` files use for the variant, which is the variant name
+    /// unless it has `#[as_gd_enum(rename = "...")]`.
+    fn name(&self) -> &'static str;
+
+    /// The position of the variant in [`Self::VARIANTS`]. With
+    /// `#[as_gd_enum(repr = int)]` this is *not* the discriminant.
+    fn index(&self) -> usize;
+
+    /// The variant at `index` in [`Self::VARIANTS`], if there is one.
+    fn from_index(index: usize) -> Option<Self> {
+        Self::VARIANTS.get(index).copied()
+    }
+}
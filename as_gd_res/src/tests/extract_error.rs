@@ -0,0 +1,86 @@
+use crate::extract_error::{ExtractError, ExtractErrorKind, PathSegment};
+
+#[test]
+fn test_field_path_is_built_outermost_first() {
+    // Simulates an error bubbling up from
+    // `Complicated.array_simple[2].path_to_node`.
+    let err = ExtractError::unset()
+        .in_field("path_to_node")
+        .in_resource("SimpleData", "")
+        .at_index(2)
+        .in_field("array_simple")
+        .in_resource("Complicated", "res://complicated.tres");
+
+    assert_eq!(err.field_path(), "Complicated.array_simple[2].path_to_node");
+    assert_eq!(err.type_name(), Some("Complicated"));
+    assert_eq!(
+        err.segments().cloned().collect::<Vec<_>>(),
+        vec![
+            PathSegment::Field("array_simple".to_string()),
+            PathSegment::Index(2),
+            PathSegment::Field("path_to_node".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_innermost_resource_path_is_kept() {
+    let err = ExtractError::unset()
+        .in_field("curve")
+        .in_resource("Inner", "res://inner.tres::Resource_abc")
+        .in_field("inner")
+        .in_resource("Outer", "res://outer.tres");
+
+    assert_eq!(err.resource_path(), Some("res://inner.tres::Resource_abc"));
+}
+
+#[test]
+fn test_empty_resource_path_is_skipped() {
+    let err = ExtractError::unset()
+        .in_field("curve")
+        .in_resource("Inner", "")
+        .in_field("inner")
+        .in_resource("Outer", "res://outer.tres");
+
+    assert_eq!(err.resource_path(), Some("res://outer.tres"));
+}
+
+#[test]
+fn test_path_without_type_name() {
+    let err = ExtractError::null_element().at_index(0).in_field("items");
+    assert_eq!(err.field_path(), "items[0]");
+    assert_eq!(err.kind(), &ExtractErrorKind::NullElement);
+}
+
+#[test]
+fn test_dictionary_key_path() {
+    let err = ExtractError::unset()
+        .in_field("scene")
+        .in_resource("Loot", "")
+        .at_key("fire")
+        .in_field("drops")
+        .in_resource("Level", "res://level.tres");
+    assert_eq!(err.field_path(), "Level.drops[fire].scene");
+}
+
+#[test]
+fn test_display() {
+    let err = ExtractError::unset()
+        .in_field("curve")
+        .in_resource("Complicated", "res://complicated.tres");
+    assert_eq!(
+        err.to_string(),
+        "failed to extract `Complicated.curve` (in res://complicated.tres): required value is not set"
+    );
+
+    let err = ExtractError::invalid("bad value").in_field("x");
+    assert_eq!(err.to_string(), "failed to extract `x`: bad value");
+
+    let err = ExtractError::cycle()
+        .in_field("next")
+        .in_resource("DialogueNode", "");
+    assert_eq!(
+        err.to_string(),
+        "failed to extract `DialogueNode.next`: resource contains itself"
+    );
+}
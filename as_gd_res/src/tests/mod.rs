@@ -1 +1,5 @@
+mod extract_error;
 mod rust_curve;
+#[cfg(feature = "godot")]
+mod simple_enum;
+mod tres;
@@ -0,0 +1,17 @@
+use godot::builtin::VariantType;
+
+use crate::enum_array_hint_string;
+
+#[test]
+fn test_enum_array_hint_string() {
+    // `Array[String]` of variant names: `TYPE_STRING/PROPERTY_HINT_ENUM:...`
+    assert_eq!(
+        enum_array_hint_string(VariantType::STRING, "Fire,Water"),
+        "4/2:Fire,Water"
+    );
+    // `Array[int]` of discriminants with `#[as_gd_enum(repr = int)]`
+    assert_eq!(
+        enum_array_hint_string(VariantType::INT, "Low:10,High:11"),
+        "2/2:Low:10,High:11"
+    );
+}
@@ -0,0 +1,871 @@
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::engine_type_impls::{NodePathString, PackedScenePath, RustCurve};
+use crate::tres::{
+    FromTres, FromTresResource, ToTres, ToTresResource, TresDocument, TresResource, TresValue,
+    TresWriter, field, from_tres_str, load_tres, packed_to_tres, parse_value, to_tres_string,
+    toggle_field, toggle_to_tres,
+};
+use crate::{ExtractError, ExtractErrorKind};
+
+const COMPLICATED: &str =
+    include_str!("../../../resource_test_godot_project/test_complicated_resource.tres");
+
+/// Hand-written equivalent of what `#[derive(AsGdRes)]` generates.
+#[derive(Debug, PartialEq)]
+struct SimpleData {
+    name: String,
+    value: i32,
+    int_vec: Vec<u8>,
+    path_to_node: NodePathString,
+}
+
+impl FromTresResource for SimpleData {
+    fn from_tres_resource(res: &TresResource, doc: &TresDocument) -> Result<Self, ExtractError> {
+        (|| {
+            res.expect_type("SimpleDataResource")?;
+            Ok(Self {
+                name: field(res, doc, "name")?,
+                value: field(res, doc, "value")?,
+                int_vec: field(res, doc, "int_vec")?,
+                path_to_node: field(res, doc, "path_to_node")?,
+            })
+        })()
+        .map_err(|e: ExtractError| e.in_resource("SimpleData", &doc.resource_path(res)))
+    }
+}
+
+impl FromTres for SimpleData {
+    fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+        doc.resolve(value, Self::from_tres_resource)
+    }
+}
+
+impl ToTresResource for SimpleData {
+    fn tres_type_name(&self) -> String {
+        "SimpleDataResource".to_string()
+    }
+
+    fn to_tres_properties(&self, writer: &mut TresWriter) -> Vec<(String, TresValue)> {
+        vec![
+            ("name".to_string(), self.name.to_tres(writer)),
+            ("value".to_string(), self.value.to_tres(writer)),
+            ("int_vec".to_string(), self.int_vec.to_tres(writer)),
+            (
+                "path_to_node".to_string(),
+                self.path_to_node.to_tres(writer),
+            ),
+        ]
+    }
+}
+
+impl ToTres for SimpleData {
+    fn to_tres(&self, writer: &mut TresWriter) -> TresValue {
+        writer.add_sub_resource(self)
+    }
+
+    fn tres_type() -> String {
+        "SimpleDataResource".to_string()
+    }
+}
+
+/// A resource with sub-resources and ext-resources, to test the writer.
+struct Scenes {
+    scenes: Vec<PackedScenePath>,
+    data: Vec<SimpleData>,
+    curve: Option<RustCurve>,
+}
+
+impl ToTresResource for Scenes {
+    fn tres_type_name(&self) -> String {
+        "ScenesResource".to_string()
+    }
+
+    fn to_tres_properties(&self, writer: &mut TresWriter) -> Vec<(String, TresValue)> {
+        vec![
+            ("scenes".to_string(), self.scenes.to_tres(writer)),
+            ("data".to_string(), self.data.to_tres(writer)),
+            ("curve".to_string(), self.curve.to_tres(writer)),
+        ]
+    }
+}
+
+fn simple(name: &str, value: i32) -> SimpleData {
+    SimpleData {
+        name: name.to_string(),
+        value,
+        int_vec: vec![1, 2],
+        path_to_node: NodePathString("A/B".to_string()),
+    }
+}
+
+fn complicated() -> TresDocument {
+    TresDocument::parse(COMPLICATED).unwrap()
+}
+
+fn read<T: FromTres>(doc: &TresDocument, name: &str) -> Result<T, ExtractError> {
+    field(doc.resource(), doc, name)
+}
+
+#[test]
+fn test_parse_sections() {
+    let doc = complicated();
+    assert_eq!(doc.resource().type_name(), "ComplicatedResource");
+    assert_eq!(
+        doc.ext_resource("1_2kx5d").unwrap().path,
+        "res://another_scene.tscn"
+    );
+    let sub = doc.sub_resource("HealDataResource_buypi").unwrap();
+    assert_eq!(sub.type_name(), "HealDataResource");
+    assert_eq!(sub.property("duration"), Some(&TresValue::Int(43)));
+    assert_eq!(
+        doc.resource().property("nested_enum"),
+        Some(&TresValue::SubResource(
+            "PowerUpDataResource_8pr8v".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_read_fields() {
+    let doc = complicated();
+    assert_eq!(read::<i32>(&doc, "value").unwrap(), 2);
+    assert_eq!(read::<Vec<u8>>(&doc, "int_vec").unwrap(), vec![1, 2]);
+    assert_eq!(read::<String>(&doc, "string").unwrap(), "");
+    assert_eq!(
+        read::<PackedScenePath>(&doc, "path").unwrap(),
+        PackedScenePath("res://another_scene.tscn".to_string())
+    );
+    assert_eq!(
+        read::<Option<PackedScenePath>>(&doc, "path_option").unwrap(),
+        None
+    );
+    assert_eq!(
+        read::<Vec<NodePathString>>(&doc, "node_path_array").unwrap(),
+        vec![
+            NodePathString("ChildNode2".to_string()),
+            NodePathString("ChildNode2/GrandChildNode2".to_string()),
+        ]
+    );
+    assert_eq!(
+        read::<Vec<SimpleData>>(&doc, "array_simple").unwrap()[1],
+        SimpleData {
+            name: "b".to_string(),
+            value: 2,
+            int_vec: vec![],
+            path_to_node: NodePathString(String::new()),
+        }
+    );
+}
+
+#[test]
+fn test_missing_resource_is_unset() {
+    let doc = complicated();
+    let err = read::<SimpleData>(&doc, "nested_simple_option").unwrap_err();
+    assert_eq!(err.kind(), &ExtractErrorKind::Unset);
+    assert_eq!(err.field_path(), "nested_simple_option");
+}
+
+#[test]
+fn test_error_path_into_sub_resource() {
+    let src = COMPLICATED.replace(
+        "value = 2\n\n[sub_resource type=\"Curve\"",
+        "value = 3000000000\n\n[sub_resource type=\"Curve\"",
+    );
+    let doc = TresDocument::parse(&src).unwrap();
+    let err = read::<Vec<SimpleData>>(&doc, "array_simple").unwrap_err();
+    assert_eq!(err.field_path(), "SimpleData.array_simple[1].value");
+    assert_eq!(
+        err.kind(),
+        &ExtractErrorKind::Invalid("3000000000 does not fit in `i32`".to_string())
+    );
+    // Parsed from a string, so there is no file path to report.
+    assert_eq!(err.resource_path(), None);
+}
+
+#[test]
+fn test_load_uses_res_paths() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../resource_test_godot_project/test_complicated_resource.tres"
+    );
+    let doc = TresDocument::load(path).unwrap();
+    assert_eq!(doc.path(), "res://test_complicated_resource.tres");
+    let res = doc.sub_resource("SimpleDataResource_ia1lp").unwrap();
+    assert_eq!(
+        doc.resource_path(res),
+        "res://test_complicated_resource.tres::SimpleDataResource_ia1lp"
+    );
+}
+
+/// A resource that links to the next one, possibly in another file.
+#[derive(Debug, PartialEq)]
+struct Link {
+    value: i32,
+    next: Option<Box<Link>>,
+}
+
+impl FromTresResource for Link {
+    fn from_tres_resource(res: &TresResource, doc: &TresDocument) -> Result<Self, ExtractError> {
+        Ok(Self {
+            value: field(res, doc, "value")?,
+            next: field(res, doc, "next")?,
+        })
+    }
+}
+
+impl FromTres for Link {
+    fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+        doc.resolve(value, Self::from_tres_resource)
+    }
+}
+
+/// Writes a Godot project with one `Link` file per `(name, value, next)`.
+fn link_project(name: &str, links: &[(&str, i32, Option<&str>)]) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("as_gd_res_{}_{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("project.godot"), "").unwrap();
+    for (file, value, next) in links {
+        let src = match next {
+            Some(next) => format!(
+                "[gd_resource type=\"LinkResource\" load_steps=2 format=3]\n\n\
+                 [ext_resource type=\"Resource\" path=\"res://{}.tres\" id=\"1\"]\n\n\
+                 [resource]\nvalue = {}\nnext = ExtResource(\"1\")\n",
+                next, value
+            ),
+            None => format!(
+                "[gd_resource type=\"LinkResource\" format=3]\n\n[resource]\nvalue = {}\n",
+                value
+            ),
+        };
+        std::fs::write(dir.join(format!("{}.tres", file)), src).unwrap();
+    }
+    dir
+}
+
+#[test]
+fn test_ext_resources_are_loaded_once() {
+    let dir = link_project("loaded_once", &[("a", 1, Some("b")), ("b", 2, None)]);
+    let doc = TresDocument::load(dir.join("a.tres")).unwrap();
+    let expected = Link {
+        value: 1,
+        next: Some(Box::new(Link {
+            value: 2,
+            next: None,
+        })),
+    };
+    assert_eq!(doc.extract::<Link>().unwrap(), expected);
+    // Read from the cache the second time.
+    std::fs::remove_file(dir.join("b.tres")).unwrap();
+    assert_eq!(doc.extract::<Link>().unwrap(), expected);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_ext_resource_cycle() {
+    let dir = link_project("cycle", &[("a", 1, Some("b")), ("b", 2, Some("a"))]);
+    let err = load_tres::<Link>(dir.join("a.tres")).unwrap_err();
+    assert!(
+        err.to_string().contains("resource contains itself"),
+        "{}",
+        err
+    );
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_loaded_document_outliving_its_owner() {
+    let dir = link_project(
+        "outlived",
+        &[("a", 1, Some("b")), ("b", 2, Some("c")), ("c", 3, None)],
+    );
+    let doc = TresDocument::load(dir.join("a.tres")).unwrap();
+    let next = doc.resource().property("next").unwrap();
+    let b = doc.resolve(next, |_, b| Ok(b.clone())).unwrap();
+    drop(doc);
+    let err = b.extract::<Link>().unwrap_err();
+    assert!(err.to_string().contains("was dropped"), "{}", err);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_wrong_resource_type() {
+    let err = from_tres_str::<SimpleData>(COMPLICATED).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("expected a `SimpleDataResource`, found a `ComplicatedResource`")
+    );
+}
+
+#[test]
+fn test_curve_matches_godot_sampling() {
+    // Values printed by `ExtractGd for Gd<Curve>` for `Curve_coq8p` in Godot
+    // (see `rust_print_output_expected.txt`).
+    #[rustfmt::skip]
+    let expected = [
+        0.0, 0.06349207, 0.12698413, 0.1904762, 0.25396827, 0.31746033, 0.3809524, 0.44444445,
+        0.5079366, 0.57142854, 0.6349206, 0.69841266, 0.7619047, 0.82539684, 0.88888884, 0.952381,
+        0.99206346, 0.96031743, 0.9285714, 0.8968253, 0.8650794, 0.8333333, 0.8015873, 0.76984125,
+        0.7380952, 0.70634925, 0.6746031, 0.6428571, 0.61111116, 0.5793651, 0.54761904, 0.515873,
+        0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5,
+        0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5,
+    ];
+    let doc = complicated();
+    let curve = read::<RustCurve>(&doc, "curve").unwrap();
+    assert_eq!(
+        curve,
+        RustCurve::new_for_test(expected, 0.56638974, 0.0, 1.0)
+    );
+
+    let curves = read::<Vec<RustCurve>>(&doc, "curve_array").unwrap();
+    assert_eq!(curves[0].integral(), 0.50793654);
+    assert_eq!(curves[1].integral(), 0.5079365);
+}
+
+#[test]
+fn test_parse_values() {
+    assert_eq!(
+        parse_value(r#""a \"quoted\"\nline \u00e9!""#),
+        Ok(TresValue::String("a \"quoted\"\nline é!".to_string()))
+    );
+    assert_eq!(
+        parse_value("&\"name\""),
+        Ok(TresValue::StringName("name".to_string()))
+    );
+    assert_eq!(parse_value("-1e-05"), Ok(TresValue::Float(-1e-05)));
+    assert_eq!(
+        parse_value("inf_neg"),
+        Ok(TresValue::Float(f64::NEG_INFINITY))
+    );
+    assert_eq!(
+        parse_value("PackedByteArray(\"AQID\")"),
+        Ok(TresValue::Array(vec![
+            TresValue::Int(1),
+            TresValue::Int(2),
+            TresValue::Int(3)
+        ]))
+    );
+    assert_eq!(
+        parse_value("{\n\"a\": 1,\n\"b\": [2]\n}"),
+        Ok(TresValue::Dictionary(vec![
+            (TresValue::String("a".to_string()), TresValue::Int(1)),
+            (
+                TresValue::String("b".to_string()),
+                TresValue::Array(vec![TresValue::Int(2)])
+            ),
+        ]))
+    );
+    assert_eq!(
+        parse_value("Dictionary[String, ExtResource(\"1_ab\")]({\n\"a\": null\n})"),
+        Ok(TresValue::TypedDictionary(
+            "String".to_string(),
+            "ExtResource(\"1_ab\")".to_string(),
+            vec![(TresValue::String("a".to_string()), TresValue::Null)]
+        ))
+    );
+    assert_eq!(
+        parse_value("PackedVector2Array(0, 1, 2, 3)"),
+        Ok(TresValue::Array(vec![
+            TresValue::Constructor {
+                name: "Vector2".to_string(),
+                args: vec![TresValue::Int(0), TresValue::Int(1)],
+            },
+            TresValue::Constructor {
+                name: "Vector2".to_string(),
+                args: vec![TresValue::Int(2), TresValue::Int(3)],
+            },
+        ]))
+    );
+    assert!(parse_value("PackedColorArray(0, 1, 2)").is_err());
+    assert_eq!(
+        parse_value("Vector2(0.5, -1)"),
+        Ok(TresValue::Constructor {
+            name: "Vector2".to_string(),
+            args: vec![TresValue::Float(0.5), TresValue::Int(-1)],
+        })
+    );
+}
+
+#[test]
+fn test_parse_error_line() {
+    let err =
+        TresDocument::parse("[gd_resource type=\"X\" format=3]\n\n[resource]\nvalue = [1, 2\n")
+            .unwrap_err();
+    assert_eq!(err.line, 5);
+}
+
+#[test]
+fn test_parse_typed_array() {
+    assert_eq!(
+        parse_value("Array[NodePath]([NodePath(\"A\")])"),
+        Ok(TresValue::TypedArray(
+            "NodePath".to_string(),
+            vec![TresValue::NodePath("A".to_string())]
+        ))
+    );
+    // Truncated type arguments are an error, not a panic
+    assert!(parse_value("Array[").is_err());
+    assert!(parse_value("Array[int").is_err());
+    assert!(parse_value("Dictionary[String, Array[int]").is_err());
+}
+
+#[test]
+fn test_maps() {
+    let doc = TresDocument::parse(
+        r#"[gd_resource type="MapsResource" load_steps=2 format=3]
+
+[sub_resource type="SimpleDataResource" id="SimpleDataResource_a"]
+value = 7
+
+[resource]
+counts = Dictionary[String, int]({
+"b": 2,
+"a": 1
+})
+data = Dictionary[int, SimpleDataResource]({
+3: SubResource("SimpleDataResource_a")
+})
+bad = {
+"x": 1.5
+}
+"#,
+    )
+    .unwrap();
+
+    let counts: BTreeMap<String, i32> = read(&doc, "counts").unwrap();
+    assert_eq!(
+        counts,
+        BTreeMap::from([("a".to_string(), 1), ("b".to_string(), 2)])
+    );
+    let data: HashMap<u8, SimpleData> = read(&doc, "data").unwrap();
+    assert_eq!(data[&3].value, 7);
+    let missing: HashMap<String, i32> = read(&doc, "missing").unwrap();
+    assert!(missing.is_empty());
+
+    let err = read::<HashMap<String, i32>>(&doc, "bad").unwrap_err();
+    assert_eq!(err.field_path(), "bad[x]");
+
+    let mut writer = TresWriter::new();
+    assert_eq!(
+        counts.to_tres(&mut writer).to_string(),
+        "Dictionary[String, int]({\n\"a\": 1,\n\"b\": 2\n})"
+    );
+    let counts: HashMap<_, _> = counts.into_iter().collect();
+    assert_eq!(
+        counts.to_tres(&mut writer).to_string(),
+        "Dictionary[String, int]({\n\"a\": 1,\n\"b\": 2\n})"
+    );
+    let data = BTreeMap::from([(3u8, simple("c", 1))]);
+    assert_eq!(
+        data.to_tres(&mut writer).to_string(),
+        "Dictionary[int, SimpleDataResource]({\n3: SubResource(\"SimpleDataResource_1\")\n})"
+    );
+}
+
+#[test]
+fn test_pointers() {
+    let doc = TresDocument::parse(
+        r#"[gd_resource type="PointersResource" load_steps=2 format=3]
+
+[sub_resource type="SimpleDataResource" id="SimpleDataResource_a"]
+value = 7
+
+[resource]
+boxed = SubResource("SimpleDataResource_a")
+shared = Array[int]([1, 2])
+"#,
+    )
+    .unwrap();
+
+    let boxed: Box<SimpleData> = read(&doc, "boxed").unwrap();
+    assert_eq!(boxed.value, 7);
+    let shared: Arc<Vec<i32>> = read(&doc, "shared").unwrap();
+    assert_eq!(*shared, vec![1, 2]);
+    let missing: Option<Rc<SimpleData>> = read(&doc, "missing").unwrap();
+    assert_eq!(missing, None);
+
+    let mut writer = TresWriter::new();
+    assert_eq!(
+        Box::new(simple("c", 1)).to_tres(&mut writer).to_string(),
+        "SubResource(\"SimpleDataResource_1\")"
+    );
+    assert_eq!(
+        vec![Rc::new(1u8)].to_tres(&mut writer).to_string(),
+        "Array[int]([1])"
+    );
+}
+
+#[test]
+fn test_optional_values() {
+    let doc = TresDocument::parse(
+        r#"[gd_resource type="TuningResource" format=3]
+
+[resource]
+speed_enabled = true
+speed = 2.5
+max_hp_enabled = true
+label_enabled = true
+label = "boss"
+target_enabled = true
+target = NodePath("../Player")
+scale = 2.0
+cleared_enabled = false
+cleared = true
+"#,
+    )
+    .unwrap();
+    fn read_toggle<T: FromTres>(doc: &TresDocument, name: &str) -> Option<T> {
+        toggle_field(doc.resource(), doc, &format!("{}_enabled", name), name).unwrap()
+    }
+
+    assert_eq!(read_toggle::<f32>(&doc, "speed"), Some(2.5));
+    // Godot omits a value equal to its default.
+    assert_eq!(read_toggle::<i32>(&doc, "max_hp"), Some(0));
+    assert_eq!(
+        read_toggle::<String>(&doc, "label"),
+        Some("boss".to_string())
+    );
+    assert_eq!(
+        read_toggle::<NodePathString>(&doc, "target"),
+        Some(NodePathString("../Player".to_string()))
+    );
+    // The value is ignored while the toggle is off or missing.
+    assert_eq!(read_toggle::<f64>(&doc, "scale"), None);
+    assert_eq!(read_toggle::<bool>(&doc, "cleared"), None);
+    assert_eq!(read_toggle::<bool>(&doc, "missing"), None);
+    let err = toggle_field::<i32>(doc.resource(), &doc, "label_enabled", "label").unwrap_err();
+    assert_eq!(err.field_path(), "label");
+
+    let mut writer = TresWriter::new();
+    assert_eq!(toggle_to_tres(Some(&3i32), &mut writer).to_string(), "3");
+    assert_eq!(
+        toggle_to_tres(None::<&String>, &mut writer).to_string(),
+        "\"\""
+    );
+    assert_eq!(
+        toggle_to_tres(None::<&NodePathString>, &mut writer).to_string(),
+        "NodePath(\"\")"
+    );
+}
+
+#[test]
+fn test_packed_arrays() {
+    let mut writer = TresWriter::new();
+    assert_eq!(
+        packed_to_tres(&[1i32, -2], &mut writer).to_string(),
+        "PackedInt32Array(1, -2)"
+    );
+    assert_eq!(
+        packed_to_tres(&[1i64 << 40], &mut writer).to_string(),
+        "PackedInt64Array(1099511627776)"
+    );
+    assert_eq!(
+        packed_to_tres(&[0.5f32, 1.0], &mut writer).to_string(),
+        "PackedFloat32Array(0.5, 1.0)"
+    );
+    assert_eq!(
+        packed_to_tres(&Vec::<f64>::new(), &mut writer).to_string(),
+        "PackedFloat64Array()"
+    );
+    assert_eq!(
+        packed_to_tres(&Vec::<u8>::new(), &mut writer).to_string(),
+        "PackedByteArray()"
+    );
+    assert_eq!(
+        packed_to_tres(&[1u8, 2], &mut writer).to_string(),
+        "PackedByteArray(\"AQI=\")"
+    );
+
+    // Without `#[as_gd_res(packed)]`, numbers are written as typed arrays.
+    assert_eq!(
+        vec![1u8, 2].to_tres(&mut writer).to_string(),
+        "Array[int]([1, 2])"
+    );
+    assert_eq!(
+        vec![0.5f64].to_tres(&mut writer).to_string(),
+        "Array[float]([0.5])"
+    );
+
+    // Either form can be read back.
+    let doc = complicated();
+    let value = parse_value("PackedInt32Array(1, 2)").unwrap();
+    assert_eq!(Vec::<i32>::from_tres(&value, &doc).unwrap(), [1, 2]);
+    let value = parse_value("Array[int]([1, 2])").unwrap();
+    assert_eq!(Vec::<i32>::from_tres(&value, &doc).unwrap(), [1, 2]);
+
+    // Every padding length survives the base64 round trip.
+    for len in 0..=7u8 {
+        let bytes: Vec<u8> = (0..len).map(|i| i.wrapping_mul(97) ^ 0xa5).collect();
+        let written = packed_to_tres(&bytes, &mut writer).to_string();
+        let value = parse_value(&written).unwrap();
+        assert_eq!(
+            Vec::<u8>::from_tres(&value, &doc).unwrap(),
+            bytes,
+            "{}",
+            written
+        );
+    }
+}
+
+#[test]
+fn test_fixed_size_arrays() {
+    let doc = complicated();
+    let mut writer = TresWriter::new();
+    assert_eq!(
+        [0.5f32, 1.0].to_tres(&mut writer).to_string(),
+        "Array[float]([0.5, 1.0])"
+    );
+
+    let value = parse_value("PackedInt32Array(1, 2, 3)").unwrap();
+    assert_eq!(<[i32; 3]>::from_tres(&value, &doc).unwrap(), [1, 2, 3]);
+    assert_eq!(
+        <[i32; 2]>::from_tres(&value, &doc).unwrap_err().kind(),
+        &ExtractErrorKind::Invalid("expected 2 array elements, found 3".to_string())
+    );
+    assert_eq!(
+        crate::pad_array::<i32, 4>(Vec::from_tres(&value, &doc).unwrap()),
+        [1, 2, 3, 0]
+    );
+    assert_eq!(
+        crate::pad_array::<i32, 2>(Vec::from_tres(&value, &doc).unwrap()),
+        [1, 2]
+    );
+
+    // A missing array still holds the `N` default elements of a new resource.
+    assert_eq!(<[String; 2]>::from_tres_missing().unwrap(), ["", ""]);
+    assert_eq!(
+        <[SimpleData; 1]>::from_tres_missing().unwrap_err().kind(),
+        &ExtractErrorKind::Unset
+    );
+}
+
+#[test]
+fn test_tuples() {
+    let doc = complicated();
+    let mut writer = TresWriter::new();
+    let drops = vec![("gold".to_string(), 3), ("gem".to_string(), 1)];
+    let value = drops.to_tres(&mut writer);
+    assert_eq!(
+        value.to_string(),
+        r#"Array[Array]([["gold", 3], ["gem", 1]])"#
+    );
+    assert_eq!(
+        Vec::<(String, i32)>::from_tres(&value, &doc).unwrap(),
+        drops
+    );
+
+    let value = parse_value("[0.5, 2, null]").unwrap();
+    assert_eq!(
+        <(f32, f32)>::from_tres(&value, &doc).unwrap_err().kind(),
+        &ExtractErrorKind::Invalid("expected 2 tuple elements, found 3".to_string())
+    );
+    let err = <(f32, f32, f32)>::from_tres(&value, &doc).unwrap_err();
+    assert_eq!(err.kind(), &ExtractErrorKind::NullElement);
+    assert_eq!(err.field_path(), "[2]");
+
+    // A missing tuple holds the default elements of a new resource.
+    assert_eq!(
+        <(f32, String)>::from_tres_missing().unwrap(),
+        (0.0, String::new())
+    );
+    let err = <(i32, SimpleData)>::from_tres_missing().unwrap_err();
+    assert_eq!(err.kind(), &ExtractErrorKind::Unset);
+    assert_eq!(err.field_path(), "[1]");
+}
+
+#[cfg(feature = "godot")]
+#[test]
+fn test_builtins() {
+    use godot::builtin::{Color, Transform2D, Vector2, Vector2i, Vector4};
+
+    let doc = TresDocument::parse(
+        r#"[gd_resource type="BuiltinsResource" format=3]
+
+[resource]
+position = Vector2(0.5, -1)
+cell = Vector2i(3, 4)
+points = PackedVector2Array(0, 1, 2, 3)
+colors = PackedColorArray(1, 0, 0, 1)
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(read(&doc, "position"), Ok(Vector2::new(0.5, -1.0)));
+    assert_eq!(read(&doc, "cell"), Ok(Vector2i::new(3, 4)));
+    assert_eq!(
+        read(&doc, "points"),
+        Ok(vec![Vector2::new(0.0, 1.0), Vector2::new(2.0, 3.0)])
+    );
+    assert_eq!(
+        read(&doc, "colors"),
+        Ok(vec![Color::from_rgba(1.0, 0.0, 0.0, 1.0)])
+    );
+    // Missing properties get Godot's defaults, which aren't always zero.
+    assert_eq!(
+        read(&doc, "transform"),
+        Ok(Transform2D::from_cols(
+            Vector2::new(1.0, 0.0),
+            Vector2::new(0.0, 1.0),
+            Vector2::new(0.0, 0.0),
+        ))
+    );
+    assert_eq!(
+        read::<Vector2i>(&doc, "position"),
+        Err(
+            ExtractError::invalid("expected a `Vector2i`, found `Vector2(0.5, -1)`")
+                .in_field("position")
+        )
+    );
+
+    let mut writer = TresWriter::new();
+    assert_eq!(
+        vec![Vector2::new(0.5, 1.0)]
+            .to_tres(&mut writer)
+            .to_string(),
+        "PackedVector2Array(0.5, 1.0)"
+    );
+    assert_eq!(
+        vec![Vector2i::new(1, 2)].to_tres(&mut writer).to_string(),
+        "Array[Vector2i]([Vector2i(1, 2)])"
+    );
+
+    let corners = vec![
+        Vector4::new(0.5, 1.0, 2.0, 3.0),
+        Vector4::new(4.0, 5.0, 6.0, 7.0),
+    ];
+    let value = corners.to_tres(&mut writer);
+    assert_eq!(
+        value.to_string(),
+        "PackedVector4Array(0.5, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0)"
+    );
+    let value = parse_value(&value.to_string()).unwrap();
+    assert_eq!(Vec::<Vector4>::from_tres(&value, &doc).unwrap(), corners);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam() {
+    use glam::{Mat3, Vec2, Vec3};
+
+    let doc = TresDocument::parse(
+        r#"[gd_resource type="GlamResource" format=3]
+
+[resource]
+basis = Basis(1, 2, 3, 4, 5, 6, 7, 8, 9)
+"#,
+    )
+    .unwrap();
+
+    // Godot writes a basis row by row.
+    let mat: Mat3 = read(&doc, "basis").unwrap();
+    assert_eq!(mat.x_axis, Vec3::new(1.0, 4.0, 7.0));
+    assert_eq!(
+        mat.to_tres(&mut TresWriter::new()).to_string(),
+        "Basis(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0)"
+    );
+
+    assert_eq!(
+        vec![Vec2::new(0.5, 1.0)]
+            .to_tres(&mut TresWriter::new())
+            .to_string(),
+        "PackedVector2Array(0.5, 1.0)"
+    );
+}
+
+#[test]
+fn test_write_simple() {
+    let text = to_tres_string(&SimpleData {
+        name: "say \"hi\"".to_string(),
+        ..simple("", 3)
+    });
+    assert_eq!(
+        text,
+        r#"[gd_resource type="SimpleDataResource" format=3]
+
+[resource]
+name = "say \"hi\""
+value = 3
+int_vec = Array[int]([1, 2])
+path_to_node = NodePath("A/B")
+"#
+    );
+    assert_eq!(
+        from_tres_str::<SimpleData>(&text).unwrap().name,
+        "say \"hi\""
+    );
+}
+
+#[test]
+fn test_write_sub_and_ext_resources() {
+    let value = Scenes {
+        scenes: vec![
+            PackedScenePath("res://a.tscn".to_string()),
+            PackedScenePath("res://b.tscn".to_string()),
+            PackedScenePath("res://a.tscn".to_string()),
+        ],
+        data: vec![simple("x", 1), simple("y", 2)],
+        curve: None,
+    };
+    let text = to_tres_string(&value);
+    assert_eq!(
+        text,
+        r#"[gd_resource type="ScenesResource" load_steps=5 format=3]
+
+[ext_resource type="PackedScene" path="res://a.tscn" id="1"]
+
+[ext_resource type="PackedScene" path="res://b.tscn" id="2"]
+
+[sub_resource type="SimpleDataResource" id="SimpleDataResource_1"]
+name = "x"
+value = 1
+int_vec = Array[int]([1, 2])
+path_to_node = NodePath("A/B")
+
+[sub_resource type="SimpleDataResource" id="SimpleDataResource_2"]
+name = "y"
+value = 2
+int_vec = Array[int]([1, 2])
+path_to_node = NodePath("A/B")
+
+[resource]
+scenes = Array[PackedScene]([ExtResource("1"), ExtResource("2"), ExtResource("1")])
+data = Array[SimpleDataResource]([SubResource("SimpleDataResource_1"), SubResource("SimpleDataResource_2")])
+"#
+    );
+
+    let doc = TresDocument::parse(&text).unwrap();
+    assert_eq!(
+        read::<Vec<PackedScenePath>>(&doc, "scenes").unwrap(),
+        value.scenes
+    );
+    assert_eq!(read::<Vec<SimpleData>>(&doc, "data").unwrap(), value.data);
+}
+
+#[test]
+fn test_write_curve_round_trip() {
+    let doc = complicated();
+    let curve = read::<RustCurve>(&doc, "curve").unwrap();
+    let text = to_tres_string(&Scenes {
+        scenes: vec![],
+        data: vec![],
+        curve: Some(curve.clone()),
+    });
+    let written = TresDocument::parse(&text).unwrap();
+    let round_trip = read::<RustCurve>(&written, "curve").unwrap();
+    for i in 0..=20 {
+        let x = i as f32 / 20.0;
+        let (a, b) = (
+            curve.try_sample(x).unwrap(),
+            round_trip.try_sample(x).unwrap(),
+        );
+        assert!((a - b).abs() < 1e-5, "{} != {} at {}", a, b, x);
+    }
+}
+
+#[test]
+fn test_display_round_trips_document() {
+    let doc = complicated();
+    assert_eq!(TresDocument::parse(&doc.to_string()).unwrap(), doc);
+}
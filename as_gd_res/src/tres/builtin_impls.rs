@@ -0,0 +1,282 @@
+//! `FromTres`/`ToTres` for Godot's builtin value types, and for the `glam` and
+//! `mint` types that are stored as them.
+
+use super::impls::{float, unexpected};
+use super::{FromTres, ToTres, TresDocument, TresValue, TresWriter};
+use crate::ExtractError;
+
+/// Godot builtins written as a constructor call with one argument per component,
+/// e.g. `Vector2(1, 2)`. Those stored in a `$packed` array on the Godot side are
+/// written as one, with the components of all elements in a row. `$default` holds
+/// the components Godot uses when the property is missing, and also fixes how many
+/// components there are.
+macro_rules! impl_tres_builtin {
+    ($(
+        $t:ty, $name:literal $(in $packed:literal)?, $component:ty, $default:expr,
+        |$v:ident| $to:expr,
+        |$c:ident| $from:expr;
+    )*) => {
+        $(
+            impl FromTres for $t {
+                fn from_tres(value: &TresValue, _doc: &TresDocument) -> Result<Self, ExtractError> {
+                    let expected = || unexpected(concat!("a `", $name, "`"), value);
+                    let args = value.as_constructor($name).ok_or_else(expected)?;
+                    let mut $c = $default;
+                    if args.len() != $c.len() {
+                        return Err(expected());
+                    }
+                    for (c, arg) in $c.iter_mut().zip(args) {
+                        *c = <$component>::from_tres_component(arg).ok_or_else(expected)?;
+                    }
+                    Ok($from)
+                }
+
+                fn from_tres_missing() -> Result<Self, ExtractError> {
+                    let $c = $default;
+                    Ok($from)
+                }
+            }
+
+            impl ToTres for $t {
+                fn to_tres(&self, _writer: &mut TresWriter) -> TresValue {
+                    let $v = self;
+                    TresValue::Constructor {
+                        name: $name.to_string(),
+                        args: $to.into_iter().map(TresComponent::to_tres_component).collect(),
+                    }
+                }
+
+                fn tres_type() -> String {
+                    $name.to_string()
+                }
+
+                $(
+                    fn to_tres_array<'a>(
+                        values: impl IntoIterator<Item = &'a Self>,
+                        _writer: &mut TresWriter,
+                    ) -> TresValue {
+                        TresValue::Constructor {
+                            name: $packed.to_string(),
+                            args: values
+                                .into_iter()
+                                .flat_map(|$v| $to)
+                                .map(TresComponent::to_tres_component)
+                                .collect(),
+                        }
+                    }
+                )?
+            }
+        )*
+    };
+}
+
+/// A component of a Godot builtin like `Vector2` or `Vector2i`.
+trait TresComponent: Sized {
+    fn from_tres_component(value: &TresValue) -> Option<Self>;
+    fn to_tres_component(self) -> TresValue;
+}
+
+impl TresComponent for f32 {
+    fn from_tres_component(value: &TresValue) -> Option<Self> {
+        value.as_float().map(|f| f as f32)
+    }
+
+    fn to_tres_component(self) -> TresValue {
+        float(self)
+    }
+}
+
+impl TresComponent for f64 {
+    fn from_tres_component(value: &TresValue) -> Option<Self> {
+        value.as_float()
+    }
+
+    fn to_tres_component(self) -> TresValue {
+        float(self)
+    }
+}
+
+impl TresComponent for i32 {
+    fn from_tres_component(value: &TresValue) -> Option<Self> {
+        value.as_int().and_then(|i| i32::try_from(i).ok())
+    }
+
+    fn to_tres_component(self) -> TresValue {
+        TresValue::Int(self as i64)
+    }
+}
+
+#[cfg(feature = "godot")]
+mod godot_builtins {
+    use godot::builtin::{
+        Aabb, Basis, Color, Plane, Projection, Quaternion, Rect2, Rect2i, Transform2D, Transform3D,
+        Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i, real,
+    };
+
+    use super::*;
+
+    impl_tres_builtin! {
+        Vector2, "Vector2" in "PackedVector2Array", real, [0.0; 2],
+        |v| [v.x, v.y],
+        |c| Vector2::new(c[0], c[1]);
+        Vector2i, "Vector2i", i32, [0; 2],
+        |v| [v.x, v.y],
+        |c| Vector2i::new(c[0], c[1]);
+        Vector3, "Vector3" in "PackedVector3Array", real, [0.0; 3],
+        |v| [v.x, v.y, v.z],
+        |c| Vector3::new(c[0], c[1], c[2]);
+        Vector3i, "Vector3i", i32, [0; 3],
+        |v| [v.x, v.y, v.z],
+        |c| Vector3i::new(c[0], c[1], c[2]);
+        Vector4, "Vector4" in "PackedVector4Array", real, [0.0; 4],
+        |v| [v.x, v.y, v.z, v.w],
+        |c| Vector4::new(c[0], c[1], c[2], c[3]);
+        Vector4i, "Vector4i", i32, [0; 4],
+        |v| [v.x, v.y, v.z, v.w],
+        |c| Vector4i::new(c[0], c[1], c[2], c[3]);
+        Color, "Color" in "PackedColorArray", f32, [0.0, 0.0, 0.0, 1.0],
+        |v| [v.r, v.g, v.b, v.a],
+        |c| Color::from_rgba(c[0], c[1], c[2], c[3]);
+        Rect2, "Rect2", real, [0.0; 4],
+        |v| [v.position.x, v.position.y, v.size.x, v.size.y],
+        |c| Rect2::from_components(c[0], c[1], c[2], c[3]);
+        Rect2i, "Rect2i", i32, [0; 4],
+        |v| [v.position.x, v.position.y, v.size.x, v.size.y],
+        |c| Rect2i::from_components(c[0], c[1], c[2], c[3]);
+        Transform2D, "Transform2D", real, [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+        |v| [v.a.x, v.a.y, v.b.x, v.b.y, v.origin.x, v.origin.y],
+        |c| Transform2D::from_cols(
+            Vector2::new(c[0], c[1]),
+            Vector2::new(c[2], c[3]),
+            Vector2::new(c[4], c[5]),
+        );
+        // Godot writes the basis row by row, followed by the origin.
+        Transform3D, "Transform3D", real, [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0],
+        |v| {
+            let [x, y, z] = v.basis.rows;
+            [x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z, v.origin.x, v.origin.y, v.origin.z]
+        },
+        |c| Transform3D::new(
+            Basis::from_rows(
+                Vector3::new(c[0], c[1], c[2]),
+                Vector3::new(c[3], c[4], c[5]),
+                Vector3::new(c[6], c[7], c[8]),
+            ),
+            Vector3::new(c[9], c[10], c[11]),
+        );
+        Basis, "Basis", real, [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+        |v| {
+            let [x, y, z] = v.rows;
+            [x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z]
+        },
+        |c| Basis::from_rows(
+            Vector3::new(c[0], c[1], c[2]),
+            Vector3::new(c[3], c[4], c[5]),
+            Vector3::new(c[6], c[7], c[8]),
+        );
+        Quaternion, "Quaternion", real, [0.0, 0.0, 0.0, 1.0],
+        |v| [v.x, v.y, v.z, v.w],
+        |c| Quaternion::new(c[0], c[1], c[2], c[3]);
+        Aabb, "AABB", real, [0.0; 6],
+        |v| [v.position.x, v.position.y, v.position.z, v.size.x, v.size.y, v.size.z],
+        |c| Aabb::new(Vector3::new(c[0], c[1], c[2]), Vector3::new(c[3], c[4], c[5]));
+        Plane, "Plane", real, [0.0; 4],
+        |v| [v.normal.x, v.normal.y, v.normal.z, v.d],
+        |c| Plane { normal: Vector3::new(c[0], c[1], c[2]), d: c[3] };
+        // Column by column.
+        Projection, "Projection", real,
+        [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+        |v| v.cols.map(|col| [col.x, col.y, col.z, col.w]).concat(),
+        |c| Projection::new([
+            Vector4::new(c[0], c[1], c[2], c[3]),
+            Vector4::new(c[4], c[5], c[6], c[7]),
+            Vector4::new(c[8], c[9], c[10], c[11]),
+            Vector4::new(c[12], c[13], c[14], c[15]),
+        ]);
+    }
+}
+
+// Stored as the Godot builtin with the same layout.
+#[cfg(feature = "glam")]
+mod glam_types {
+    use glam::{Affine2, Affine3A, IVec2, IVec3, IVec4, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+
+    use super::*;
+
+    impl_tres_builtin! {
+        Vec2, "Vector2" in "PackedVector2Array", f32, [0.0; 2],
+        |v| v.to_array(),
+        |c| Vec2::from_array(c);
+        Vec3, "Vector3" in "PackedVector3Array", f32, [0.0; 3],
+        |v| v.to_array(),
+        |c| Vec3::from_array(c);
+        Vec4, "Vector4" in "PackedVector4Array", f32, [0.0; 4],
+        |v| v.to_array(),
+        |c| Vec4::from_array(c);
+        IVec2, "Vector2i", i32, [0; 2],
+        |v| v.to_array(),
+        |c| IVec2::from_array(c);
+        IVec3, "Vector3i", i32, [0; 3],
+        |v| v.to_array(),
+        |c| IVec3::from_array(c);
+        IVec4, "Vector4i", i32, [0; 4],
+        |v| v.to_array(),
+        |c| IVec4::from_array(c);
+        Quat, "Quaternion", f32, [0.0, 0.0, 0.0, 1.0],
+        |v| v.to_array(),
+        |c| Quat::from_array(c);
+        // Godot writes a basis row by row, glam stores columns.
+        Mat3, "Basis", f32, [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+        |v| v.transpose().to_cols_array(),
+        |c| Mat3::from_cols_array(&c).transpose();
+        Affine2, "Transform2D", f32, [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+        |v| v.to_cols_array(),
+        |c| Affine2::from_cols_array(&c);
+        Affine3A, "Transform3D", f32,
+        [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0],
+        |v| {
+            let basis = Mat3::from(v.matrix3).transpose().to_cols_array();
+            let origin = v.translation.to_array();
+            [basis.as_slice(), origin.as_slice()].concat()
+        },
+        |c| Affine3A::from_mat3_translation(
+            Mat3::from_cols_slice(&c[..9]).transpose(),
+            Vec3::from_slice(&c[9..]),
+        );
+        Mat4, "Projection", f32,
+        [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+        |v| v.to_cols_array(),
+        |c| Mat4::from_cols_array(&c);
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint_types {
+    use mint::{Quaternion, Vector2, Vector3, Vector4};
+
+    use super::*;
+
+    impl_tres_builtin! {
+        Vector2<f32>, "Vector2" in "PackedVector2Array", f32, [0.0; 2],
+        |v| [v.x, v.y],
+        |c| Vector2::from(c);
+        Vector3<f32>, "Vector3" in "PackedVector3Array", f32, [0.0; 3],
+        |v| [v.x, v.y, v.z],
+        |c| Vector3::from(c);
+        Vector4<f32>, "Vector4" in "PackedVector4Array", f32, [0.0; 4],
+        |v| [v.x, v.y, v.z, v.w],
+        |c| Vector4::from(c);
+        Vector2<i32>, "Vector2i", i32, [0; 2],
+        |v| [v.x, v.y],
+        |c| Vector2::from(c);
+        Vector3<i32>, "Vector3i", i32, [0; 3],
+        |v| [v.x, v.y, v.z],
+        |c| Vector3::from(c);
+        Vector4<i32>, "Vector4i", i32, [0; 4],
+        |v| [v.x, v.y, v.z, v.w],
+        |c| Vector4::from(c);
+        Quaternion<f32>, "Quaternion", f32, [0.0, 0.0, 0.0, 1.0],
+        |v| [v.v.x, v.v.y, v.v.z, v.s],
+        |c| Quaternion::from(c);
+    }
+}
@@ -0,0 +1,654 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use super::parser::encode_base64;
+use super::{FromTres, ToTres, ToTresPacked, TresDocument, TresResource, TresValue, TresWriter};
+use crate::ExtractError;
+use crate::engine_type_impls::{NodePathString, PackedScenePath, RustCurve};
+
+pub(super) fn unexpected(expected: &str, value: &TresValue) -> ExtractError {
+    ExtractError::invalid(format!("expected {}, found `{}`", expected, value))
+}
+
+/// Writes `values` as the scalar packed array `name`, e.g. `PackedInt32Array(1, 2)`.
+fn packed_array<'a, T: ToTres + 'a>(
+    name: &str,
+    values: impl IntoIterator<Item = &'a T>,
+    writer: &mut TresWriter,
+) -> TresValue {
+    TresValue::Constructor {
+        name: name.to_string(),
+        args: values.into_iter().map(|v| v.to_tres(writer)).collect(),
+    }
+}
+
+/// Writes `PackedByteArray("AQI=")`, the base64 form Godot 4.3+ uses.
+fn packed_bytes<'a>(values: impl IntoIterator<Item = &'a u8>) -> TresValue {
+    let bytes: Vec<u8> = values.into_iter().copied().collect();
+    let args = if bytes.is_empty() {
+        Vec::new()
+    } else {
+        vec![TresValue::String(encode_base64(&bytes))]
+    };
+    TresValue::Constructor {
+        name: "PackedByteArray".to_string(),
+        args,
+    }
+}
+
+/// `$to_packed` writes an `#[as_gd_res(packed)]` `Vec<$t>` as the matching packed array.
+macro_rules! impl_tres_int {
+    ($($t:ty $(=> $to_packed:expr)?),*) => {
+        $(
+            impl FromTres for $t {
+                fn from_tres(value: &TresValue, _doc: &TresDocument) -> Result<Self, ExtractError> {
+                    let int = value.as_int().ok_or_else(|| unexpected("an integer", value))?;
+                    <$t>::try_from(int).map_err(|_| {
+                        ExtractError::invalid(format!(
+                            "{} does not fit in `{}`",
+                            int,
+                            stringify!($t)
+                        ))
+                    })
+                }
+
+                fn from_tres_missing() -> Result<Self, ExtractError> {
+                    Ok(0)
+                }
+            }
+
+            impl ToTres for $t {
+                fn to_tres(&self, _writer: &mut TresWriter) -> TresValue {
+                    TresValue::Int(*self as i64)
+                }
+
+                fn tres_type() -> String {
+                    "int".to_string()
+                }
+
+            }
+
+            $(
+                impl ToTresPacked for $t {
+                    fn to_tres_packed<'a>(
+                        values: impl IntoIterator<Item = &'a Self>,
+                        writer: &mut TresWriter,
+                    ) -> TresValue {
+                        ($to_packed)(values, writer)
+                    }
+                }
+            )?
+        )*
+    };
+}
+
+impl_tres_int! {
+    i8, i16,
+    i32 => |values, writer| packed_array("PackedInt32Array", values, writer),
+    i64 => |values, writer| packed_array("PackedInt64Array", values, writer),
+    u8 => |values, _| packed_bytes(values),
+    u16, u32
+}
+
+macro_rules! impl_tres_float {
+    ($($t:ty $(=> $to_packed:expr)?),*) => {
+        $(
+            impl FromTres for $t {
+                fn from_tres(value: &TresValue, _doc: &TresDocument) -> Result<Self, ExtractError> {
+                    let float = value.as_float().ok_or_else(|| unexpected("a number", value))?;
+                    Ok(float as $t)
+                }
+
+                fn from_tres_missing() -> Result<Self, ExtractError> {
+                    Ok(0.0)
+                }
+            }
+
+            impl ToTres for $t {
+                fn to_tres(&self, _writer: &mut TresWriter) -> TresValue {
+                    float(*self)
+                }
+
+                fn tres_type() -> String {
+                    "float".to_string()
+                }
+
+            }
+
+            $(
+                impl ToTresPacked for $t {
+                    fn to_tres_packed<'a>(
+                        values: impl IntoIterator<Item = &'a Self>,
+                        writer: &mut TresWriter,
+                    ) -> TresValue {
+                        ($to_packed)(values, writer)
+                    }
+                }
+            )?
+        )*
+    };
+}
+
+impl_tres_float! {
+    f32 => |values, writer| packed_array("PackedFloat32Array", values, writer),
+    f64 => |values, writer| packed_array("PackedFloat64Array", values, writer)
+}
+
+impl FromTres for bool {
+    fn from_tres(value: &TresValue, _doc: &TresDocument) -> Result<Self, ExtractError> {
+        value.as_bool().ok_or_else(|| unexpected("a bool", value))
+    }
+
+    fn from_tres_missing() -> Result<Self, ExtractError> {
+        Ok(false)
+    }
+}
+
+impl FromTres for String {
+    fn from_tres(value: &TresValue, _doc: &TresDocument) -> Result<Self, ExtractError> {
+        value
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| unexpected("a string", value))
+    }
+
+    fn from_tres_missing() -> Result<Self, ExtractError> {
+        Ok(String::new())
+    }
+}
+
+impl FromTres for NodePathString {
+    fn from_tres(value: &TresValue, _doc: &TresDocument) -> Result<Self, ExtractError> {
+        match value {
+            TresValue::NodePath(path) | TresValue::String(path) => Ok(NodePathString(path.clone())),
+            _ => Err(unexpected("a `NodePath`", value)),
+        }
+    }
+
+    fn from_tres_missing() -> Result<Self, ExtractError> {
+        Ok(NodePathString(String::new()))
+    }
+}
+
+/// Scenes are not loaded; only the `path` of the `[ext_resource]` is read.
+impl FromTres for PackedScenePath {
+    fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+        match value {
+            TresValue::ExtResource(id) => {
+                let ext = doc.ext_resource(id).ok_or_else(|| {
+                    ExtractError::invalid(format!("no ext-resource with id `{}`", id))
+                })?;
+                if ext.type_name != "PackedScene" {
+                    return Err(ExtractError::invalid(format!(
+                        "expected a `PackedScene`, found a `{}`",
+                        ext.type_name
+                    )));
+                }
+                Ok(PackedScenePath(ext.path.clone()))
+            }
+            TresValue::Null => Err(ExtractError::unset()),
+            _ => Err(unexpected("an `ExtResource` scene", value)),
+        }
+    }
+}
+
+impl FromTres for RustCurve {
+    fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+        doc.resolve(value, |res, doc| {
+            curve_from_tres(res).map_err(|e| e.in_resource("RustCurve", &doc.resource_path(res)))
+        })
+    }
+}
+
+/// A point of a Godot `Curve`: position, left tangent, right tangent.
+type CurvePoint = (f32, f32, f32, f32);
+
+/// Reads the points of a `Curve` sub-resource and bakes them the same way
+/// `ExtractGd for Gd<Curve>` does, using Godot's `Curve::sample` algorithm.
+fn curve_from_tres(res: &TresResource) -> Result<RustCurve, ExtractError> {
+    res.expect_type("Curve")?;
+    let float_prop = |name: &str, default: f32| match res.property(name) {
+        Some(value) => value
+            .as_float()
+            .map(|f| f as f32)
+            .ok_or_else(|| unexpected("a number", value).in_field(name)),
+        None => Ok(default),
+    };
+    let min = float_prop("min_domain", 0.0)?;
+    let max = float_prop("max_domain", 1.0)?;
+
+    // `_data` holds 5 values per point:
+    // `Vector2(x, y), left_tangent, right_tangent, left_mode, right_mode`
+    let data = match res.property("_data") {
+        Some(value) => value
+            .as_array()
+            .ok_or_else(|| unexpected("an array", value).in_field("_data"))?,
+        None => &[],
+    };
+    let points = data
+        .chunks(5)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let point = match chunk {
+                [pos, left, right, ..] => pos.as_constructor("Vector2").and_then(|xy| {
+                    Some((
+                        xy.first()?.as_float()? as f32,
+                        xy.get(1)?.as_float()? as f32,
+                        left.as_float()? as f32,
+                        right.as_float()? as f32,
+                    ))
+                }),
+                _ => None,
+            };
+            point.ok_or_else(|| ExtractError::invalid("malformed curve point").at_index(i * 5))
+        })
+        .collect::<Result<Vec<CurvePoint>, _>>()
+        .map_err(|e| e.in_field("_data"))?;
+
+    Ok(RustCurve::from_sample_fn(min, max, |t| {
+        sample_curve(&points, t)
+    }))
+}
+
+/// Port of Godot's `Curve::sample`.
+fn sample_curve(points: &[CurvePoint], offset: f32) -> f32 {
+    match points {
+        [] => return 0.0,
+        [(_, y, _, _)] => return *y,
+        _ => {}
+    }
+    let i = curve_index(points, offset);
+    if i == points.len() - 1 {
+        return points[i].1;
+    }
+    let local = offset - points[i].0;
+    if i == 0 && local <= 0.0 {
+        return points[0].1;
+    }
+
+    let (ax, ay, _, a_right) = points[i];
+    let (bx, by, b_left, _) = points[i + 1];
+    let mut d = bx - ax;
+    if d.abs() < 0.00001 {
+        return by;
+    }
+    let t = local / d;
+    d /= 3.0;
+    let yac = ay + d * a_right;
+    let ybc = by - d * b_left;
+
+    let omt = 1.0 - t;
+    let omt2 = omt * omt;
+    let omt3 = omt2 * omt;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    ay * omt3 + yac * omt2 * t * 3.0 + ybc * omt * t2 * 3.0 + by * t3
+}
+
+/// Port of Godot's `Curve::get_index`: the index of the last point at or before `offset`.
+fn curve_index(points: &[CurvePoint], offset: f32) -> usize {
+    let mut imin = 0;
+    let mut imax = points.len() - 1;
+    while imax - imin > 1 {
+        let m = (imin + imax) / 2;
+        let a = points[m].0;
+        let b = points[m + 1].0;
+        if a < offset && b < offset {
+            imin = m;
+        } else if a > offset {
+            imax = m;
+        } else {
+            return m;
+        }
+    }
+    if offset > points[imax].0 {
+        return imax;
+    }
+    imin
+}
+
+impl<T: FromTres> FromTres for Option<T> {
+    fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+        T::from_tres_opt(value, doc)
+    }
+
+    fn from_tres_missing() -> Result<Self, ExtractError> {
+        Ok(None)
+    }
+}
+
+impl<T: FromTres> FromTres for Vec<T> {
+    fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+        let values = value
+            .as_array()
+            .ok_or_else(|| unexpected("an array", value))?;
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| match v {
+                TresValue::Null => Err(ExtractError::null_element().at_index(i)),
+                _ => T::from_tres(v, doc).map_err(|e| e.at_index(i)),
+            })
+            .collect()
+    }
+
+    fn from_tres_missing() -> Result<Self, ExtractError> {
+        Ok(Vec::new())
+    }
+}
+
+impl<T: FromTres, const N: usize> FromTres for [T; N] {
+    fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+        crate::array_from_vec(Vec::from_tres(value, doc)?)
+    }
+
+    // Godot omits the array if it still holds the `N` elements a new resource starts
+    // with, so each element is missing as well.
+    fn from_tres_missing() -> Result<Self, ExtractError> {
+        let values = (0..N)
+            .map(|i| T::from_tres_missing().map_err(|e| e.at_index(i)))
+            .collect::<Result<Vec<T>, _>>()?;
+        crate::array_from_vec(values)
+    }
+}
+
+macro_rules! impl_tres_pointer {
+    ($($p:ident),*) => {
+        $(
+            impl<T: FromTres> FromTres for $p<T> {
+                fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+                    T::from_tres(value, doc).map($p::new)
+                }
+
+                fn from_tres_missing() -> Result<Self, ExtractError> {
+                    T::from_tres_missing().map($p::new)
+                }
+
+                fn from_tres_opt(
+                    value: &TresValue,
+                    doc: &TresDocument,
+                ) -> Result<Option<Self>, ExtractError> {
+                    Ok(T::from_tres_opt(value, doc)?.map($p::new))
+                }
+            }
+
+            impl<T: ToTres> ToTres for $p<T> {
+                fn to_tres(&self, writer: &mut TresWriter) -> TresValue {
+                    (**self).to_tres(writer)
+                }
+
+                fn tres_type() -> String {
+                    T::tres_type()
+                }
+
+                fn to_tres_array<'a>(
+                    values: impl IntoIterator<Item = &'a Self>,
+                    writer: &mut TresWriter,
+                ) -> TresValue
+                where
+                    Self: 'a,
+                {
+                    T::to_tres_array(values.into_iter().map(|v| &**v), writer)
+                }
+
+                fn to_tres_opt(value: Option<&Self>, writer: &mut TresWriter) -> TresValue {
+                    T::to_tres_opt(value.map(|v| &**v), writer)
+                }
+            }
+        )*
+    };
+}
+
+impl_tres_pointer!(Box, Rc, Arc);
+
+/// The key as shown in an error path: strings without quotes.
+fn key_name(key: &TresValue) -> String {
+    key.as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| key.to_string())
+}
+
+fn map_from_tres<K: FromTres, V: FromTres, M: FromIterator<(K, V)>>(
+    value: &TresValue,
+    doc: &TresDocument,
+) -> Result<M, ExtractError> {
+    let entries = value
+        .as_dictionary()
+        .ok_or_else(|| unexpected("a dictionary", value))?;
+    entries
+        .iter()
+        .map(|(k, v)| {
+            let at_key = |e: ExtractError| e.at_key(&key_name(k));
+            Ok((
+                K::from_tres(k, doc).map_err(at_key)?,
+                V::from_tres(v, doc).map_err(at_key)?,
+            ))
+        })
+        .collect()
+}
+
+impl<K: FromTres + Eq + Hash, V: FromTres> FromTres for HashMap<K, V> {
+    fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+        map_from_tres(value, doc)
+    }
+
+    fn from_tres_missing() -> Result<Self, ExtractError> {
+        Ok(HashMap::new())
+    }
+}
+
+impl<K: FromTres + Ord, V: FromTres> FromTres for BTreeMap<K, V> {
+    fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+        map_from_tres(value, doc)
+    }
+
+    fn from_tres_missing() -> Result<Self, ExtractError> {
+        Ok(BTreeMap::new())
+    }
+}
+
+/// Converts via the shortest decimal representation, so that e.g. `0.1f32` is
+/// written as `0.1` rather than `0.10000000149011612`.
+pub(super) fn float(value: impl ToString) -> TresValue {
+    TresValue::Float(value.to_string().parse().unwrap_or(f64::NAN))
+}
+
+impl ToTres for bool {
+    fn to_tres(&self, _writer: &mut TresWriter) -> TresValue {
+        TresValue::Bool(*self)
+    }
+
+    fn tres_type() -> String {
+        "bool".to_string()
+    }
+}
+
+impl ToTres for String {
+    fn to_tres(&self, _writer: &mut TresWriter) -> TresValue {
+        TresValue::String(self.clone())
+    }
+
+    fn tres_type() -> String {
+        "String".to_string()
+    }
+}
+
+impl ToTres for NodePathString {
+    fn to_tres(&self, _writer: &mut TresWriter) -> TresValue {
+        TresValue::NodePath(self.0.clone())
+    }
+
+    fn tres_type() -> String {
+        "NodePath".to_string()
+    }
+}
+
+impl ToTres for PackedScenePath {
+    fn to_tres(&self, writer: &mut TresWriter) -> TresValue {
+        writer.add_ext_resource("PackedScene", &self.0)
+    }
+
+    fn tres_type() -> String {
+        "PackedScene".to_string()
+    }
+}
+
+/// Written as a `Curve` with the same points as [`RustCurve::to_curve`].
+impl ToTres for RustCurve {
+    fn to_tres(&self, writer: &mut TresWriter) -> TresValue {
+        let points = self.curve_points();
+        let mut data = Vec::with_capacity(points.len() * 5);
+        for (x, y, left, right) in &points {
+            data.extend([
+                TresValue::Constructor {
+                    name: "Vector2".to_string(),
+                    args: vec![float(x), float(y)],
+                },
+                float(left),
+                float(right),
+                // `Curve.TANGENT_FREE`
+                TresValue::Int(0),
+                TresValue::Int(0),
+            ]);
+        }
+        let (lo, hi) = self.value_range();
+        let properties = vec![
+            ("min_value".to_string(), float(lo)),
+            ("max_value".to_string(), float(hi)),
+            ("min_domain".to_string(), float(self.min_domain())),
+            ("max_domain".to_string(), float(self.max_domain())),
+            ("_data".to_string(), TresValue::Array(data)),
+            (
+                "point_count".to_string(),
+                TresValue::Int(points.len() as i64),
+            ),
+        ];
+        writer.add_raw_sub_resource("Curve".to_string(), properties)
+    }
+
+    fn tres_type() -> String {
+        "Curve".to_string()
+    }
+}
+
+impl<T: ToTres> ToTres for Option<T> {
+    fn to_tres(&self, writer: &mut TresWriter) -> TresValue {
+        T::to_tres_opt(self.as_ref(), writer)
+    }
+
+    fn tres_type() -> String {
+        T::tres_type()
+    }
+}
+
+impl<T: ToTres> ToTres for Vec<T> {
+    fn to_tres(&self, writer: &mut TresWriter) -> TresValue {
+        T::to_tres_array(self, writer)
+    }
+
+    fn tres_type() -> String {
+        "Array".to_string()
+    }
+}
+
+impl<T: ToTres, const N: usize> ToTres for [T; N] {
+    fn to_tres(&self, writer: &mut TresWriter) -> TresValue {
+        T::to_tres_array(self, writer)
+    }
+
+    fn tres_type() -> String {
+        "Array".to_string()
+    }
+}
+
+/// Tuples are stored as an untyped array with one element per tuple field.
+macro_rules! impl_tres_tuple {
+    ($($len:literal => ($($t:ident $i:tt),*);)*) => {
+        $(
+            impl<$($t: FromTres),*> FromTres for ($($t,)*) {
+                fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError> {
+                    let values = value
+                        .as_array()
+                        .ok_or_else(|| unexpected("an array", value))?;
+                    if values.len() != $len {
+                        return Err(ExtractError::invalid(format!(
+                            "expected {} tuple elements, found {}",
+                            $len,
+                            values.len()
+                        )));
+                    }
+                    Ok(($(
+                        match &values[$i] {
+                            TresValue::Null => Err(ExtractError::null_element().at_index($i)),
+                            v => $t::from_tres(v, doc).map_err(|e| e.at_index($i)),
+                        }?,
+                    )*))
+                }
+
+                // Godot omits the array if it still holds the default elements a new
+                // resource starts with, so each element is missing as well.
+                fn from_tres_missing() -> Result<Self, ExtractError> {
+                    Ok(($($t::from_tres_missing().map_err(|e| e.at_index($i))?,)*))
+                }
+            }
+
+            impl<$($t: ToTres),*> ToTres for ($($t,)*) {
+                fn to_tres(&self, writer: &mut TresWriter) -> TresValue {
+                    TresValue::Array(vec![$(self.$i.to_tres(writer)),*])
+                }
+
+                fn tres_type() -> String {
+                    "Array".to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_tres_tuple! {
+    2 => (A 0, B 1);
+    3 => (A 0, B 1, C 2);
+    4 => (A 0, B 1, C 2, D 3);
+    5 => (A 0, B 1, C 2, D 3, E 4);
+    6 => (A 0, B 1, C 2, D 3, E 4, F 5);
+}
+
+fn map_to_tres<'a, K: ToTres + 'a, V: ToTres + 'a>(
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
+    writer: &mut TresWriter,
+) -> TresValue {
+    let entries = entries
+        .map(|(k, v)| (k.to_tres(writer), v.to_tres(writer)))
+        .collect();
+    TresValue::TypedDictionary(K::tres_type(), V::tres_type(), entries)
+}
+
+impl<K: ToTres, V: ToTres> ToTres for HashMap<K, V> {
+    /// Entries are sorted by key, so that saving the same map twice gives the
+    /// same file.
+    fn to_tres(&self, writer: &mut TresWriter) -> TresValue {
+        let mut entries: Vec<_> = self.iter().map(|(k, v)| (k.to_tres(writer), v)).collect();
+        entries.sort_by_cached_key(|(k, _)| key_name(k));
+        let entries = entries
+            .into_iter()
+            .map(|(k, v)| (k, v.to_tres(writer)))
+            .collect();
+        TresValue::TypedDictionary(K::tres_type(), V::tres_type(), entries)
+    }
+
+    fn tres_type() -> String {
+        "Dictionary".to_string()
+    }
+}
+
+impl<K: ToTres, V: ToTres> ToTres for BTreeMap<K, V> {
+    fn to_tres(&self, writer: &mut TresWriter) -> TresValue {
+        map_to_tres(self.iter(), writer)
+    }
+
+    fn tres_type() -> String {
+        "Dictionary".to_string()
+    }
+}
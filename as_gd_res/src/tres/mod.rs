@@ -0,0 +1,623 @@
+//! Offline reader and writer for Godot text resources (`.tres`).
+//!
+//! This lets types that `#[derive(AsGdRes)]` be loaded straight from a resource file
+//! without a running Godot engine (e.g. in `cargo test` or on a headless server):
+//!
+//! ```ignore
+//! let complicated: Complicated = as_gd_res::tres::load_tres("project/test_complicated_resource.tres")?;
+//! ```
+//!
+//! The derive generates [`FromTresResource`] and [`FromTres`] impls keyed on the name of
+//! the generated `{Name}Resource` class and its property names, so the same files that
+//! the editor writes can be read back. Properties that are missing from the file (Godot
+//! omits values equal to their default) get the field's `#[init(val = ...)]` value, or
+//! the Godot default of the field's resource type.
+//!
+//! Values computed in a `post_init` method are not stored in the file, and are *not*
+//! recomputed here.
+//!
+//! In the other direction, [`ToTresResource`] and [`ToTres`] turn a Rust value into a
+//! resource file that the editor can open:
+//!
+//! ```ignore
+//! as_gd_res::tres::save_tres(&complicated, "project/generated.tres")?;
+//! ```
+
+#[cfg(any(feature = "godot", feature = "glam", feature = "mint"))]
+mod builtin_impls;
+mod impls;
+mod parser;
+mod value;
+mod writer;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, Weak};
+
+pub use parser::{TresParseError, parse_value};
+pub use value::TresValue;
+pub use writer::TresWriter;
+
+use crate::ExtractError;
+
+/// A value that can be read from a property of a Godot text resource.
+pub trait FromTres: Sized {
+    fn from_tres(value: &TresValue, doc: &TresDocument) -> Result<Self, ExtractError>;
+
+    /// The value to use when the property is absent from the file.
+    ///
+    /// Godot omits properties that are equal to their default, so this should match the
+    /// default of the corresponding Godot type. Types without a usable default (e.g. an
+    /// unset `OnEditor<Gd<_>>`) return [`ExtractError::unset`].
+    fn from_tres_missing() -> Result<Self, ExtractError> {
+        Err(ExtractError::unset())
+    }
+
+    /// Reads an `Option<Self>`, where `null` is `None`. Types with their own way of
+    /// storing `None` on the Godot side override it.
+    fn from_tres_opt(value: &TresValue, doc: &TresDocument) -> Result<Option<Self>, ExtractError> {
+        match value {
+            TresValue::Null => Ok(None),
+            _ => Self::from_tres(value, doc).map(Some),
+        }
+    }
+}
+
+/// A value that is stored as a resource of its own: a `[sub_resource]`, the main
+/// `[resource]` of a file, or an external `.tres` file.
+pub trait FromTresResource: Sized {
+    fn from_tres_resource(res: &TresResource, doc: &TresDocument) -> Result<Self, ExtractError>;
+}
+
+/// A value that can be written as a property of a Godot text resource.
+pub trait ToTres {
+    /// Returns the property value, adding any sub-resources and ext-resources it
+    /// refers to to `writer`.
+    fn to_tres(&self, writer: &mut TresWriter) -> TresValue;
+
+    /// The element type used when the value is stored in a typed array, e.g. `int`
+    /// in `Array[int]([1, 2])`.
+    fn tres_type() -> String
+    where
+        Self: Sized;
+
+    /// Returns the property value of a `Vec<Self>`. This is a typed array by
+    /// default; types stored in a packed array on the Godot side override it.
+    fn to_tres_array<'a>(
+        values: impl IntoIterator<Item = &'a Self>,
+        writer: &mut TresWriter,
+    ) -> TresValue
+    where
+        Self: Sized + 'a,
+    {
+        let values = values.into_iter().map(|v| v.to_tres(writer)).collect();
+        TresValue::TypedArray(Self::tres_type(), values)
+    }
+
+    /// Returns the property value of an `Option<Self>`, `null` for `None` by default.
+    fn to_tres_opt(value: Option<&Self>, writer: &mut TresWriter) -> TresValue
+    where
+        Self: Sized,
+    {
+        match value {
+            Some(value) => value.to_tres(writer),
+            None => TresValue::Null,
+        }
+    }
+}
+
+/// A number that can be written as a packed array, e.g. `PackedInt32Array(1, 2)`,
+/// for `Vec` fields marked `#[as_gd_res(packed)]`.
+pub trait ToTresPacked: ToTres {
+    fn to_tres_packed<'a>(
+        values: impl IntoIterator<Item = &'a Self>,
+        writer: &mut TresWriter,
+    ) -> TresValue
+    where
+        Self: Sized + 'a;
+}
+
+/// Writes the `#[as_gd_res(packed)]` field `values` as a packed array.
+pub fn packed_to_tres<'a, T: ToTresPacked + 'a>(
+    values: impl IntoIterator<Item = &'a T>,
+    writer: &mut TresWriter,
+) -> TresValue {
+    T::to_tres_packed(values, writer)
+}
+
+/// A value that is written as a resource of its own.
+pub trait ToTresResource {
+    /// The Godot class of the resource, e.g. `SimpleDataResource`.
+    fn tres_type_name(&self) -> String;
+
+    /// The properties of the resource, in declaration order.
+    fn to_tres_properties(&self, writer: &mut TresWriter) -> Vec<(String, TresValue)>;
+}
+
+/// Reads the property `name` of `res`, falling back to [`FromTres::from_tres_missing`].
+pub fn field<T: FromTres>(
+    res: &TresResource,
+    doc: &TresDocument,
+    name: &str,
+) -> Result<T, ExtractError> {
+    match res.property(name) {
+        Some(value) => T::from_tres(value, doc),
+        None => T::from_tres_missing(),
+    }
+    .map_err(|e| e.in_field(name))
+}
+
+/// Reads the property `name` of `res`, falling back to `default` (used for fields with
+/// an `#[init(val = ...)]`).
+pub fn field_or_else<T: FromTres>(
+    res: &TresResource,
+    doc: &TresDocument,
+    name: &str,
+    default: impl FnOnce() -> T,
+) -> Result<T, ExtractError> {
+    match res.property(name) {
+        Some(value) => T::from_tres(value, doc).map_err(|e| e.in_field(name)),
+        None => Ok(default()),
+    }
+}
+
+/// Reads an `Option<T>` field of a number, `bool` or string, which is stored as the
+/// toggle `enabled` plus the value `name`. `None` unless the toggle is on.
+pub fn toggle_field<T: FromTres>(
+    res: &TresResource,
+    doc: &TresDocument,
+    enabled: &str,
+    name: &str,
+) -> Result<Option<T>, ExtractError> {
+    if !field::<bool>(res, doc, enabled)? {
+        return Ok(None);
+    }
+    field(res, doc, name).map(Some)
+}
+
+/// Writes the value property of an `Option<T>` toggle field, `T::default()` for `None`.
+pub fn toggle_to_tres<T: ToTres + Default>(
+    value: Option<&T>,
+    writer: &mut TresWriter,
+) -> TresValue {
+    match value {
+        Some(value) => value.to_tres(writer),
+        None => T::default().to_tres(writer),
+    }
+}
+
+/// A `[sub_resource]` section, or the main `[resource]` section of a file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TresResource {
+    type_name: String,
+    id: Option<String>,
+    properties: Vec<(String, TresValue)>,
+}
+
+impl TresResource {
+    /// The Godot class of the resource, e.g. `SimpleDataResource` or `Curve`.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// The id of a `[sub_resource]`; `None` for the main resource.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn property(&self, name: &str) -> Option<&TresValue> {
+        self.properties
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn properties(&self) -> impl Iterator<Item = (&str, &TresValue)> {
+        self.properties.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Fails unless the resource is of the Godot class `type_name`.
+    pub fn expect_type(&self, type_name: &str) -> Result<(), ExtractError> {
+        if self.type_name == type_name {
+            Ok(())
+        } else {
+            Err(ExtractError::invalid(format!(
+                "expected a `{}`, found a `{}`",
+                type_name, self.type_name
+            )))
+        }
+    }
+}
+
+/// An `[ext_resource]` section.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TresExtResource {
+    pub id: String,
+    pub type_name: String,
+    pub path: String,
+}
+
+/// The files that `ExtResource(...)` references were loaded from, shared by a document
+/// and every document loaded through it.
+#[derive(Clone, Debug)]
+struct ExternalDocuments {
+    // Only the document the others were loaded through owns the state, so that the
+    // loaded documents don't keep it alive themselves.
+    owner: Option<Arc<Mutex<ExternalState>>>,
+    state: Weak<Mutex<ExternalState>>,
+}
+
+#[derive(Debug, Default)]
+struct ExternalState {
+    // Loaded files by `res://` path, so each file is only read once.
+    loaded: HashMap<String, Arc<TresDocument>>,
+    // Files whose main resource is being extracted, outermost first.
+    resolving: Vec<String>,
+}
+
+impl Default for ExternalDocuments {
+    fn default() -> Self {
+        let owner = Arc::default();
+        Self {
+            state: Arc::downgrade(&owner),
+            owner: Some(owner),
+        }
+    }
+}
+
+impl ExternalDocuments {
+    fn state(&self) -> Result<Arc<Mutex<ExternalState>>, ExtractError> {
+        // A clone of a loaded document can outlive the document it was loaded through
+        self.owner
+            .clone()
+            .or_else(|| self.state.upgrade())
+            .ok_or_else(|| {
+                ExtractError::invalid(
+                    "cannot load external resources after the document this one was loaded \
+                     through was dropped",
+                )
+            })
+    }
+
+    /// The same files, for a document loaded through this one.
+    fn shared(&self) -> Self {
+        Self {
+            owner: None,
+            state: self.state.clone(),
+        }
+    }
+}
+
+// The cache doesn't change what a document contains.
+impl PartialEq for ExternalDocuments {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// A parsed `.tres` file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TresDocument {
+    path: String,
+    project_root: Option<PathBuf>,
+    external: ExternalDocuments,
+    ext_resources: Vec<TresExtResource>,
+    sub_resources: Vec<TresResource>,
+    resource: TresResource,
+}
+
+impl TresDocument {
+    /// Parses the text of a resource file.
+    ///
+    /// `ExtResource(...)` references to other resource files can only be followed for
+    /// documents created with [`TresDocument::load`].
+    pub fn parse(src: &str) -> Result<Self, TresParseError> {
+        let mut sections = parser::parse_sections(src)?.into_iter();
+        let header_error = |message: &str| TresParseError {
+            line: 1,
+            message: message.to_string(),
+        };
+        let header = sections
+            .next()
+            .filter(|s| s.tag == "gd_resource")
+            .ok_or_else(|| header_error("expected a `[gd_resource ...]` header"))?;
+        let attr = |attrs: &[(String, TresValue)], key: &str| {
+            attrs
+                .iter()
+                .find(|(k, _)| k == key)
+                .and_then(|(_, v)| match v {
+                    TresValue::String(s) => Some(s.clone()),
+                    TresValue::Int(i) => Some(i.to_string()),
+                    _ => None,
+                })
+        };
+        let resource_type = attr(&header.attrs, "type").unwrap_or_default();
+
+        let mut ext_resources = Vec::new();
+        let mut sub_resources = Vec::new();
+        let mut resource = None;
+        for section in sections {
+            match section.tag.as_str() {
+                "ext_resource" => ext_resources.push(TresExtResource {
+                    id: attr(&section.attrs, "id").unwrap_or_default(),
+                    type_name: attr(&section.attrs, "type").unwrap_or_default(),
+                    path: attr(&section.attrs, "path").unwrap_or_default(),
+                }),
+                "sub_resource" => sub_resources.push(TresResource {
+                    type_name: attr(&section.attrs, "type").unwrap_or_default(),
+                    id: attr(&section.attrs, "id"),
+                    properties: section.properties,
+                }),
+                "resource" => {
+                    resource = Some(TresResource {
+                        type_name: resource_type.clone(),
+                        id: None,
+                        properties: section.properties,
+                    })
+                }
+                other => {
+                    return Err(header_error(&format!(
+                        "unexpected `[{}]` section in a resource file",
+                        other
+                    )));
+                }
+            }
+        }
+        Ok(Self {
+            path: String::new(),
+            project_root: None,
+            external: ExternalDocuments::default(),
+            ext_resources,
+            sub_resources,
+            resource: resource.ok_or_else(|| header_error("missing `[resource]` section"))?,
+        })
+    }
+
+    /// Reads and parses a resource file.
+    ///
+    /// If one of the parent directories contains a `project.godot`, it is used to
+    /// resolve `res://` paths, both for error messages and to follow `ExtResource(...)`
+    /// references to other resource files.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TresError> {
+        let path = path.as_ref();
+        let src = std::fs::read_to_string(path).map_err(|source| TresError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let mut doc = Self::parse(&src).map_err(|source| TresError::Parse {
+            path: path.display().to_string(),
+            source,
+        })?;
+        let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        let root = absolute
+            .ancestors()
+            .skip(1)
+            .find(|dir| dir.join("project.godot").is_file());
+        match root {
+            Some(root) => {
+                let relative = absolute.strip_prefix(root).unwrap_or(&absolute);
+                let parts: Vec<_> = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect();
+                doc.path = format!("res://{}", parts.join("/"));
+                doc.project_root = Some(root.to_path_buf());
+            }
+            None => doc.path = path.display().to_string(),
+        }
+        Ok(doc)
+    }
+
+    /// The `res://` path of the file if known, otherwise the path it was loaded from.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The main `[resource]` section.
+    pub fn resource(&self) -> &TresResource {
+        &self.resource
+    }
+
+    pub fn sub_resource(&self, id: &str) -> Option<&TresResource> {
+        self.sub_resources.iter().find(|r| r.id() == Some(id))
+    }
+
+    pub fn ext_resource(&self, id: &str) -> Option<&TresExtResource> {
+        self.ext_resources.iter().find(|r| r.id == id)
+    }
+
+    /// The path Godot would report for `res`: the file path, with `::{id}` appended for
+    /// sub-resources. Empty for documents that were not loaded from a file.
+    pub fn resource_path(&self, res: &TresResource) -> String {
+        match res.id() {
+            Some(id) if !self.path.is_empty() => format!("{}::{}", self.path, id),
+            _ => self.path.clone(),
+        }
+    }
+
+    /// Extracts the main resource of the file.
+    pub fn extract<T: FromTresResource>(&self) -> Result<T, ExtractError> {
+        T::from_tres_resource(&self.resource, self)
+    }
+
+    /// Follows a `SubResource(...)` or `ExtResource(...)` reference and passes the
+    /// resource it points to to `f`, along with the document it lives in.
+    ///
+    /// Each external file is only read once. A file that refers back to itself through
+    /// `ExtResource(...)` references is reported as [`ExtractError::cycle`].
+    pub fn resolve<T>(
+        &self,
+        value: &TresValue,
+        f: impl FnOnce(&TresResource, &TresDocument) -> Result<T, ExtractError>,
+    ) -> Result<T, ExtractError> {
+        match value {
+            TresValue::SubResource(id) => {
+                let res = self.sub_resource(id).ok_or_else(|| {
+                    ExtractError::invalid(format!("no sub-resource with id `{}`", id))
+                })?;
+                f(res, self)
+            }
+            TresValue::ExtResource(id) => {
+                let ext = self.ext_resource(id).ok_or_else(|| {
+                    ExtractError::invalid(format!("no ext-resource with id `{}`", id))
+                })?;
+                let doc = self.load_external(&ext.path)?;
+                let state = self.external.state()?;
+                {
+                    let mut state = state.lock().unwrap();
+                    if state.resolving.contains(&ext.path) {
+                        return Err(ExtractError::cycle());
+                    }
+                    state.resolving.push(ext.path.clone());
+                }
+                let extracted = f(&doc.resource, &doc);
+                state.lock().unwrap().resolving.pop();
+                extracted
+            }
+            TresValue::Null => Err(ExtractError::unset()),
+            other => Err(ExtractError::invalid(format!(
+                "expected a resource reference, found `{}`",
+                other
+            ))),
+        }
+    }
+
+    fn load_external(&self, res_path: &str) -> Result<Arc<TresDocument>, ExtractError> {
+        if let Some(doc) = self.external.state()?.lock().unwrap().loaded.get(res_path) {
+            return Ok(doc.clone());
+        }
+        let root = self.project_root.as_ref().ok_or_else(|| {
+            ExtractError::invalid(format!(
+                "cannot load `{}` without a project root (no `project.godot` found)",
+                res_path
+            ))
+        })?;
+        let relative = res_path.strip_prefix("res://").unwrap_or(res_path);
+        let mut doc = TresDocument::load(root.join(relative))
+            .map_err(|e| ExtractError::invalid(format!("cannot load `{}`: {}", res_path, e)))?;
+        doc.external = self.external.shared();
+        let doc = Arc::new(doc);
+        self.external
+            .state()?
+            .lock()
+            .unwrap()
+            .loaded
+            .insert(res_path.to_string(), doc.clone());
+        Ok(doc)
+    }
+}
+
+impl fmt::Display for TresDocument {
+    /// Writes the document in the format Godot uses for `.tres` files.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[gd_resource type={}",
+            TresValue::String(self.resource.type_name.clone())
+        )?;
+        let load_steps = self.ext_resources.len() + self.sub_resources.len() + 1;
+        if load_steps > 1 {
+            write!(f, " load_steps={}", load_steps)?;
+        }
+        writeln!(f, " format=3]")?;
+
+        for ext in &self.ext_resources {
+            write!(
+                f,
+                "\n[ext_resource type={} path={} id={}]\n",
+                TresValue::String(ext.type_name.clone()),
+                TresValue::String(ext.path.clone()),
+                TresValue::String(ext.id.clone()),
+            )?;
+        }
+        let write_properties = |f: &mut fmt::Formatter<'_>, res: &TresResource| -> fmt::Result {
+            // Godot leaves out unset objects rather than writing `null`
+            for (key, value) in res.properties.iter().filter(|(_, v)| *v != TresValue::Null) {
+                writeln!(f, "{} = {}", key, value)?;
+            }
+            Ok(())
+        };
+        for sub in &self.sub_resources {
+            write!(
+                f,
+                "\n[sub_resource type={} id={}]\n",
+                TresValue::String(sub.type_name.clone()),
+                TresValue::String(sub.id.clone().unwrap_or_default()),
+            )?;
+            write_properties(f, sub)?;
+        }
+        writeln!(f, "\n[resource]")?;
+        write_properties(f, &self.resource)
+    }
+}
+
+/// Error returned by [`load_tres`] and [`from_tres_str`].
+#[derive(Debug)]
+pub enum TresError {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Parse {
+        path: String,
+        source: TresParseError,
+    },
+    Extract(ExtractError),
+}
+
+impl fmt::Display for TresError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TresError::Io { path, source } => {
+                write!(f, "cannot read `{}`: {}", path.display(), source)
+            }
+            TresError::Parse { path, source } if path.is_empty() => write!(f, "{}", source),
+            TresError::Parse { path, source } => write!(f, "{}: {}", path, source),
+            TresError::Extract(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for TresError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TresError::Io { source, .. } => Some(source),
+            TresError::Parse { source, .. } => Some(source),
+            TresError::Extract(e) => Some(e),
+        }
+    }
+}
+
+impl From<ExtractError> for TresError {
+    fn from(e: ExtractError) -> Self {
+        TresError::Extract(e)
+    }
+}
+
+/// Loads the resource file at `path` and extracts its main resource as `T`.
+pub fn load_tres<T: FromTresResource>(path: impl AsRef<Path>) -> Result<T, TresError> {
+    Ok(TresDocument::load(path)?.extract()?)
+}
+
+/// Parses the text of a resource file and extracts its main resource as `T`.
+pub fn from_tres_str<T: FromTresResource>(src: &str) -> Result<T, TresError> {
+    let doc = TresDocument::parse(src).map_err(|source| TresError::Parse {
+        path: String::new(),
+        source,
+    })?;
+    Ok(doc.extract()?)
+}
+
+/// Writes `value` as the text of a `.tres` file.
+pub fn to_tres_string<T: ToTresResource + ?Sized>(value: &T) -> String {
+    TresDocument::from_value(value).to_string()
+}
+
+/// Writes `value` to a `.tres` file at `path`.
+pub fn save_tres<T: ToTresResource + ?Sized>(
+    value: &T,
+    path: impl AsRef<Path>,
+) -> std::io::Result<()> {
+    std::fs::write(path, to_tres_string(value))
+}
@@ -0,0 +1,491 @@
+use std::fmt;
+
+use super::TresValue;
+
+/// Error returned when the text of a resource file is malformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TresParseError {
+    /// 1-based line of the offending token.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for TresParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for TresParseError {}
+
+/// One `[tag key=value ...]` header together with the `key = value` lines below it.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RawSection {
+    pub tag: String,
+    pub attrs: Vec<(String, TresValue)>,
+    pub properties: Vec<(String, TresValue)>,
+}
+
+/// Packed arrays of scalars, which are read as plain arrays.
+const SCALAR_PACKED_ARRAYS: &[&str] = &[
+    "PackedByteArray",
+    "PackedInt32Array",
+    "PackedInt64Array",
+    "PackedFloat32Array",
+    "PackedFloat64Array",
+    "PackedStringArray",
+];
+
+/// Packed arrays of vectors and colors, whose components are written flat and which
+/// are read as a plain array of constructor calls, e.g. `[Vector2(1, 2), Vector2(3, 4)]`.
+const VECTOR_PACKED_ARRAYS: &[(&str, &str, usize)] = &[
+    ("PackedVector2Array", "Vector2", 2),
+    ("PackedVector3Array", "Vector3", 3),
+    ("PackedVector4Array", "Vector4", 4),
+    ("PackedColorArray", "Color", 4),
+];
+
+/// Turns the flat components of a packed vector array into one constructor call per
+/// element. Returns `None` if the number of components doesn't fit.
+fn split_packed_vectors(packed: &str, components: &[TresValue]) -> Option<TresValue> {
+    let &(_, element, len) = VECTOR_PACKED_ARRAYS.iter().find(|(p, ..)| *p == packed)?;
+    if !components.len().is_multiple_of(len) {
+        return None;
+    }
+    let elements = components
+        .chunks(len)
+        .map(|args| TresValue::Constructor {
+            name: element.to_string(),
+            args: args.to_vec(),
+        })
+        .collect();
+    Some(TresValue::Array(elements))
+}
+
+pub(crate) fn parse_sections(src: &str) -> Result<Vec<RawSection>, TresParseError> {
+    let mut parser = Parser { src, pos: 0 };
+    let mut sections: Vec<RawSection> = Vec::new();
+    loop {
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => return Ok(sections),
+            Some('[') => sections.push(parser.section_header()?),
+            Some(_) => {
+                let Some(section) = sections.last_mut() else {
+                    return Err(parser.error("expected a `[section]` header"));
+                };
+                let key = parser.key()?;
+                parser.skip_whitespace();
+                parser.expect('=')?;
+                let value = parser.value()?;
+                section.properties.push((key, value));
+            }
+        }
+    }
+}
+
+/// Parses a single value, e.g. the right-hand side of a property line.
+pub fn parse_value(src: &str) -> Result<TresValue, TresParseError> {
+    let mut parser = Parser { src, pos: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return Err(parser.error("unexpected trailing characters"));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: impl Into<String>) -> TresParseError {
+        TresParseError {
+            line: self.src[..self.pos].matches('\n').count() + 1,
+            message: message.into(),
+        }
+    }
+
+    fn rest(&self) -> &str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Skips whitespace, newlines and `;` comments.
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.bump();
+            } else if c == ';' {
+                while !matches!(self.bump(), None | Some('\n')) {}
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), TresParseError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.bump();
+                Ok(())
+            }
+            Some(c) => Err(self.error(format!("expected `{}`, found `{}`", expected, c))),
+            None => Err(self.error(format!("expected `{}`, found end of file", expected))),
+        }
+    }
+
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &str {
+        let start = self.pos;
+        while self.peek().is_some_and(&pred) {
+            self.bump();
+        }
+        &self.src[start..self.pos]
+    }
+
+    fn ident(&mut self) -> Result<String, TresParseError> {
+        let ident = self.take_while(|c| c.is_alphanumeric() || c == '_');
+        if ident.is_empty() {
+            return Err(self.error("expected an identifier"));
+        }
+        Ok(ident.to_string())
+    }
+
+    fn section_header(&mut self) -> Result<RawSection, TresParseError> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        let tag = self.ident()?;
+        let mut attrs = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(']') {
+                self.bump();
+                break;
+            }
+            let key = self.ident()?;
+            self.skip_whitespace();
+            self.expect('=')?;
+            attrs.push((key, self.value()?));
+        }
+        Ok(RawSection {
+            tag,
+            attrs,
+            properties: Vec::new(),
+        })
+    }
+
+    /// Property names may contain `/` (e.g. `metadata/foo`), and are quoted if they
+    /// contain anything unusual.
+    fn key(&mut self) -> Result<String, TresParseError> {
+        if self.peek() == Some('"') {
+            return self.string();
+        }
+        let key = self.take_while(|c| !c.is_whitespace() && c != '=');
+        if key.is_empty() {
+            return Err(self.error("expected a property name"));
+        }
+        Ok(key.to_string())
+    }
+
+    fn string(&mut self) -> Result<String, TresParseError> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(self.error("unterminated string")),
+                Some('"') => return Ok(out),
+                Some('\\') => {
+                    let escaped = match self.bump() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('u') => {
+                            let end = (self.pos + 4).min(self.src.len());
+                            let hex = self
+                                .src
+                                .get(self.pos..end)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?;
+                            self.pos = end;
+                            u32::from_str_radix(hex, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?
+                        }
+                        Some(c) => c,
+                        None => return Err(self.error("unterminated string")),
+                    };
+                    out.push(escaped);
+                }
+                Some(c) => out.push(c),
+            }
+        }
+    }
+
+    /// Parses comma-separated items up to (and including) `close`.
+    fn list<T>(
+        &mut self,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, TresParseError>,
+    ) -> Result<Vec<T>, TresParseError> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(close) {
+                self.bump();
+                return Ok(items);
+            }
+            items.push(item(self)?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some(c) if c == close => {}
+                _ => return Err(self.error(format!("expected `,` or `{}`", close))),
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<TresValue, TresParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err(self.error("expected a value, found end of file")),
+            Some('"') => Ok(TresValue::String(self.string()?)),
+            Some('&') => {
+                self.bump();
+                Ok(TresValue::StringName(self.string()?))
+            }
+            Some('^') => {
+                self.bump();
+                Ok(TresValue::NodePath(self.string()?))
+            }
+            Some('[') => {
+                self.bump();
+                Ok(TresValue::Array(self.list(']', Self::value)?))
+            }
+            Some('{') => {
+                self.bump();
+                let entries = self.list('}', |p| {
+                    let key = p.value()?;
+                    p.skip_whitespace();
+                    p.expect(':')?;
+                    Ok((key, p.value()?))
+                })?;
+                Ok(TresValue::Dictionary(entries))
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => self.number(),
+            Some(c) if c.is_alphabetic() || c == '_' => self.ident_value(),
+            Some(c) => Err(self.error(format!("unexpected character `{}`", c))),
+        }
+    }
+
+    fn number(&mut self) -> Result<TresValue, TresParseError> {
+        let start = self.pos;
+        if matches!(self.peek(), Some('-' | '+')) {
+            self.bump();
+        }
+        if self.rest().starts_with("inf") {
+            self.pos += "inf".len();
+            let neg = self.src[start..].starts_with('-');
+            return Ok(TresValue::Float(if neg {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            }));
+        }
+        let mut prev = ' ';
+        while let Some(c) = self.peek() {
+            let exponent_sign = (c == '-' || c == '+') && (prev == 'e' || prev == 'E');
+            if !(c.is_ascii_alphanumeric() || c == '.' || c == '_' || exponent_sign) {
+                break;
+            }
+            prev = c;
+            self.bump();
+        }
+        let text = self.src[start..self.pos].replace('_', "");
+        let is_float = text.contains(['.', 'e', 'E']);
+        let parsed = if is_float {
+            text.parse::<f64>().ok().map(TresValue::Float)
+        } else {
+            text.parse::<i64>().ok().map(TresValue::Int)
+        };
+        parsed.ok_or_else(|| self.error(format!("invalid number `{}`", text)))
+    }
+
+    fn ident_value(&mut self) -> Result<TresValue, TresParseError> {
+        let name = self.ident()?;
+        match name.as_str() {
+            "null" | "nil" => return Ok(TresValue::Null),
+            "true" => return Ok(TresValue::Bool(true)),
+            "false" => return Ok(TresValue::Bool(false)),
+            "inf" => return Ok(TresValue::Float(f64::INFINITY)),
+            "inf_neg" => return Ok(TresValue::Float(f64::NEG_INFINITY)),
+            "nan" => return Ok(TresValue::Float(f64::NAN)),
+            _ => {}
+        }
+        // `Array[int](...)` and `Dictionary[String, int](...)`
+        self.skip_whitespace();
+        let mut type_args = None;
+        if self.peek() == Some('[') {
+            let start = self.pos + 1;
+            let mut depth = 0;
+            loop {
+                match self.bump() {
+                    Some('[') => depth += 1,
+                    Some(']') => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    Some(_) => {}
+                    None => return Err(self.error("expected `]`, found end of file")),
+                }
+            }
+            type_args = Some(self.src[start..self.pos - 1].trim().to_string());
+            self.skip_whitespace();
+        }
+        if self.peek() != Some('(') {
+            return Err(self.error(format!("unexpected identifier `{}`", name)));
+        }
+        self.bump();
+        let mut args = self.list(')', Self::value)?;
+
+        let single_string = |args: &mut Vec<TresValue>| match args.as_slice() {
+            [TresValue::String(_)] | [TresValue::Int(_)] => Some(args.remove(0)),
+            _ => None,
+        };
+        let value = match name.as_str() {
+            "Array" if args.len() == 1 => match (type_args, args.remove(0)) {
+                (Some(element_type), TresValue::Array(values)) => {
+                    TresValue::TypedArray(element_type, values)
+                }
+                (_, value) => value,
+            },
+            "Dictionary" if args.len() == 1 => {
+                match (
+                    type_args.as_deref().and_then(split_type_args),
+                    args.remove(0),
+                ) {
+                    (Some((key_type, value_type)), TresValue::Dictionary(entries)) => {
+                        TresValue::TypedDictionary(key_type, value_type, entries)
+                    }
+                    (_, value) => value,
+                }
+            }
+            "SubResource" | "ExtResource" => {
+                let id = match single_string(&mut args) {
+                    Some(TresValue::String(id)) => id,
+                    Some(TresValue::Int(id)) => id.to_string(),
+                    _ => return Err(self.error(format!("invalid `{}` reference", name))),
+                };
+                if name == "SubResource" {
+                    TresValue::SubResource(id)
+                } else {
+                    TresValue::ExtResource(id)
+                }
+            }
+            "NodePath" | "StringName" => match single_string(&mut args) {
+                Some(TresValue::String(s)) if name == "NodePath" => TresValue::NodePath(s),
+                Some(TresValue::String(s)) => TresValue::StringName(s),
+                _ => return Err(self.error(format!("invalid `{}`", name))),
+            },
+            // Godot 4.3+ writes byte arrays base64-encoded.
+            "PackedByteArray" if matches!(args.as_slice(), [TresValue::String(_)]) => {
+                let Some(TresValue::String(encoded)) = args.pop() else {
+                    unreachable!()
+                };
+                let bytes = decode_base64(&encoded)
+                    .ok_or_else(|| self.error("invalid base64 in `PackedByteArray`"))?;
+                TresValue::Array(
+                    bytes
+                        .into_iter()
+                        .map(|b| TresValue::Int(b as i64))
+                        .collect(),
+                )
+            }
+            n if SCALAR_PACKED_ARRAYS.contains(&n) => TresValue::Array(args),
+            n if VECTOR_PACKED_ARRAYS.iter().any(|(packed, ..)| *packed == n) => {
+                split_packed_vectors(n, &args)
+                    .ok_or_else(|| self.error(format!("invalid `{}`", name)))?
+            }
+            _ => TresValue::Constructor { name, args },
+        };
+        Ok(value)
+    }
+}
+
+/// Splits the `String, int` of `Dictionary[String, int]` at the top-level comma.
+fn split_type_args(type_args: &str) -> Option<(String, String)> {
+    let mut depth = 0;
+    for (i, c) in type_args.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                let (key, value) = (type_args[..i].trim(), type_args[i + 1..].trim());
+                return Some((key.to_string(), value.to_string()));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(super) fn encode_base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let buf = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |buf, (i, b)| buf | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_ALPHABET[(buf >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(encoded.len() * 3 / 4);
+    let mut buf = 0u32;
+    let mut bits = 0;
+    for c in encoded.bytes().filter(|c| *c != b'=') {
+        let v = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buf = (buf << 6) | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buf >> bits) as u8);
+            buf &= (1 << bits) - 1;
+        }
+    }
+    Some(out)
+}
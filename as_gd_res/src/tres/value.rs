@@ -0,0 +1,190 @@
+use std::fmt;
+
+/// A property value as written in a Godot text resource.
+///
+/// Scalar packed arrays (`PackedInt32Array(1, 2)`, `PackedByteArray("AQI=")`, ...)
+/// are read as a plain [`TresValue::Array`]. So are packed vector and color arrays,
+/// with one [`TresValue::Constructor`] per element.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TresValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    /// `&"name"` or `StringName("name")`
+    StringName(String),
+    /// `NodePath("a/b")` or `^"a/b"`
+    NodePath(String),
+    Array(Vec<TresValue>),
+    /// `Array[int]([1, 2])`, with the element type (`int`, `NodePath`, `SimpleDataResource`, ...)
+    TypedArray(String, Vec<TresValue>),
+    Dictionary(Vec<(TresValue, TresValue)>),
+    /// `Dictionary[String, int]({"a": 1})`, with the key and value types
+    TypedDictionary(String, String, Vec<(TresValue, TresValue)>),
+    /// `SubResource("id")`, a reference to a `[sub_resource]` of the same file
+    SubResource(String),
+    /// `ExtResource("id")`, a reference to an `[ext_resource]` of the same file
+    ExtResource(String),
+    /// Any other constructor call, e.g. `Vector2(0, 0)`
+    Constructor {
+        name: String,
+        args: Vec<TresValue>,
+    },
+}
+
+impl TresValue {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            TresValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            TresValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Returns floats as well as integers, since Godot writes `1.0` as `1` in some places
+    /// (e.g. inside `Vector2(...)`).
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            TresValue::Float(f) => Some(*f),
+            TresValue::Int(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the contents of a `String` or `StringName`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            TresValue::String(s) | TresValue::StringName(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of a typed or untyped array.
+    pub fn as_array(&self) -> Option<&[TresValue]> {
+        match self {
+            TresValue::Array(values) | TresValue::TypedArray(_, values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the entries of a typed or untyped dictionary.
+    pub fn as_dictionary(&self) -> Option<&[(TresValue, TresValue)]> {
+        match self {
+            TresValue::Dictionary(entries) | TresValue::TypedDictionary(_, _, entries) => {
+                Some(entries)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the arguments if this is a call to the constructor `name`.
+    pub fn as_constructor(&self, name: &str) -> Option<&[TresValue]> {
+        match self {
+            TresValue::Constructor { name: n, args } if n == name => Some(args),
+            _ => None,
+        }
+    }
+}
+
+fn write_str(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            _ => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+fn write_list<'a>(
+    f: &mut fmt::Formatter<'_>,
+    values: impl IntoIterator<Item = &'a TresValue>,
+) -> fmt::Result {
+    for (i, v) in values.into_iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}", v)?;
+    }
+    Ok(())
+}
+
+fn write_dict(f: &mut fmt::Formatter<'_>, entries: &[(TresValue, TresValue)]) -> fmt::Result {
+    if entries.is_empty() {
+        return f.write_str("{}");
+    }
+    f.write_str("{\n")?;
+    for (i, (k, v)) in entries.iter().enumerate() {
+        if i > 0 {
+            f.write_str(",\n")?;
+        }
+        write!(f, "{}: {}", k, v)?;
+    }
+    f.write_str("\n}")
+}
+
+/// Formats the value the way Godot writes it in a `.tres` file.
+impl fmt::Display for TresValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TresValue::Null => f.write_str("null"),
+            TresValue::Bool(b) => write!(f, "{}", b),
+            TresValue::Int(i) => write!(f, "{}", i),
+            TresValue::Float(x) if x.is_nan() => f.write_str("nan"),
+            TresValue::Float(x) if x.is_infinite() => {
+                f.write_str(if *x > 0.0 { "inf" } else { "inf_neg" })
+            }
+            TresValue::Float(x) => write!(f, "{:?}", x),
+            TresValue::String(s) => write_str(f, s),
+            TresValue::StringName(s) => {
+                f.write_str("&")?;
+                write_str(f, s)
+            }
+            TresValue::NodePath(s) => {
+                f.write_str("NodePath(")?;
+                write_str(f, s)?;
+                f.write_str(")")
+            }
+            TresValue::Array(values) => {
+                f.write_str("[")?;
+                write_list(f, values)?;
+                f.write_str("]")
+            }
+            TresValue::TypedArray(element_type, values) => {
+                write!(f, "Array[{}]([", element_type)?;
+                write_list(f, values)?;
+                f.write_str("])")
+            }
+            TresValue::Dictionary(entries) => write_dict(f, entries),
+            TresValue::TypedDictionary(key_type, value_type, entries) => {
+                write!(f, "Dictionary[{}, {}](", key_type, value_type)?;
+                write_dict(f, entries)?;
+                f.write_str(")")
+            }
+            TresValue::SubResource(id) => {
+                f.write_str("SubResource(")?;
+                write_str(f, id)?;
+                f.write_str(")")
+            }
+            TresValue::ExtResource(id) => {
+                f.write_str("ExtResource(")?;
+                write_str(f, id)?;
+                f.write_str(")")
+            }
+            TresValue::Constructor { name, args } => {
+                write!(f, "{}(", name)?;
+                write_list(f, args)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use super::{
+    ExternalDocuments, ToTresResource, TresDocument, TresExtResource, TresResource, TresValue,
+};
+
+/// Collects the sub-resources and ext-resources of a document while it is being written.
+///
+/// Sub-resources are added children-first, so every `SubResource(...)` reference
+/// points to a section that comes before it in the file, as Godot requires.
+#[derive(Default)]
+pub struct TresWriter {
+    ext_resources: Vec<TresExtResource>,
+    sub_resources: Vec<TresResource>,
+}
+
+impl TresWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `value` as a `[sub_resource]` and returns a reference to it.
+    pub fn add_sub_resource<T: ToTresResource + ?Sized>(&mut self, value: &T) -> TresValue {
+        let properties = value.to_tres_properties(self);
+        self.add_raw_sub_resource(value.tres_type_name(), properties)
+    }
+
+    /// Adds a `[sub_resource]` of the Godot class `type_name` (e.g. `Curve`) and
+    /// returns a reference to it.
+    pub fn add_raw_sub_resource(
+        &mut self,
+        type_name: String,
+        properties: Vec<(String, TresValue)>,
+    ) -> TresValue {
+        let id = format!("{}_{}", type_name, self.sub_resources.len() + 1);
+        self.sub_resources.push(TresResource {
+            type_name,
+            id: Some(id.clone()),
+            properties,
+        });
+        TresValue::SubResource(id)
+    }
+
+    /// Adds an `[ext_resource]` for the file at `path` (e.g. `res://scene.tscn`),
+    /// reusing an existing entry for the same path, and returns a reference to it.
+    pub fn add_ext_resource(&mut self, type_name: &str, path: &str) -> TresValue {
+        if let Some(ext) = self.ext_resources.iter().find(|e| e.path == path) {
+            return TresValue::ExtResource(ext.id.clone());
+        }
+        let id = (self.ext_resources.len() + 1).to_string();
+        self.ext_resources.push(TresExtResource {
+            id: id.clone(),
+            type_name: type_name.to_string(),
+            path: path.to_string(),
+        });
+        TresValue::ExtResource(id)
+    }
+}
+
+impl TresDocument {
+    /// Builds a document whose main resource is `value`.
+    pub fn from_value<T: ToTresResource + ?Sized>(value: &T) -> Self {
+        let mut writer = TresWriter::new();
+        let properties = value.to_tres_properties(&mut writer);
+        Self {
+            path: String::new(),
+            project_root: None,
+            external: ExternalDocuments::default(),
+            ext_resources: writer.ext_resources,
+            sub_resources: writer.sub_resources,
+            resource: TresResource {
+                type_name: value.tres_type_name(),
+                id: None,
+                properties,
+            },
+        }
+    }
+}
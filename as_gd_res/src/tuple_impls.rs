@@ -0,0 +1,169 @@
+//! Tuples as fields, stored as an untyped `Array` with one element per tuple field.
+//! Each element is stored like a dictionary value (see [`AsGdResDictElement`]).
+
+use godot::prelude::*;
+
+use crate::{
+    AsGdRes, AsGdResArray, AsGdResDictElement, ExtractContext, ExtractError, FromGdRes,
+    FromGdResArray, ToGdRes, ToGdResArray, apply_to_array, array_element, try_from_array,
+};
+
+/// The initial value of a tuple field in a new resource: the default element of each
+/// tuple field (see [`AsGdResDictElement::default_gd_element`]), so the inspector shows
+/// a slot for each of them and an unedited tuple can be extracted.
+pub fn sized_gd_tuple<T>() -> Array<Variant>
+where
+    T: AsGdResDictElement<GdElement = Array<Variant>>,
+{
+    T::default_gd_element().to()
+}
+
+/// Fails unless the Godot-side array of a tuple has exactly `len` elements.
+fn check_tuple_len(res: &Array<Variant>, len: usize) -> Result<(), ExtractError> {
+    if res.len() != len {
+        return Err(ExtractError::invalid(format!(
+            "expected {} tuple elements, found {}",
+            len,
+            res.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Extracts the tuple field at `index` from the Godot-side array of a tuple.
+fn tuple_element<T: AsGdResDictElement>(
+    res: &Array<Variant>,
+    index: usize,
+    ctx: &mut ExtractContext,
+) -> Result<T, ExtractError> {
+    let variant = array_element(res, index).unwrap_or_default();
+    if variant.is_nil() {
+        return Err(ExtractError::null_element().at_index(index));
+    }
+    let element = variant.try_to::<T::GdElement>().map_err(|_| {
+        ExtractError::invalid(format!("unexpected tuple element `{}`", variant)).at_index(index)
+    })?;
+    T::try_from_gd_element(&element, ctx).map_err(|e| e.at_index(index))
+}
+
+/// Applies the tuple field at `index` onto the existing element if it holds the
+/// right Godot type, reusing sub-resources, and rebuilds it otherwise. Sets
+/// `replaced` if the element stored in the array has to change.
+fn apply_tuple_element<T: AsGdResDictElement>(
+    value: &T,
+    res: &Array<Variant>,
+    index: usize,
+    replaced: &mut bool,
+) -> Variant {
+    match array_element(res, index).and_then(|v| v.try_to::<T::GdElement>().ok()) {
+        Some(mut element) => {
+            *replaced |= value.apply_to_gd_element(&mut element);
+            element.to_variant()
+        }
+        None => {
+            *replaced = true;
+            value.to_gd_element().to_variant()
+        }
+    }
+}
+
+macro_rules! impl_as_gd_res_tuple {
+    ($($len:literal => ($($t:ident $i:tt),*);)*) => {
+        $(
+            impl<$($t: AsGdResDictElement),*> AsGdRes for ($($t,)*) {
+                type ResType = Array<Variant>;
+            }
+
+            impl<$($t: AsGdResDictElement),*> ToGdRes for ($($t,)*) {
+                fn to_gd_res(&self) -> Self::ResType {
+                    self.to_gd_element()
+                }
+
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    self.apply_to_gd_element(res)
+                }
+            }
+
+            impl<$($t: AsGdResDictElement),*> FromGdRes for ($($t,)*) {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    ctx: &mut ExtractContext,
+                ) -> Result<Self, ExtractError> {
+                    Self::try_from_gd_element(res, ctx)
+                }
+            }
+
+            impl<$($t: AsGdResDictElement),*> AsGdResArray for ($($t,)*) {
+                type GdArray = Array<Array<Variant>>;
+            }
+
+            impl<$($t: AsGdResDictElement),*> ToGdResArray for ($($t,)*) {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray
+                where
+                    Self: 'a,
+                {
+                    values.into_iter().map(Self::to_gd_element).collect()
+                }
+
+                fn apply_to_gd_array<'a>(
+                    values: impl IntoIterator<Item = &'a Self>,
+                    res: &mut Self::GdArray,
+                ) -> bool
+                where
+                    Self: 'a,
+                {
+                    apply_to_array(values, res, Self::to_gd_element, Self::apply_to_gd_element)
+                }
+            }
+
+            impl<$($t: AsGdResDictElement),*> FromGdResArray for ($($t,)*) {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    ctx: &mut ExtractContext,
+                ) -> Result<Vec<Self>, ExtractError> {
+                    try_from_array(res, ctx, Self::try_from_gd_element)
+                }
+            }
+
+            impl<$($t: AsGdResDictElement),*> AsGdResDictElement for ($($t,)*) {
+                type GdElement = Array<Variant>;
+
+                fn to_gd_element(&self) -> Self::GdElement {
+                    [$(self.$i.to_gd_element().to_variant()),*].into_iter().collect()
+                }
+
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    ctx: &mut ExtractContext,
+                ) -> Result<Self, ExtractError> {
+                    check_tuple_len(element, $len)?;
+                    Ok(($(tuple_element::<$t>(element, $i, ctx)?,)*))
+                }
+
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    let mut replaced = res.len() != $len;
+                    let elements = [$(apply_tuple_element(&self.$i, res, $i, &mut replaced)),*];
+                    if replaced {
+                        *res = elements.into_iter().collect();
+                    }
+                    replaced
+                }
+
+                fn default_gd_element() -> Variant {
+                    [$($t::default_gd_element()),*]
+                        .into_iter()
+                        .collect::<Array<Variant>>()
+                        .to_variant()
+                }
+            }
+        )*
+    };
+}
+
+impl_as_gd_res_tuple! {
+    2 => (A 0, B 1);
+    3 => (A 0, B 1, C 2);
+    4 => (A 0, B 1, C 2, D 3);
+    5 => (A 0, B 1, C 2, D 3, E 4);
+    6 => (A 0, B 1, C 2, D 3, E 4, F 5);
+}
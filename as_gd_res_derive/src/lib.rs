@@ -8,29 +8,48 @@ use syn::{Data, DeriveInput, Fields, Type, parse_quote};
 #[proc_macro_derive(AsGdRes, attributes(export, init, var, as_gd_res, as_gd_res_types))]
 pub fn as_gd_res_derive(input: TokenStream) -> TokenStream {
     let derive_input = syn::parse_macro_input!(input as DeriveInput);
-    TokenStream::from(expand_as_gd_res(derive_input))
+    TokenStream::from(expand_as_gd_res(derive_input, cfg!(feature = "godot")))
 }
 
 /// Substitute generic type parameters with concrete types, recursing into
-/// generic arguments (e.g. `Foo<T1, T2>` → `Foo<i32, String>`).
+/// generic arguments (e.g. `Foo<T1, T2>` → `Foo<i32, String>`). Const parameters are
+/// substituted the same way, including in array lengths (e.g. `[Slot; N]` → `[Slot; 4]`).
+/// Tuple elements are substituted one by one.
 fn substitute_type(ty: &Type, type_map: &HashMap<String, Type>) -> Type {
     match ty {
         Type::Path(type_path) => {
             let mut new_path = type_path.clone();
             for segment in &mut new_path.path.segments {
                 // Check if this segment itself is a generic parameter name
-                if segment.arguments.is_none()
-                    || matches!(segment.arguments, syn::PathArguments::None)
+                if (segment.arguments.is_none()
+                    || matches!(segment.arguments, syn::PathArguments::None))
+                    && let Some(concrete_ty) = type_map.get(&segment.ident.to_string())
                 {
-                    if let Some(concrete_ty) = type_map.get(&segment.ident.to_string()) {
-                        return concrete_ty.clone();
-                    }
+                    return concrete_ty.clone();
                 }
                 // Recurse into generic arguments (e.g. <T1, T2> in Foo<T1, T2>)
                 substitute_path_arguments(&mut segment.arguments, type_map);
             }
             Type::Path(new_path)
         }
+        Type::Array(type_array) => {
+            let mut new_array = type_array.clone();
+            *new_array.elem = substitute_type(&type_array.elem, type_map);
+            if let syn::Expr::Path(len_path) = &type_array.len
+                && let Some(len_ident) = len_path.path.get_ident()
+                && let Some(concrete_len) = type_map.get(&len_ident.to_string())
+            {
+                new_array.len = syn::Expr::Verbatim(quote! { #concrete_len });
+            }
+            Type::Array(new_array)
+        }
+        Type::Tuple(type_tuple) => {
+            let mut new_tuple = type_tuple.clone();
+            for elem in &mut new_tuple.elems {
+                *elem = substitute_type(elem, type_map);
+            }
+            Type::Tuple(new_tuple)
+        }
         _ => ty.clone(),
     }
 }
@@ -46,11 +65,195 @@ fn substitute_path_arguments(args: &mut syn::PathArguments, type_map: &HashMap<S
     }
 }
 
-fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
+/// Returns `T` if `ty` is `Vec<T>`.
+fn vec_element_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+            syn::GenericArgument::Type(element_ty) => Some(element_ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns `T` if `ty` is `Option<T>` of a number, `bool`, `String` or `NodePathString`.
+/// These have no null value in Godot, so the field is exported as a
+/// `{field}_enabled` toggle plus a typed `{field}` property instead.
+fn toggle_value_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let Some(syn::GenericArgument::Type(value_ty)) = args.args.first() else {
+        return None;
+    };
+    let Type::Path(value_path) = value_ty else {
+        return None;
+    };
+    let value_segment = value_path.path.segments.last()?;
+    let is_value = matches!(
+        value_segment.ident.to_string().as_str(),
+        "i8" | "i16"
+            | "i32"
+            | "i64"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "f32"
+            | "f64"
+            | "bool"
+            | "String"
+            | "NodePathString"
+    );
+    (is_value && value_segment.arguments.is_empty()).then_some(value_ty)
+}
+
+/// The payload type of a single-tuple enum variant, with the generics of the enum
+/// substituted, e.g. `CoinData<i32>` for `Coins(CoinData<C>)`. Its resource class is
+/// `<#payload as ::as_gd_res::AsGdResClass>::GdClass`.
+fn payload_type(fields: &syn::FieldsUnnamed, type_map: Option<&HashMap<String, Type>>) -> Type {
+    let ty = &fields.unnamed[0].ty;
+    match type_map {
+        Some(type_map) => substitute_type(ty, type_map),
+        None => ty.clone(),
+    }
+}
+
+/// A tuple of `items`, e.g. `(a,)` or `(a, b)`.
+fn tuple_tokens<T: quote::ToTokens>(items: &[T]) -> proc_macro2::TokenStream {
+    match items {
+        [item] => quote! { (#item,) },
+        items => quote! { (#(#items),*) },
+    }
+}
+
+/// Whether the attributes include `#[as_gd_res(<flag>)]`.
+fn has_as_gd_res_flag(attrs: &[syn::Attribute], flag: &str) -> bool {
+    attrs.iter().any(|a| {
+        a.path().is_ident("as_gd_res")
+            && matches!(&a.meta, syn::Meta::List(meta_list)
+            if meta_list.tokens.clone().into_iter().any(|tok| {
+                matches!(tok, TokenTree::Ident(id) if id == flag)
+            }))
+    })
+}
+
+/// Parses `#[as_gd_res_types(name = "IntStrPair", T1 = i32, T2 = String, N = 4)]` into the
+/// optional name and the map from generic parameter to concrete type or const value.
+fn parse_as_gd_res_types(
+    attr: &syn::Attribute,
+) -> Result<(Option<syn::Ident>, HashMap<String, Type>), &'static str> {
+    let mut types_name = None;
+    let mut map = HashMap::new();
+    if let syn::Meta::List(meta_list) = &attr.meta {
+        let mut iter = meta_list.tokens.clone().into_iter();
+        while let Some(tok) = iter.next() {
+            if let TokenTree::Ident(param_name) = &tok {
+                // skip '='
+                if let Some(TokenTree::Punct(p)) = iter.next()
+                    && p.as_char() == '='
+                {
+                    // Collect tokens until we hit a comma or end
+                    let mut type_tokens = Vec::new();
+                    loop {
+                        match iter.next() {
+                            Some(TokenTree::Punct(p)) if p.as_char() == ',' => break,
+                            Some(t) => type_tokens.push(t),
+                            None => break,
+                        }
+                    }
+                    let type_stream: proc_macro2::TokenStream = type_tokens.into_iter().collect();
+                    if param_name == "name" {
+                        let ident = syn::parse2::<syn::LitStr>(type_stream)
+                            .and_then(|lit| lit.parse::<syn::Ident>())
+                            .map_err(|_| {
+                                "`as_gd_res_types` names must be identifiers in quotes, e.g. `name = \"IntStrPair\"`"
+                            })?;
+                        types_name = Some(ident);
+                    } else if let Ok(ty) = syn::parse2::<Type>(type_stream.clone()) {
+                        // Parse the collected tokens as a Type
+                        map.insert(param_name.to_string(), ty);
+                    } else if let Ok(value) = syn::parse2::<syn::Expr>(type_stream) {
+                        // A const parameter like `N = 4`. It is kept as verbatim tokens,
+                        // braced unless it's a literal, so it can be used as a generic
+                        // argument as well as an array length.
+                        let value = match value {
+                            syn::Expr::Lit(_) | syn::Expr::Block(_) => quote! { #value },
+                            _ => quote! { { #value } },
+                        };
+                        map.insert(param_name.to_string(), Type::Verbatim(value));
+                    }
+                }
+            }
+        }
+    }
+    Ok((types_name, map))
+}
+
+/// Expands the derive. With `godot == false` only the `tres` impls are emitted, so the
+/// type does not need Godot at all.
+fn expand_as_gd_res(input: DeriveInput, godot: bool) -> proc_macro2::TokenStream {
+    expand_item(input, godot, false)
+}
+
+/// Expands the derive for a struct, or for the fields struct of an enum variant with
+/// `variant_fields`. Those are only ever extracted, so instead of the `ToGdRes` impls
+/// they get `from_fields`, `apply_fields` and `fields_to_tres`, which take a tuple of
+/// references to the fields as bound in a `match` on the enum.
+fn expand_item(
+    mut input: DeriveInput,
+    godot: bool,
+    variant_fields: bool,
+) -> proc_macro2::TokenStream {
+    // Several #[as_gd_res_types(name = "...", ...)] blocks each derive one instantiation
+    let types_attrs = input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("as_gd_res_types"))
+        .cloned()
+        .collect::<Vec<_>>();
+    if types_attrs.len() > 1 {
+        if types_attrs
+            .iter()
+            .any(|a| matches!(parse_as_gd_res_types(a), Ok((None, _))))
+        {
+            return quote! { compile_error!("each `#[as_gd_res_types(...)]` needs a `name = \"...\"` when there is more than one"); };
+        }
+        let mut expanded = proc_macro2::TokenStream::new();
+        for i in 0..types_attrs.len() {
+            let mut instantiation = input.clone();
+            let mut index = 0;
+            instantiation.attrs.retain(|a| {
+                if !a.path().is_ident("as_gd_res_types") {
+                    return true;
+                }
+                index += 1;
+                index - 1 == i
+            });
+            expanded.extend(expand_as_gd_res(instantiation, godot));
+        }
+        return expanded;
+    }
+
     // Detect #[as_gd_res(post_init = METHOD)] on the struct
     let mut post_init_method: Option<proc_macro2::Ident> = None;
     // Detect #[as_gd_res_types(T1 = i32, T2 = String)] on the struct
     let mut generic_type_map: Option<HashMap<String, Type>> = None;
+    // The generated classes are named after `name = "..."` from #[as_gd_res_types], if given
+    let mut base_name = input.ident.clone();
     let mut new_attrs = Vec::new();
     for attr in input.attrs.into_iter() {
         if attr.path().is_ident("as_gd_res") {
@@ -58,51 +261,29 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
             if let syn::Meta::List(meta_list) = &attr.meta {
                 let mut iter = meta_list.tokens.clone().into_iter();
                 while let Some(tok) = iter.next() {
-                    if let TokenTree::Ident(id) = &tok {
-                        if id == "post_init" {
-                            // skip '='
-                            let _ = iter.next();
-                            if let Some(TokenTree::Ident(method_ident)) = iter.next() {
-                                post_init_method = Some(method_ident.clone());
-                                break;
-                            }
+                    if let TokenTree::Ident(id) = &tok
+                        && id == "post_init"
+                    {
+                        // skip '='
+                        let _ = iter.next();
+                        if let Some(TokenTree::Ident(method_ident)) = iter.next() {
+                            post_init_method = Some(method_ident.clone());
+                            break;
                         }
                     }
                 }
             }
             // Do not propagate this attribute
         } else if attr.path().is_ident("as_gd_res_types") {
-            // Parse #[as_gd_res_types(T1 = i32, T2 = String)]
-            if let syn::Meta::List(meta_list) = &attr.meta {
-                let mut map = HashMap::new();
-                let mut iter = meta_list.tokens.clone().into_iter();
-                while let Some(tok) = iter.next() {
-                    if let TokenTree::Ident(param_name) = &tok {
-                        // skip '='
-                        if let Some(TokenTree::Punct(p)) = iter.next() {
-                            if p.as_char() == '=' {
-                                // Collect tokens until we hit a comma or end
-                                let mut type_tokens = Vec::new();
-                                loop {
-                                    match iter.next() {
-                                        Some(TokenTree::Punct(p)) if p.as_char() == ',' => break,
-                                        Some(t) => type_tokens.push(t),
-                                        None => break,
-                                    }
-                                }
-                                // Parse the collected tokens as a Type
-                                let type_stream: proc_macro2::TokenStream =
-                                    type_tokens.into_iter().collect();
-                                if let Ok(ty) = syn::parse2::<Type>(type_stream) {
-                                    map.insert(param_name.to_string(), ty);
-                                }
-                            }
-                        }
-                    }
-                }
-                if !map.is_empty() {
-                    generic_type_map = Some(map);
-                }
+            let (types_name, map) = match parse_as_gd_res_types(&attr) {
+                Ok(parsed) => parsed,
+                Err(msg) => return quote! { compile_error!(#msg); },
+            };
+            if let Some(types_name) = types_name {
+                base_name = types_name;
+            }
+            if !map.is_empty() {
+                generic_type_map = Some(map);
             }
             // Do not propagate this attribute
         } else {
@@ -117,7 +298,7 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
     }
 
     let name = input.ident.clone();
-    let res_name = format_ident!("{}Resource", name);
+    let res_name = format_ident!("{}Resource", base_name);
 
     // Build concrete type arguments for trait impls if we have generics
     let concrete_type_args = if let Some(ref type_map) = generic_type_map {
@@ -125,12 +306,12 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
             .generics
             .params
             .iter()
-            .filter_map(|param| {
-                if let syn::GenericParam::Type(ty_param) = param {
-                    type_map.get(&ty_param.ident.to_string())
-                } else {
-                    None
+            .filter_map(|param| match param {
+                syn::GenericParam::Type(ty_param) => type_map.get(&ty_param.ident.to_string()),
+                syn::GenericParam::Const(const_param) => {
+                    type_map.get(&const_param.ident.to_string())
                 }
+                _ => None,
             })
             .collect();
         if args.is_empty() {
@@ -148,7 +329,13 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
                 return quote! { compile_error!("`derive(AsGdRes)` only supports structs with named fields"); };
             }
             let mut defs = Vec::new();
-            let mut extracts = Vec::new();
+            let mut try_extracts = Vec::new();
+            let mut to_gd_fields = Vec::new();
+            let mut apply_fields = Vec::new();
+            let mut tres_fields = Vec::new();
+            let mut to_tres_fields = Vec::new();
+            // The type of each field, as taken by reference with `variant_fields`
+            let mut field_params = Vec::new();
             // For init code: collect (ident, option<Lit>)
             let mut init_assigns = Vec::new();
             for field in data.fields.iter() {
@@ -179,46 +366,206 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
                     } else {
                         ty.clone()
                     };
-                    defs.push(quote! {
-                        #(#attrs)*
-                        pub #ident: <#concrete_ty as ::as_gd_res::AsGdRes>::ResType,
-                    });
-                    extracts.push(quote! {
-                        #ident: self.#ident.extract(),
-                    });
-                    // For init assignments if post_init
-                    if post_init_method.is_some() {
-                        // Find init attr in original field.attrs
-                        let mut init_value = None;
-                        for a in field.attrs.iter() {
-                            if a.path().is_ident("init") {
-                                if let syn::Meta::List(meta_list) = &a.meta {
-                                    let mut token_iter = meta_list.tokens.clone().into_iter();
-                                    while let Some(tok2) = token_iter.next() {
-                                        if let TokenTree::Ident(id2) = &tok2 {
-                                            if id2 == "val" {
-                                                // skip '='
-                                                let _ = token_iter.next();
-                                                if let Some(next_tok) = token_iter.next() {
-                                                    if let TokenTree::Literal(lit) = &next_tok {
-                                                        init_value = Some(quote! { #lit });
-                                                        break;
-                                                    }
-                                                }
-                                            }
-                                        }
+                    // #[as_gd_res(packed)] stores a `Vec` of numbers as a packed array
+                    // instead of a typed `Array`
+                    let packed = has_as_gd_res_flag(&field.attrs, "packed");
+                    // #[as_gd_res(pad)] pads or truncates a `[T; N]` field to `N` elements
+                    // instead of failing on other lengths
+                    let pad = has_as_gd_res_flag(&field.attrs, "pad");
+                    let fixed_array = match &concrete_ty {
+                        Type::Array(type_array) => Some(type_array),
+                        _ => None,
+                    };
+                    if pad && fixed_array.is_none() {
+                        return quote! { compile_error!("`#[as_gd_res(pad)]` is only supported on `[T; N]` fields"); };
+                    }
+                    // A new resource starts with `N` elements in a `[T; N]` field, and
+                    // with the default element of each tuple field in a tuple field
+                    let sized_array = match &concrete_ty {
+                        Type::Array(type_array) => {
+                            let element_ty = &type_array.elem;
+                            let len = &type_array.len;
+                            Some(quote! { ::as_gd_res::sized_gd_array::<#element_ty>(#len) })
+                        }
+                        Type::Tuple(type_tuple) if !type_tuple.elems.is_empty() => {
+                            Some(quote! { ::as_gd_res::sized_gd_tuple::<#concrete_ty>() })
+                        }
+                        _ => None,
+                    };
+                    let has_init = field.attrs.iter().any(|a| a.path().is_ident("init"));
+                    if let Some(sized_array) = &sized_array
+                        && !has_init
+                        && post_init_method.is_none()
+                    {
+                        attrs.push(parse_quote!(#[init(val = #sized_array)]));
+                    }
+                    let ident_str = ident.to_string();
+                    // Where the conversions to Godot and `.tres` read the field from
+                    let (value_field, self_field) = if variant_fields {
+                        let index = syn::Index::from(field_params.len());
+                        (quote! { (*fields.#index) }, quote! { (*fields.#index) })
+                    } else {
+                        (quote! { value.#ident }, quote! { self.#ident })
+                    };
+                    field_params.push(quote! { &#concrete_ty });
+                    if packed {
+                        let Some(element_ty) = vec_element_type(&concrete_ty) else {
+                            return quote! { compile_error!("`#[as_gd_res(packed)]` is only supported on `Vec` fields"); };
+                        };
+                        defs.push(quote! {
+                            #(#attrs)*
+                            pub #ident: ::as_gd_res::GdPackedOf<#element_ty>,
+                        });
+                        try_extracts.push(quote! {
+                            #ident: ::as_gd_res::from_gd_packed_of(&self.#ident),
+                        });
+                        to_gd_fields.push(quote! {
+                            #ident: ::as_gd_res::to_gd_packed_of(&#value_field),
+                        });
+                        apply_fields.push(quote! {
+                            | ::as_gd_res::apply_to_gd_packed_of(&#self_field, &mut bound.#ident)
+                        });
+                        to_tres_fields.push(quote! {
+                            (#ident_str.to_string(), ::as_gd_res::tres::packed_to_tres(&#self_field, writer)),
+                        });
+                    } else if let Some(value_ty) = toggle_value_type(&concrete_ty) {
+                        let enabled_ident = format_ident!("{}_enabled", ident);
+                        let enabled_str = enabled_ident.to_string();
+                        defs.push(quote! {
+                            #[export]
+                            pub #enabled_ident: bool,
+                            #(#attrs)*
+                            pub #ident: <#value_ty as ::as_gd_res::AsGdRes>::ResType,
+                        });
+                        try_extracts.push(quote! {
+                            #ident: ::as_gd_res::try_from_gd_toggle(self.#enabled_ident, &self.#ident, ctx).map_err(|e| e.in_field(#ident_str))?,
+                        });
+                        to_gd_fields.push(quote! {
+                            #enabled_ident: #value_field.is_some(),
+                            #ident: ::as_gd_res::to_gd_toggle(#value_field.as_ref()),
+                        });
+                        apply_fields.push(quote! {
+                            | ::as_gd_res::apply_to_gd_toggle(#self_field.as_ref(), &mut bound.#enabled_ident, &mut bound.#ident)
+                        });
+                        to_tres_fields.push(quote! {
+                            (#enabled_str.to_string(), ::as_gd_res::tres::ToTres::to_tres(&#self_field.is_some(), writer)),
+                            (#ident_str.to_string(), ::as_gd_res::tres::toggle_to_tres(#self_field.as_ref(), writer)),
+                        });
+                    } else if pad {
+                        defs.push(quote! {
+                            #(#attrs)*
+                            pub #ident: <#concrete_ty as ::as_gd_res::AsGdRes>::ResType,
+                        });
+                        try_extracts.push(quote! {
+                            #ident: ::as_gd_res::pad_array(::as_gd_res::FromGdRes::try_from_gd_res_with(&self.#ident, ctx).map_err(|e| e.in_field(#ident_str))?),
+                        });
+                        to_gd_fields.push(quote! {
+                            #ident: ::as_gd_res::ToGdRes::to_gd_res(&#value_field),
+                        });
+                        apply_fields.push(quote! {
+                            | ::as_gd_res::ToGdRes::apply_to_gd_res(&#self_field, &mut bound.#ident)
+                        });
+                        to_tres_fields.push(quote! {
+                            (#ident_str.to_string(), ::as_gd_res::tres::ToTres::to_tres(&#self_field, writer)),
+                        });
+                    } else {
+                        defs.push(quote! {
+                            #(#attrs)*
+                            pub #ident: <#concrete_ty as ::as_gd_res::AsGdRes>::ResType,
+                        });
+                        try_extracts.push(quote! {
+                            #ident: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.#ident, ctx).map_err(|e| e.in_field(#ident_str))?,
+                        });
+                        to_gd_fields.push(quote! {
+                            #ident: ::as_gd_res::ToGdRes::to_gd_res(&#value_field),
+                        });
+                        apply_fields.push(quote! {
+                            | ::as_gd_res::ToGdRes::apply_to_gd_res(&#self_field, &mut bound.#ident)
+                        });
+                        to_tres_fields.push(quote! {
+                            (#ident_str.to_string(), ::as_gd_res::tres::ToTres::to_tres(&#self_field, writer)),
+                        });
+                    }
+                    // Find the init value in the original field.attrs
+                    let mut init_value = None;
+                    for a in field.attrs.iter() {
+                        if a.path().is_ident("init")
+                            && let syn::Meta::List(meta_list) = &a.meta
+                        {
+                            let mut token_iter = meta_list.tokens.clone().into_iter();
+                            while let Some(tok2) = token_iter.next() {
+                                if let TokenTree::Ident(id2) = &tok2
+                                    && id2 == "val"
+                                {
+                                    // skip '='
+                                    let _ = token_iter.next();
+                                    if let Some(next_tok) = token_iter.next()
+                                        && let TokenTree::Literal(lit) = &next_tok
+                                    {
+                                        init_value = Some(quote! { #lit });
+                                        break;
                                     }
                                 }
                             }
                         }
+                    }
+                    // Fields missing from a .tres file have their init value (or the default)
+                    let toggle = toggle_value_type(&concrete_ty).is_some();
+                    if toggle {
+                        let enabled_str = format!("{}_enabled", ident);
+                        tres_fields.push(quote! {
+                            #ident: ::as_gd_res::tres::toggle_field(res, doc, #enabled_str, #ident_str)?,
+                        });
+                    } else if let Some(val) = &init_value {
+                        tres_fields.push(quote! {
+                            #ident: ::as_gd_res::tres::field_or_else(res, doc, #ident_str, || #val.into())?,
+                        });
+                    } else if pad {
+                        tres_fields.push(quote! {
+                            #ident: ::as_gd_res::pad_array(::as_gd_res::tres::field(res, doc, #ident_str)?),
+                        });
+                    } else {
+                        tres_fields.push(quote! {
+                            #ident: ::as_gd_res::tres::field(res, doc, #ident_str)?,
+                        });
+                    }
+                    // For init assignments if post_init
+                    if post_init_method.is_some() {
+                        if toggle {
+                            let enabled_ident = format_ident!("{}_enabled", ident);
+                            init_assigns.push(quote! { #enabled_ident: false, });
+                        }
                         if let Some(val) = init_value {
                             init_assigns.push(quote! { #ident: #val.into(), });
+                        } else if let Some(sized_array) = sized_array {
+                            init_assigns.push(quote! { #ident: #sized_array, });
                         } else {
                             init_assigns.push(quote! { #ident: Default::default(), });
                         }
                     }
                 }
             }
+            // Errors name the variant, e.g. `RewardCoins`, rather than its fields struct
+            let fields_tuple = tuple_tokens(&field_params);
+            let name_str = if variant_fields { &base_name } else { &name }.to_string();
+            let res_name_str = res_name.to_string();
+            let changed_expr = if apply_fields.is_empty() {
+                quote! { false }
+            } else {
+                quote! {
+                    {
+                        let mut bound = res.bind_mut();
+                        false #(#apply_fields)*
+                    }
+                }
+            };
+
+            let writer_ident = if to_tres_fields.is_empty() {
+                format_ident!("_writer")
+            } else {
+                format_ident!("writer")
+            };
+
             // Determine class attribute
             let class_attr = if post_init_method.is_some() {
                 quote! { #[class(tool,base = Resource)] }
@@ -226,10 +573,90 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
                 quote! { #[class(tool,init,base = Resource)] }
             };
 
-            let mut expanded = quote! {
+            let from_tres_impl = quote! {
+                impl ::as_gd_res::tres::FromTresResource for #name #concrete_type_args {
+                    fn from_tres_resource(
+                        res: &::as_gd_res::tres::TresResource,
+                        doc: &::as_gd_res::tres::TresDocument,
+                    ) -> Result<Self, ::as_gd_res::ExtractError> {
+                        let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                            res.expect_type(#res_name_str)?;
+                            Ok(Self {
+                                #(#tres_fields)*
+                            })
+                        })();
+                        extracted.map_err(|e| e.in_resource(#name_str, &doc.resource_path(res)))
+                    }
+                }
+            };
+            let tres_impls = if variant_fields {
+                quote! {
+                    #from_tres_impl
+
+                    impl #name {
+                        /// The `.tres` properties of the variant resource, from the fields of the
+                        /// variant bound by reference.
+                        pub fn fields_to_tres(
+                            fields: #fields_tuple,
+                            #writer_ident: &mut ::as_gd_res::tres::TresWriter,
+                        ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                            vec![
+                                #(#to_tres_fields)*
+                            ]
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                #from_tres_impl
+
+                impl ::as_gd_res::tres::FromTres for #name #concrete_type_args {
+                    fn from_tres(
+                        value: &::as_gd_res::tres::TresValue,
+                        doc: &::as_gd_res::tres::TresDocument,
+                    ) -> Result<Self, ::as_gd_res::ExtractError> {
+                        doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+                    }
+                }
+
+                impl ::as_gd_res::tres::ToTresResource for #name #concrete_type_args {
+                    fn tres_type_name(&self) -> String {
+                        #res_name_str.to_string()
+                    }
+                    fn to_tres_properties(
+                        &self,
+                        #writer_ident: &mut ::as_gd_res::tres::TresWriter,
+                    ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                        vec![
+                            #(#to_tres_fields)*
+                        ]
+                    }
+                }
+
+                impl ::as_gd_res::tres::ToTres for #name #concrete_type_args {
+                    fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                        writer.add_sub_resource(self)
+                    }
+                    fn tres_type() -> String {
+                        #res_name_str.to_string()
+                    }
+                }
+                }
+            };
+            if !godot {
+                return tres_impls;
+            }
+
+            let to_gd_impls = if variant_fields {
+                quote! {}
+            } else {
+                quote! {
                 impl ::as_gd_res::AsGdRes for #name #concrete_type_args {
                     type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<#res_name>>;
                 }
+                impl ::as_gd_res::AsGdResClass for #name #concrete_type_args {
+                    type GdClass = #res_name;
+                }
                 impl ::as_gd_res::AsGdResOpt for #name #concrete_type_args {
                     type GdOption = Option<::godot::obj::Gd<#res_name>>;
                 }
@@ -237,6 +664,114 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
                     type GdArray = ::godot::prelude::Array<::godot::obj::Gd<#res_name>>;
                 }
 
+                impl ::as_gd_res::ToGdRes for #name #concrete_type_args {
+                    fn to_gd_res(&self) -> Self::ResType {
+                        ::as_gd_res::on_editor(#res_name::from_rust(self))
+                    }
+                    fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                        ::as_gd_res::apply_to_on_editor(self, res, #res_name::from_rust, |v, gd| {
+                            v.apply_to(gd);
+                            false
+                        })
+                    }
+                }
+                impl ::as_gd_res::ToGdResOpt for #name #concrete_type_args {
+                    fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                        value.map(#res_name::from_rust)
+                    }
+                    fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                        ::as_gd_res::apply_to_option(value, res, #res_name::from_rust, |v, gd| {
+                            v.apply_to(gd);
+                            false
+                        })
+                    }
+                }
+                impl ::as_gd_res::ToGdResArray for #name #concrete_type_args {
+                    fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                        values.into_iter().map(#res_name::from_rust).collect()
+                    }
+                    fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                        ::as_gd_res::apply_to_array(values, res, #res_name::from_rust, |v, gd| {
+                            v.apply_to(gd);
+                            false
+                        })
+                    }
+                }
+                impl ::as_gd_res::AsGdResDictElement for #name #concrete_type_args {
+                    type GdElement = ::godot::obj::Gd<#res_name>;
+                    fn to_gd_element(&self) -> Self::GdElement {
+                        #res_name::from_rust(self)
+                    }
+                    fn try_from_gd_element(
+                        element: &Self::GdElement,
+                        ctx: &mut ::as_gd_res::ExtractContext,
+                    ) -> Result<Self, ::as_gd_res::ExtractError> {
+                        ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+                    }
+                    fn try_arc_from_gd_element(
+                        element: &Self::GdElement,
+                        ctx: &mut ::as_gd_res::ExtractContext,
+                    ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                        ::as_gd_res::try_arc_from_object(element, ctx)
+                    }
+                    fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                        self.apply_to(res);
+                        false
+                    }
+                }
+                ::as_gd_res::impl_from_gd_res_by_element!(#name #concrete_type_args);
+
+                impl #name #concrete_type_args {
+                    /// Writes `self` onto an existing resource in place, keeping nested
+                    /// sub-resources where possible. `emit_changed()` is only called on
+                    /// the resources whose own properties changed.
+                    pub fn apply_to(&self, res: &mut ::godot::obj::Gd<#res_name>) {
+                        let changed = #changed_expr;
+                        if changed {
+                            res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                        }
+                    }
+                }
+                }
+            };
+            let from_rust_impl = if variant_fields {
+                quote! {
+                    impl #res_name {
+                        /// Builds a new resource from the fields of a variant, bound by reference.
+                        pub fn from_fields(fields: #fields_tuple) -> ::godot::obj::Gd<Self> {
+                            ::godot::obj::Gd::from_init_fn(|base| Self {
+                                base,
+                                #(#to_gd_fields)*
+                            })
+                        }
+
+                        /// Writes the fields of a variant, bound by reference, onto an existing
+                        /// resource in place, like `apply_to` on a struct.
+                        pub fn apply_fields(res: &mut ::godot::obj::Gd<Self>, fields: #fields_tuple) {
+                            let changed = #changed_expr;
+                            if changed {
+                                res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                            }
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    impl #res_name {
+                        /// Builds a new resource, including all sub-resources, from a Rust value.
+                        pub fn from_rust(value: &#name #concrete_type_args) -> ::godot::obj::Gd<Self> {
+                            ::godot::obj::Gd::from_init_fn(|base| Self {
+                                base,
+                                #(#to_gd_fields)*
+                            })
+                        }
+                    }
+                }
+            };
+
+            let mut expanded = quote! {
+                #to_gd_impls
+
                 #[derive(::godot::prelude::GodotClass)]
                 #class_attr
                 pub struct #res_name {
@@ -247,13 +782,36 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
 
                 impl ::as_gd_res::ExtractGd for #res_name {
                     type Extracted = #name #concrete_type_args;
+                    // One `ExtractContext` for the whole resource graph, so that cycles
+                    // are detected and `Arc`s shared here as well.
                     fn extract(&self) -> Self::Extracted {
-                        use ::as_gd_res::ExtractGd;
-                        Self::Extracted {
-                            #(#extracts)*
-                        }
+                        ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+                    }
+                }
+
+                impl ::as_gd_res::TryExtractGd for #res_name {
+                    fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                        self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+                    }
+                    fn try_extract_with(
+                        &self,
+                        ctx: &mut ::as_gd_res::ExtractContext,
+                    ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                        let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                            Ok(Self::Extracted {
+                                #(#try_extracts)*
+                            })
+                        })();
+                        extracted.map_err(|e| {
+                            let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                            e.in_resource(#name_str, &path)
+                        })
                     }
                 }
+
+                #from_rust_impl
+
+                #tres_impls
             };
 
             // If post_init, append IResource impl
@@ -275,15 +833,10 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
             expanded
         }
         Data::Enum(data) => {
-            // Existing enum logic unchanged
             let all_unit = data
                 .variants
                 .iter()
                 .all(|v| matches!(&v.fields, Fields::Unit));
-            let all_tuple1 = data
-                .variants
-                .iter()
-                .all(|v| matches!(&v.fields, Fields::Unnamed(u) if u.unnamed.len()==1));
 
             if all_unit {
                 quote! {
@@ -291,37 +844,267 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
                         "`derive(AsGdRes)` only supports enums with single-tuple variants, not unit variants. Did you mean to use `derive(AsGdEnumSimple)`?"
                     );
                 }
-            } else if all_tuple1 {
-                let dyn_trait = format_ident!("{}ResourceExtractVariant", name);
+            } else {
+                let dyn_trait = format_ident!("{}ResourceExtractVariant", base_name);
+                let name_str = name.to_string();
+                // The enum with its generics substituted, e.g. `Reward<i32>`
+                let enum_ty = quote! { #name #concrete_type_args };
 
                 let mut variant_impls = Vec::new();
+                let mut to_dyn_arms = Vec::new();
+                let mut apply_arms = Vec::new();
+                let mut tres_arms = Vec::new();
+                let mut tres_type_arms = Vec::new();
+                let mut tres_property_arms = Vec::new();
+                // Without Godot, only the fields struct and its `tres` impls of each
+                // struct-like or multi-field variant
+                let mut field_variant_mods = Vec::new();
+                // A single-tuple variant uses the resource of its payload type, unless another
+                // variant has a payload of the same type or it has `#[as_gd_res(wrap)]`.
+                // Then it gets a wrapper resource like a multi-field variant, so the inspector
+                // can tell the variants apart.
+                let payload_key = |fields: &syn::FieldsUnnamed| {
+                    let payload = payload_type(fields, generic_type_map.as_ref());
+                    quote! { #payload }.to_string()
+                };
+                let mut payload_counts = HashMap::new();
+                for var in &data.variants {
+                    if let Fields::Unnamed(fields) = &var.fields
+                        && fields.unnamed.len() == 1
+                    {
+                        *payload_counts.entry(payload_key(fields)).or_insert(0) += 1;
+                    }
+                }
                 for var in &data.variants {
-                    if let Fields::Unnamed(fields) = &var.fields {
-                        let var_ident = &var.ident;
-                        let ty = &fields.unnamed[0].ty;
-                        let variant_res = match ty {
-                            Type::Path(tp) => {
-                                let seg = tp.path.segments.last().unwrap().ident.clone();
-                                format_ident!("{}Resource", seg)
+                    let var_ident = &var.ident;
+                    let variant_mod_ident = format_ident!(
+                        "mod_{}_{}",
+                        base_name.to_string().to_lowercase(),
+                        var_ident.to_string().to_lowercase()
+                    );
+                    if let Fields::Unit = &var.fields {
+                        // An empty resource class stands for the unit variant
+                        let marker_res = format_ident!("{}{}Resource", base_name, var_ident);
+                        let marker_res_str = marker_res.to_string();
+
+                        to_dyn_arms.push(quote! {
+                            #name::#var_ident => ::godot::obj::Gd::into_dyn::<dyn #dyn_trait>(::godot::obj::Gd::<#marker_res>::default()).upcast(),
+                        });
+
+                        apply_arms.push(quote! {
+                            #name::#var_ident => {
+                                if (**res).clone().try_cast::<#marker_res>().is_ok() {
+                                    return false;
+                                }
+                            }
+                        });
+
+                        tres_arms.push(quote! {
+                            #marker_res_str => Ok(#name::#var_ident),
+                        });
+                        tres_type_arms.push(quote! {
+                            #name::#var_ident => #marker_res_str.to_string(),
+                        });
+                        tres_property_arms.push(quote! {
+                            #name::#var_ident => Vec::new(),
+                        });
+
+                        variant_impls.push(quote! {
+
+                            #[derive(::godot::prelude::GodotClass)]
+                            #[class(tool,init,base = Resource)]
+                            pub struct #marker_res {
+                                #[base]
+                                base: ::godot::obj::Base<::godot::classes::Resource>,
+                            }
+
+                            pub mod #variant_mod_ident {
+                                use super::*;
+                                use ::godot::prelude::godot_dyn;
+                                #[godot_dyn]
+                                impl #dyn_trait for #marker_res {
+                                    fn try_extract_enum_variant(
+                                        &self,
+                                        _ctx: &mut ::as_gd_res::ExtractContext,
+                                    ) -> Result<#enum_ty, ::as_gd_res::ExtractError> {
+                                        Ok(#name::#var_ident)
+                                    }
+                                }
+                            }
+
+                        });
+                    } else if let Fields::Unnamed(fields) = &var.fields
+                        && fields.unnamed.len() == 1
+                        && !has_as_gd_res_flag(&var.attrs, "wrap")
+                        && payload_counts[&payload_key(fields)] == 1
+                    {
+                        // The resource class comes from the payload type, so that generic
+                        // payloads and named instantiations resolve to the right class
+                        let payload = payload_type(fields, generic_type_map.as_ref());
+                        let variant_res = quote! { #variant_mod_ident::PayloadResource };
+
+                        to_dyn_arms.push(quote! {
+                            #name::#var_ident(v) => ::godot::obj::Gd::into_dyn::<dyn #dyn_trait>(#variant_res::from_rust(v)).upcast(),
+                        });
+
+                        apply_arms.push(quote! {
+                            #name::#var_ident(v) => {
+                                if let Ok(mut gd) = (**res).clone().try_cast::<#variant_res>() {
+                                    v.apply_to(&mut gd);
+                                    return false;
+                                }
+                            }
+                        });
+
+                        tres_type_arms.push(quote! {
+                            #name::#var_ident(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                        });
+                        tres_property_arms.push(quote! {
+                            #name::#var_ident(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                        });
+                        tres_arms.push(quote! {
+                            name if name == <#payload as ::as_gd_res::tres::ToTres>::tres_type() => Ok(#name::#var_ident(::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?)),
+                        });
+
+                        variant_impls.push(quote! {
+
+                            pub mod #variant_mod_ident {
+                                use super::*;
+                                use ::godot::prelude::godot_dyn;
+
+                                pub type PayloadResource = <#payload as ::as_gd_res::AsGdResClass>::GdClass;
+
+                                #[godot_dyn]
+                                impl #dyn_trait for PayloadResource {
+                                    fn try_extract_enum_variant(
+                                        &self,
+                                        ctx: &mut ::as_gd_res::ExtractContext,
+                                    ) -> Result<#enum_ty, ::as_gd_res::ExtractError> {
+                                        Ok(#name::#var_ident(::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?))
+                                    }
+                                }
                             }
-                            _ => format_ident!("{}Resource", var_ident),
-                        };
 
-                        let variant_mod_ident = format_ident!(
-                            "mod_{}_{}",
-                            name.to_string().to_lowercase(),
-                            var_ident.to_string().to_lowercase()
+                        });
+                    } else {
+                        // Struct-like, multi-field and wrapped variants get their own resource
+                        // class, generated from a struct with the same fields. Tuple fields are
+                        // named `field_0`, `field_1`, ...
+                        let variant_name = format_ident!("{}{}", base_name, var_ident);
+                        let variant_name_str = variant_name.to_string();
+                        let fields_name = format_ident!("{}Fields", variant_name);
+                        let variant_res = format_ident!("{}Resource", variant_name);
+                        let variant_res_str = variant_res.to_string();
+                        let field_idents = var
+                            .fields
+                            .iter()
+                            .enumerate()
+                            .map(|(i, f)| {
+                                f.ident
+                                    .clone()
+                                    .unwrap_or_else(|| format_ident!("field_{}", i))
+                            })
+                            .collect::<Vec<_>>();
+                        let field_tys = var
+                            .fields
+                            .iter()
+                            .map(|f| match &generic_type_map {
+                                Some(type_map) => substitute_type(&f.ty, type_map),
+                                None => f.ty.clone(),
+                            })
+                            .collect::<Vec<_>>();
+                        let field_defs = var.fields.iter().zip(&field_idents).zip(&field_tys).map(
+                            |((f, ident), ty)| {
+                                let attrs = &f.attrs;
+                                quote! { #(#attrs)* pub #ident: #ty }
+                            },
                         );
+                        let fields_input: DeriveInput = parse_quote! {
+                            #[as_gd_res_types(name = #variant_name_str)]
+                            pub struct #fields_name {
+                                #(#field_defs,)*
+                            }
+                        };
+                        let fields_expansion = expand_item(fields_input, godot, true);
+                        let fields_items = quote! {
+                            pub struct #fields_name {
+                                #( pub #field_idents: #field_tys, )*
+                            }
+
+                            #fields_expansion
+                        };
+
+                        // Binds every field of the variant by reference, and builds the
+                        // variant from the fields struct `v`
+                        let (pattern, any_pattern, from_fields) = if let Fields::Named(_) =
+                            &var.fields
+                        {
+                            (
+                                quote! { #name::#var_ident { #(#field_idents),* } },
+                                quote! { #name::#var_ident { .. } },
+                                quote! { #name::#var_ident { #( #field_idents: v.#field_idents ),* } },
+                            )
+                        } else {
+                            (
+                                quote! { #name::#var_ident( #(#field_idents),* ) },
+                                quote! { #name::#var_ident(..) },
+                                quote! { #name::#var_ident( #( v.#field_idents ),* ) },
+                            )
+                        };
+                        // The bound fields, as taken by `from_fields`, `apply_fields` and
+                        // `fields_to_tres`
+                        let bound_fields = tuple_tokens(&field_idents);
+
+                        to_dyn_arms.push(quote! {
+                            #pattern => ::godot::obj::Gd::into_dyn::<dyn #dyn_trait>(#variant_mod_ident::#variant_res::from_fields(#bound_fields)).upcast(),
+                        });
+
+                        apply_arms.push(quote! {
+                            #pattern => {
+                                if let Ok(mut gd) = (**res).clone().try_cast::<#variant_mod_ident::#variant_res>() {
+                                    #variant_mod_ident::#variant_res::apply_fields(&mut gd, #bound_fields);
+                                    return false;
+                                }
+                            }
+                        });
+
+                        tres_type_arms.push(quote! {
+                            #any_pattern => #variant_res_str.to_string(),
+                        });
+                        tres_property_arms.push(quote! {
+                            #pattern => #variant_mod_ident::#fields_name::fields_to_tres(#bound_fields, writer),
+                        });
+                        tres_arms.push(quote! {
+                            #variant_res_str => {
+                                let v: #variant_mod_ident::#fields_name = ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?;
+                                Ok(#from_fields)
+                            }
+                        });
+
+                        field_variant_mods.push(quote! {
+                            pub mod #variant_mod_ident {
+                                use super::*;
+
+                                #fields_items
+                            }
+                        });
 
                         variant_impls.push(quote! {
 
                             pub mod #variant_mod_ident {
                                 use super::*;
                                 use ::godot::prelude::godot_dyn;
+
+                                #fields_items
+
                                 #[godot_dyn]
                                 impl #dyn_trait for #variant_res {
-                                    fn extract_enum_variant(&self) -> #name {
-                                        #name::#var_ident(self.extract())
+                                    fn try_extract_enum_variant(
+                                        &self,
+                                        ctx: &mut ::as_gd_res::ExtractContext,
+                                    ) -> Result<#enum_ty, ::as_gd_res::ExtractError> {
+                                        let v = ::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?;
+                                        Ok(#from_fields)
                                     }
                                 }
                             }
@@ -330,48 +1113,175 @@ fn expand_as_gd_res(mut input: DeriveInput) -> proc_macro2::TokenStream {
                     }
                 }
 
+                let tres_impls = quote! {
+                    impl ::as_gd_res::tres::FromTresResource for #enum_ty {
+                        fn from_tres_resource(
+                            res: &::as_gd_res::tres::TresResource,
+                            doc: &::as_gd_res::tres::TresDocument,
+                        ) -> Result<Self, ::as_gd_res::ExtractError> {
+                            match res.type_name() {
+                                #(#tres_arms)*
+                                other => Err(::as_gd_res::ExtractError::invalid(format!(
+                                    "`{}` is not a resource for any variant of `{}`",
+                                    other, #name_str
+                                ))
+                                .in_resource(#name_str, &doc.resource_path(res))),
+                            }
+                        }
+                    }
+
+                    impl ::as_gd_res::tres::FromTres for #enum_ty {
+                        fn from_tres(
+                            value: &::as_gd_res::tres::TresValue,
+                            doc: &::as_gd_res::tres::TresDocument,
+                        ) -> Result<Self, ::as_gd_res::ExtractError> {
+                            doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+                        }
+                    }
+
+                    impl ::as_gd_res::tres::ToTresResource for #enum_ty {
+                        fn tres_type_name(&self) -> String {
+                            match self {
+                                #(#tres_type_arms)*
+                            }
+                        }
+                        fn to_tres_properties(
+                            &self,
+                            writer: &mut ::as_gd_res::tres::TresWriter,
+                        ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                            match self {
+                                #(#tres_property_arms)*
+                            }
+                        }
+                    }
+
+                    impl ::as_gd_res::tres::ToTres for #enum_ty {
+                        fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                            writer.add_sub_resource(self)
+                        }
+                        fn tres_type() -> String {
+                            "Resource".to_string()
+                        }
+                    }
+                };
+                if !godot {
+                    return quote! {
+                        #tres_impls
+
+                        #(#field_variant_mods)*
+                    };
+                }
+
                 quote! {
                     pub trait #dyn_trait {
-                        fn extract_enum_variant(&self) -> #name;
+                        fn try_extract_enum_variant(
+                            &self,
+                            ctx: &mut ::as_gd_res::ExtractContext,
+                        ) -> Result<#enum_ty, ::as_gd_res::ExtractError>;
                     }
 
                     type #res_name = ::godot::obj::DynGd<::godot::classes::Resource, dyn #dyn_trait>;
 
-                    impl ::as_gd_res::AsGdRes for #name {
+                    impl ::as_gd_res::AsGdRes for #enum_ty {
                         type ResType = ::godot::prelude::OnEditor<#res_name>;
                     }
-                    impl ::as_gd_res::AsGdResOpt for #name {
+                    impl ::as_gd_res::AsGdResOpt for #enum_ty {
                         type GdOption = Option<#res_name>;
                     }
-                    impl ::as_gd_res::AsGdResArray for #name {
+                    impl ::as_gd_res::AsGdResArray for #enum_ty {
                         type GdArray = ::godot::prelude::Array<#res_name>;
                     }
 
+                    impl ::as_gd_res::ToGdRes for #enum_ty {
+                        fn to_gd_res(&self) -> Self::ResType {
+                            ::as_gd_res::on_editor(<dyn #dyn_trait>::from_rust(self))
+                        }
+                        fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                            ::as_gd_res::apply_to_on_editor(self, res, <dyn #dyn_trait>::from_rust, <dyn #dyn_trait>::apply_rust)
+                        }
+                    }
+                    impl ::as_gd_res::ToGdResOpt for #enum_ty {
+                        fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                            value.map(<dyn #dyn_trait>::from_rust)
+                        }
+                        fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                            ::as_gd_res::apply_to_option(value, res, <dyn #dyn_trait>::from_rust, <dyn #dyn_trait>::apply_rust)
+                        }
+                    }
+                    impl ::as_gd_res::ToGdResArray for #enum_ty {
+                        fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                            values.into_iter().map(<dyn #dyn_trait>::from_rust).collect()
+                        }
+                        fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                            ::as_gd_res::apply_to_array(values, res, <dyn #dyn_trait>::from_rust, <dyn #dyn_trait>::apply_rust)
+                        }
+                    }
+                    impl ::as_gd_res::AsGdResDictElement for #enum_ty {
+                        type GdElement = #res_name;
+                        fn to_gd_element(&self) -> Self::GdElement {
+                            <dyn #dyn_trait>::from_rust(self)
+                        }
+                        fn try_from_gd_element(
+                            element: &Self::GdElement,
+                            ctx: &mut ::as_gd_res::ExtractContext,
+                        ) -> Result<Self, ::as_gd_res::ExtractError> {
+                            ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+                        }
+                        fn try_arc_from_gd_element(
+                            element: &Self::GdElement,
+                            ctx: &mut ::as_gd_res::ExtractContext,
+                        ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                            ::as_gd_res::try_arc_from_object(element, ctx)
+                        }
+                        fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                            <dyn #dyn_trait>::apply_rust(self, res)
+                        }
+                    }
+                    ::as_gd_res::impl_from_gd_res_by_element!(#enum_ty);
+
+                    impl dyn #dyn_trait {
+                        /// Builds the resource for the variant held by `value`.
+                        pub fn from_rust(value: &#enum_ty) -> #res_name {
+                            match value {
+                                #(#to_dyn_arms)*
+                            }
+                        }
+
+                        /// Writes `value` onto `res` in place if it already holds a resource for
+                        /// the same variant, and replaces it otherwise. Returns `true` if `res`
+                        /// was replaced.
+                        pub fn apply_rust(value: &#enum_ty, res: &mut #res_name) -> bool {
+                            match value {
+                                #(#apply_arms)*
+                            }
+                            *res = Self::from_rust(value);
+                            true
+                        }
+                    }
+
                     impl ::as_gd_res::ExtractGd for dyn #dyn_trait {
-                        type Extracted = #name;
+                        type Extracted = #enum_ty;
                         fn extract(&self) -> Self::Extracted {
-                            self.extract_enum_variant()
+                            ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+                        }
+                    }
+
+                    impl ::as_gd_res::TryExtractGd for dyn #dyn_trait {
+                        fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                            self.try_extract_enum_variant(&mut ::as_gd_res::ExtractContext::new())
+                        }
+                        fn try_extract_with(
+                            &self,
+                            ctx: &mut ::as_gd_res::ExtractContext,
+                        ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                            self.try_extract_enum_variant(ctx)
                         }
                     }
 
+                    #tres_impls
+
                     #(#variant_impls)*
                 }
-            } else {
-                let invalid = data
-                    .variants
-                    .iter()
-                    .filter_map(|v| match &v.fields {
-                        Fields::Unnamed(u) if u.unnamed.len() == 1 => None,
-                        Fields::Unit => None,
-                        _ => Some(v.ident.to_string()),
-                    })
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                let msg = format!(
-                    "`derive(AsGdRes)` only supports unit enums or single-tuple enums. Unsupported variants: {}",
-                    invalid
-                );
-                quote! { compile_error!(#msg); }
             }
         }
         _ => quote! {
@@ -399,4 +1309,5 @@ mod tests {
     mod struct_nested_generics;
     mod struct_post_init;
     mod struct_with_generics;
+    mod without_godot;
 }
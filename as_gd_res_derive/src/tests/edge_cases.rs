@@ -8,11 +8,14 @@ fn test_empty_struct() {
     let input: syn::DeriveInput = parse_quote! {
         pub struct EmptyStruct {}
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for EmptyStruct {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<EmptyStructResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for EmptyStruct {
+            type GdClass = EmptyStructResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for EmptyStruct {
             type GdOption = Option<::godot::obj::Gd<EmptyStructResource>>;
@@ -22,6 +25,76 @@ fn test_empty_struct() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<EmptyStructResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for EmptyStruct {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(EmptyStructResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, EmptyStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for EmptyStruct {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(EmptyStructResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, EmptyStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for EmptyStruct {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(EmptyStructResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, EmptyStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for EmptyStruct {
+            type GdElement = ::godot::obj::Gd<EmptyStructResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                EmptyStructResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(EmptyStruct);
+
+        impl EmptyStruct {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<EmptyStructResource>) {
+                let changed = false;
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct EmptyStructResource {
@@ -32,9 +105,84 @@ fn test_empty_struct() {
         impl ::as_gd_res::ExtractGd for EmptyStructResource {
             type Extracted = EmptyStruct;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for EmptyStructResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("EmptyStruct", &path)
+                })
+            }
+        }
+
+        impl EmptyStructResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &EmptyStruct) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for EmptyStruct {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("EmptyStructResource")?;
+                    Ok(Self {
+
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("EmptyStruct", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for EmptyStruct {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for EmptyStruct {
+            fn tres_type_name(&self) -> String {
+                "EmptyStructResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                _writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for EmptyStruct {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "EmptyStructResource".to_string()
             }
         }
     };
@@ -47,7 +195,7 @@ fn test_empty_enum() {
     let input: syn::DeriveInput = parse_quote! {
         pub enum EmptyEnum {}
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     // Empty enum has no variants, so all_unit = true (vacuously)
     // This should produce the error for unit enums
     let expected = quote! {
@@ -67,11 +215,14 @@ fn test_struct_var_only_attribute() {
             pub field: i32,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for VarOnlyStruct {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<VarOnlyStructResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for VarOnlyStruct {
+            type GdClass = VarOnlyStructResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for VarOnlyStruct {
             type GdOption = Option<::godot::obj::Gd<VarOnlyStructResource>>;
@@ -81,6 +232,80 @@ fn test_struct_var_only_attribute() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<VarOnlyStructResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for VarOnlyStruct {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(VarOnlyStructResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, VarOnlyStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for VarOnlyStruct {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(VarOnlyStructResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, VarOnlyStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for VarOnlyStruct {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(VarOnlyStructResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, VarOnlyStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for VarOnlyStruct {
+            type GdElement = ::godot::obj::Gd<VarOnlyStructResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                VarOnlyStructResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(VarOnlyStruct);
+
+        impl VarOnlyStruct {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<VarOnlyStructResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field, &mut bound.field)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct VarOnlyStructResource {
@@ -93,10 +318,84 @@ fn test_struct_var_only_attribute() {
         impl ::as_gd_res::ExtractGd for VarOnlyStructResource {
             type Extracted = VarOnlyStruct;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field: self.field.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for VarOnlyStructResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field, ctx).map_err(|e| e.in_field("field"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("VarOnlyStruct", &path)
+                })
+            }
+        }
+
+        impl VarOnlyStructResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &VarOnlyStruct) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field: ::as_gd_res::ToGdRes::to_gd_res(&value.field),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for VarOnlyStruct {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("VarOnlyStructResource")?;
+                    Ok(Self {
+                        field: ::as_gd_res::tres::field(res, doc, "field")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("VarOnlyStruct", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for VarOnlyStruct {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for VarOnlyStruct {
+            fn tres_type_name(&self) -> String {
+                "VarOnlyStructResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for VarOnlyStruct {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "VarOnlyStructResource".to_string()
             }
         }
     };
@@ -113,11 +412,14 @@ fn test_struct_init_only_attribute() {
             pub field: i32,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for InitOnlyStruct {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<InitOnlyStructResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for InitOnlyStruct {
+            type GdClass = InitOnlyStructResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for InitOnlyStruct {
             type GdOption = Option<::godot::obj::Gd<InitOnlyStructResource>>;
@@ -127,6 +429,80 @@ fn test_struct_init_only_attribute() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<InitOnlyStructResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for InitOnlyStruct {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(InitOnlyStructResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, InitOnlyStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for InitOnlyStruct {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(InitOnlyStructResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, InitOnlyStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for InitOnlyStruct {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(InitOnlyStructResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, InitOnlyStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for InitOnlyStruct {
+            type GdElement = ::godot::obj::Gd<InitOnlyStructResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                InitOnlyStructResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(InitOnlyStruct);
+
+        impl InitOnlyStruct {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<InitOnlyStructResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field, &mut bound.field)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct InitOnlyStructResource {
@@ -139,10 +515,84 @@ fn test_struct_init_only_attribute() {
         impl ::as_gd_res::ExtractGd for InitOnlyStructResource {
             type Extracted = InitOnlyStruct;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field: self.field.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for InitOnlyStructResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field, ctx).map_err(|e| e.in_field("field"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("InitOnlyStruct", &path)
+                })
+            }
+        }
+
+        impl InitOnlyStructResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &InitOnlyStruct) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field: ::as_gd_res::ToGdRes::to_gd_res(&value.field),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for InitOnlyStruct {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("InitOnlyStructResource")?;
+                    Ok(Self {
+                        field: ::as_gd_res::tres::field_or_else(res, doc, "field", || 42.into())?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("InitOnlyStruct", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for InitOnlyStruct {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for InitOnlyStruct {
+            fn tres_type_name(&self) -> String {
+                "InitOnlyStructResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for InitOnlyStruct {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "InitOnlyStructResource".to_string()
             }
         }
     };
@@ -157,11 +607,14 @@ fn test_private_struct() {
             pub field: i32,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for PrivateStruct {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<PrivateStructResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for PrivateStruct {
+            type GdClass = PrivateStructResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for PrivateStruct {
             type GdOption = Option<::godot::obj::Gd<PrivateStructResource>>;
@@ -171,6 +624,80 @@ fn test_private_struct() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<PrivateStructResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for PrivateStruct {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(PrivateStructResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, PrivateStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for PrivateStruct {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(PrivateStructResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, PrivateStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for PrivateStruct {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(PrivateStructResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, PrivateStructResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for PrivateStruct {
+            type GdElement = ::godot::obj::Gd<PrivateStructResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                PrivateStructResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(PrivateStruct);
+
+        impl PrivateStruct {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<PrivateStructResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field, &mut bound.field)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct PrivateStructResource {
@@ -183,10 +710,84 @@ fn test_private_struct() {
         impl ::as_gd_res::ExtractGd for PrivateStructResource {
             type Extracted = PrivateStruct;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field: self.field.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for PrivateStructResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field, ctx).map_err(|e| e.in_field("field"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("PrivateStruct", &path)
+                })
+            }
+        }
+
+        impl PrivateStructResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &PrivateStruct) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field: ::as_gd_res::ToGdRes::to_gd_res(&value.field),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for PrivateStruct {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("PrivateStructResource")?;
+                    Ok(Self {
+                        field: ::as_gd_res::tres::field(res, doc, "field")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("PrivateStruct", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for PrivateStruct {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for PrivateStruct {
+            fn tres_type_name(&self) -> String {
+                "PrivateStructResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for PrivateStruct {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "PrivateStructResource".to_string()
             }
         }
     };
@@ -201,10 +802,13 @@ fn test_single_variant_enum() {
             Only(SomeData),
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         pub trait SingleVariantResourceExtractVariant {
-            fn extract_enum_variant(&self) -> SingleVariant;
+            fn try_extract_enum_variant(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<SingleVariant, ::as_gd_res::ExtractError>;
         }
 
         type SingleVariantResource =
@@ -220,20 +824,160 @@ fn test_single_variant_enum() {
             type GdArray = ::godot::prelude::Array<SingleVariantResource>;
         }
 
+        impl ::as_gd_res::ToGdRes for SingleVariant {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(<dyn SingleVariantResourceExtractVariant>::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, <dyn SingleVariantResourceExtractVariant>::from_rust, <dyn SingleVariantResourceExtractVariant>::apply_rust)
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for SingleVariant {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(<dyn SingleVariantResourceExtractVariant>::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, <dyn SingleVariantResourceExtractVariant>::from_rust, <dyn SingleVariantResourceExtractVariant>::apply_rust)
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for SingleVariant {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(<dyn SingleVariantResourceExtractVariant>::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, <dyn SingleVariantResourceExtractVariant>::from_rust, <dyn SingleVariantResourceExtractVariant>::apply_rust)
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for SingleVariant {
+            type GdElement = SingleVariantResource;
+            fn to_gd_element(&self) -> Self::GdElement {
+                <dyn SingleVariantResourceExtractVariant>::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                <dyn SingleVariantResourceExtractVariant>::apply_rust(self, res)
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(SingleVariant);
+
+        impl dyn SingleVariantResourceExtractVariant {
+            /// Builds the resource for the variant held by `value`.
+            pub fn from_rust(value: &SingleVariant) -> SingleVariantResource {
+                match value {
+                    SingleVariant::Only(v) => ::godot::obj::Gd::into_dyn::<dyn SingleVariantResourceExtractVariant>(mod_singlevariant_only::PayloadResource::from_rust(v)).upcast(),
+                }
+            }
+
+            /// Writes `value` onto `res` in place if it already holds a resource for
+            /// the same variant, and replaces it otherwise. Returns `true` if `res`
+            /// was replaced.
+            pub fn apply_rust(value: &SingleVariant, res: &mut SingleVariantResource) -> bool {
+                match value {
+                    SingleVariant::Only(v) => {
+                        if let Ok(mut gd) = (**res).clone().try_cast::<mod_singlevariant_only::PayloadResource>() {
+                            v.apply_to(&mut gd);
+                            return false;
+                        }
+                    }
+                }
+                *res = Self::from_rust(value);
+                true
+            }
+        }
+
         impl ::as_gd_res::ExtractGd for dyn SingleVariantResourceExtractVariant {
             type Extracted = SingleVariant;
             fn extract(&self) -> Self::Extracted {
-                self.extract_enum_variant()
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for dyn SingleVariantResourceExtractVariant {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(ctx)
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for SingleVariant {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match res.type_name() {
+                    name if name == <SomeData as ::as_gd_res::tres::ToTres>::tres_type() => Ok(SingleVariant::Only(::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?)),
+                    other => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a resource for any variant of `{}`",
+                        other, "SingleVariant"
+                    ))
+                    .in_resource("SingleVariant", &doc.resource_path(res))),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for SingleVariant {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for SingleVariant {
+            fn tres_type_name(&self) -> String {
+                match self {
+                    SingleVariant::Only(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                }
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                match self {
+                    SingleVariant::Only(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for SingleVariant {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "Resource".to_string()
             }
         }
 
         pub mod mod_singlevariant_only {
             use super::*;
             use ::godot::prelude::godot_dyn;
+            pub type PayloadResource = <SomeData as ::as_gd_res::AsGdResClass>::GdClass;
+
             #[godot_dyn]
-            impl SingleVariantResourceExtractVariant for SomeDataResource {
-                fn extract_enum_variant(&self) -> SingleVariant {
-                    SingleVariant::Only(self.extract())
+            impl SingleVariantResourceExtractVariant for PayloadResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<SingleVariant, ::as_gd_res::ExtractError> {
+                    Ok(SingleVariant::Only(::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?))
                 }
             }
         }
@@ -248,11 +992,14 @@ fn test_post_init_empty_struct() {
         #[as_gd_res(post_init = init_method)]
         pub struct EmptyPostInit {}
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for EmptyPostInit {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<EmptyPostInitResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for EmptyPostInit {
+            type GdClass = EmptyPostInitResource;
+        }
         impl ::as_gd_res::AsGdResOpt for EmptyPostInit {
             type GdOption = Option<::godot::obj::Gd<EmptyPostInitResource>>;
         }
@@ -260,6 +1007,76 @@ fn test_post_init_empty_struct() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<EmptyPostInitResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for EmptyPostInit {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(EmptyPostInitResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, EmptyPostInitResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for EmptyPostInit {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(EmptyPostInitResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, EmptyPostInitResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for EmptyPostInit {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(EmptyPostInitResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, EmptyPostInitResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for EmptyPostInit {
+            type GdElement = ::godot::obj::Gd<EmptyPostInitResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                EmptyPostInitResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(EmptyPostInit);
+
+        impl EmptyPostInit {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<EmptyPostInitResource>) {
+                let changed = false;
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,base = Resource)]
         pub struct EmptyPostInitResource {
@@ -270,9 +1087,84 @@ fn test_post_init_empty_struct() {
         impl ::as_gd_res::ExtractGd for EmptyPostInitResource {
             type Extracted = EmptyPostInit;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for EmptyPostInitResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("EmptyPostInit", &path)
+                })
+            }
+        }
+
+        impl EmptyPostInitResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &EmptyPostInit) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for EmptyPostInit {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("EmptyPostInitResource")?;
+                    Ok(Self {
+
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("EmptyPostInit", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for EmptyPostInit {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for EmptyPostInit {
+            fn tres_type_name(&self) -> String {
+                "EmptyPostInitResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                _writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for EmptyPostInit {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "EmptyPostInitResource".to_string()
             }
         }
 
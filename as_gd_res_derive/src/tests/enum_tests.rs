@@ -22,7 +22,10 @@ fn test_simple_enum() {
                 );
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 // For enums with data variants, we do the following:
@@ -43,7 +46,10 @@ fn test_enum_with_data_variants() {
 
     let expected = quote! {
         pub trait PickupResourceExtractVariant {
-            fn extract_enum_variant(&self) -> Pickup;
+            fn try_extract_enum_variant(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Pickup, ::as_gd_res::ExtractError>;
         }
 
         type PickupResource =
@@ -59,20 +65,180 @@ fn test_enum_with_data_variants() {
             type GdArray = ::godot::prelude::Array<PickupResource>;
         }
 
+        impl ::as_gd_res::ToGdRes for Pickup {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(<dyn PickupResourceExtractVariant>::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, <dyn PickupResourceExtractVariant>::from_rust, <dyn PickupResourceExtractVariant>::apply_rust)
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for Pickup {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(<dyn PickupResourceExtractVariant>::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, <dyn PickupResourceExtractVariant>::from_rust, <dyn PickupResourceExtractVariant>::apply_rust)
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for Pickup {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(<dyn PickupResourceExtractVariant>::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, <dyn PickupResourceExtractVariant>::from_rust, <dyn PickupResourceExtractVariant>::apply_rust)
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Pickup {
+            type GdElement = PickupResource;
+            fn to_gd_element(&self) -> Self::GdElement {
+                <dyn PickupResourceExtractVariant>::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                <dyn PickupResourceExtractVariant>::apply_rust(self, res)
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(Pickup);
+
+        impl dyn PickupResourceExtractVariant {
+            /// Builds the resource for the variant held by `value`.
+            pub fn from_rust(value: &Pickup) -> PickupResource {
+                match value {
+                    Pickup::Money(v) => ::godot::obj::Gd::into_dyn::<dyn PickupResourceExtractVariant>(mod_pickup_money::PayloadResource::from_rust(v)).upcast(),
+                    Pickup::PowerUp(v) => ::godot::obj::Gd::into_dyn::<dyn PickupResourceExtractVariant>(mod_pickup_powerup::PayloadResource::from_rust(v)).upcast(),
+                    Pickup::Heal(v) => ::godot::obj::Gd::into_dyn::<dyn PickupResourceExtractVariant>(mod_pickup_heal::PayloadResource::from_rust(v)).upcast(),
+                }
+            }
+
+            /// Writes `value` onto `res` in place if it already holds a resource for
+            /// the same variant, and replaces it otherwise. Returns `true` if `res`
+            /// was replaced.
+            pub fn apply_rust(value: &Pickup, res: &mut PickupResource) -> bool {
+                match value {
+                    Pickup::Money(v) => {
+                        if let Ok(mut gd) = (**res).clone().try_cast::<mod_pickup_money::PayloadResource>() {
+                            v.apply_to(&mut gd);
+                            return false;
+                        }
+                    }
+                    Pickup::PowerUp(v) => {
+                        if let Ok(mut gd) = (**res).clone().try_cast::<mod_pickup_powerup::PayloadResource>() {
+                            v.apply_to(&mut gd);
+                            return false;
+                        }
+                    }
+                    Pickup::Heal(v) => {
+                        if let Ok(mut gd) = (**res).clone().try_cast::<mod_pickup_heal::PayloadResource>() {
+                            v.apply_to(&mut gd);
+                            return false;
+                        }
+                    }
+                }
+                *res = Self::from_rust(value);
+                true
+            }
+        }
+
         impl ::as_gd_res::ExtractGd for dyn PickupResourceExtractVariant {
             type Extracted = Pickup;
             fn extract(&self) -> Self::Extracted {
-                self.extract_enum_variant()
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for dyn PickupResourceExtractVariant {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(ctx)
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Pickup {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match res.type_name() {
+                    name if name == <MoneyData as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Pickup::Money(::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?)),
+                    name if name == <PowerUpData as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Pickup::PowerUp(::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?)),
+                    name if name == <HealData as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Pickup::Heal(::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?)),
+                    other => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a resource for any variant of `{}`",
+                        other, "Pickup"
+                    ))
+                    .in_resource("Pickup", &doc.resource_path(res))),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Pickup {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Pickup {
+            fn tres_type_name(&self) -> String {
+                match self {
+                    Pickup::Money(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                    Pickup::PowerUp(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                    Pickup::Heal(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                }
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                match self {
+                    Pickup::Money(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                    Pickup::PowerUp(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                    Pickup::Heal(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Pickup {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "Resource".to_string()
             }
         }
 
         pub mod mod_pickup_money{
             use super::*;
             use ::godot::prelude::godot_dyn;
+            pub type PayloadResource = <MoneyData as ::as_gd_res::AsGdResClass>::GdClass;
+
             #[godot_dyn]
-            impl PickupResourceExtractVariant for MoneyDataResource {
-                fn extract_enum_variant(&self) -> Pickup {
-                    Pickup::Money(self.extract())
+            impl PickupResourceExtractVariant for PayloadResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Pickup, ::as_gd_res::ExtractError> {
+                    Ok(Pickup::Money(::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?))
                 }
             }
         }
@@ -81,10 +247,15 @@ fn test_enum_with_data_variants() {
             use super::*;
             use ::godot::prelude::godot_dyn;
 
+            pub type PayloadResource = <PowerUpData as ::as_gd_res::AsGdResClass>::GdClass;
+
             #[godot_dyn]
-            impl PickupResourceExtractVariant for PowerUpDataResource {
-                fn extract_enum_variant(&self) -> Pickup {
-                    Pickup::PowerUp(self.extract())
+            impl PickupResourceExtractVariant for PayloadResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Pickup, ::as_gd_res::ExtractError> {
+                    Ok(Pickup::PowerUp(::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?))
                 }
             }
         }
@@ -92,29 +263,1233 @@ fn test_enum_with_data_variants() {
         pub mod mod_pickup_heal{
             use super::*;
             use ::godot::prelude::godot_dyn;
+            pub type PayloadResource = <HealData as ::as_gd_res::AsGdResClass>::GdClass;
+
+            #[godot_dyn]
+            impl PickupResourceExtractVariant for PayloadResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Pickup, ::as_gd_res::ExtractError> {
+                    Ok(Pickup::Heal(::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?))
+                }
+            }
+        }
+    };
+
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+// Unit variants get an empty `{EnumName}{Variant}Resource` marker class, which implements the
+// `{EnumName}ResourceExtractVariant` trait like the resources of the data variants.
+#[test]
+fn test_enum_with_unit_variants() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Loot {
+            Nothing,
+            Money(MoneyData),
+        }
+    };
+
+    let expected = quote! {
+        pub trait LootResourceExtractVariant {
+            fn try_extract_enum_variant(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Loot, ::as_gd_res::ExtractError>;
+        }
+
+        type LootResource = ::godot::obj::DynGd<::godot::classes::Resource, dyn LootResourceExtractVariant>;
+        impl ::as_gd_res::AsGdRes for Loot {
+            type ResType = ::godot::prelude::OnEditor<LootResource>;
+        }
+
+        impl ::as_gd_res::AsGdResOpt for Loot {
+            type GdOption = Option<LootResource>;
+        }
+
+        impl ::as_gd_res::AsGdResArray for Loot {
+            type GdArray = ::godot::prelude::Array<LootResource>;
+        }
+
+        impl ::as_gd_res::ToGdRes for Loot {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(<dyn LootResourceExtractVariant>::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(
+                    self,
+                    res,
+                    <dyn LootResourceExtractVariant>::from_rust,
+                    <dyn LootResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResOpt for Loot {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(<dyn LootResourceExtractVariant>::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(
+                    value,
+                    res,
+                    <dyn LootResourceExtractVariant>::from_rust,
+                    <dyn LootResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResArray for Loot {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values
+                    .into_iter()
+                    .map(<dyn LootResourceExtractVariant>::from_rust)
+                    .collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(
+                    values,
+                    res,
+                    <dyn LootResourceExtractVariant>::from_rust,
+                    <dyn LootResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Loot {
+            type GdElement = LootResource;
+            fn to_gd_element(&self) -> Self::GdElement {
+                <dyn LootResourceExtractVariant>::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                <dyn LootResourceExtractVariant>::apply_rust(self, res)
+            }
+        }
+
+        ::as_gd_res::impl_from_gd_res_by_element!(Loot);
+        impl dyn LootResourceExtractVariant {
+            /// Builds the resource for the variant held by `value`.
+            pub fn from_rust(value: &Loot) -> LootResource {
+                match value {
+                    Loot::Nothing => ::godot::obj::Gd::into_dyn::<dyn LootResourceExtractVariant>(
+                        ::godot::obj::Gd::<LootNothingResource>::default()
+                    )
+                    .upcast(),
+                    Loot::Money(v) => ::godot::obj::Gd::into_dyn::<dyn LootResourceExtractVariant>(
+                        mod_loot_money::PayloadResource::from_rust(v)
+                    )
+                    .upcast(),
+                }
+            }
+            /// Writes `value` onto `res` in place if it already holds a resource for
+            /// the same variant, and replaces it otherwise. Returns `true` if `res`
+            /// was replaced.
+            pub fn apply_rust(value: &Loot, res: &mut LootResource) -> bool {
+                match value {
+                    Loot::Nothing => {
+                        if (**res).clone().try_cast::<LootNothingResource>().is_ok() {
+                            return false;
+                        }
+                    }
+                    Loot::Money(v) => {
+                        if let Ok(mut gd) = (**res).clone().try_cast::<mod_loot_money::PayloadResource>() {
+                            v.apply_to(&mut gd);
+                            return false;
+                        }
+                    }
+                }
+                *res = Self::from_rust(value);
+                true
+            }
+        }
+
+        impl ::as_gd_res::ExtractGd for dyn LootResourceExtractVariant {
+            type Extracted = Loot;
+            fn extract(&self) -> Self::Extracted {
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for dyn LootResourceExtractVariant {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(ctx)
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Loot {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match res.type_name() {
+                    "LootNothingResource" => Ok(Loot::Nothing),
+                    name if name == <MoneyData as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Loot::Money(
+                        ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?
+                    )),
+                    other => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a resource for any variant of `{}`",
+                        other, "Loot"
+                    ))
+                    .in_resource("Loot", &doc.resource_path(res))),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Loot {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(
+                    value,
+                    <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+                )
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Loot {
+            fn tres_type_name(&self) -> String {
+                match self {
+                    Loot::Nothing => "LootNothingResource".to_string(),
+                    Loot::Money(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                }
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                match self {
+                    Loot::Nothing => Vec::new(),
+                    Loot::Money(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Loot {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "Resource".to_string()
+            }
+        }
+
+        #[derive(::godot::prelude::GodotClass)]
+        #[class(tool,init,base = Resource)]
+        pub struct LootNothingResource {
+            #[base]
+            base: ::godot::obj::Base<::godot::classes::Resource>,
+        }
+
+        pub mod mod_loot_nothing {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            #[godot_dyn]
+            impl LootResourceExtractVariant for LootNothingResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Loot, ::as_gd_res::ExtractError> {
+                    Ok(Loot::Nothing)
+                }
+            }
+        }
+
+        pub mod mod_loot_money {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub type PayloadResource = <MoneyData as ::as_gd_res::AsGdResClass>::GdClass;
+
+            #[godot_dyn]
+            impl LootResourceExtractVariant for PayloadResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Loot, ::as_gd_res::ExtractError> {
+                    Ok(Loot::Money(::as_gd_res::TryExtractGd::try_extract_with(
+                        self, ctx
+                    )?))
+                }
+            }
+        }
+    };
+
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+// Struct-like and multi-field variants get a `{EnumName}{Variant}Resource` class, generated
+// like a `#[derive(AsGdRes)]` struct with the variant's fields, in the variant's module.
+#[test]
+fn test_enum_with_struct_variants() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Spell {
+            Fireball {
+                #[export(range = (0.0, 10.0))]
+                radius: f32,
+                damage: i32,
+            },
+            Heal(HealData),
+        }
+    };
+
+    let expected = quote! {
+        pub trait SpellResourceExtractVariant {
+            fn try_extract_enum_variant(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Spell, ::as_gd_res::ExtractError>;
+        }
+
+        type SpellResource =
+            ::godot::obj::DynGd<::godot::classes::Resource, dyn SpellResourceExtractVariant>;
+        impl ::as_gd_res::AsGdRes for Spell {
+            type ResType = ::godot::prelude::OnEditor<SpellResource>;
+        }
+
+        impl ::as_gd_res::AsGdResOpt for Spell {
+            type GdOption = Option<SpellResource>;
+        }
+
+        impl ::as_gd_res::AsGdResArray for Spell {
+            type GdArray = ::godot::prelude::Array<SpellResource>;
+        }
+
+        impl ::as_gd_res::ToGdRes for Spell {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(<dyn SpellResourceExtractVariant>::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(
+                    self,
+                    res,
+                    <dyn SpellResourceExtractVariant>::from_rust,
+                    <dyn SpellResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResOpt for Spell {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(<dyn SpellResourceExtractVariant>::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(
+                    value,
+                    res,
+                    <dyn SpellResourceExtractVariant>::from_rust,
+                    <dyn SpellResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResArray for Spell {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values
+                    .into_iter()
+                    .map(<dyn SpellResourceExtractVariant>::from_rust)
+                    .collect()
+            }
+            fn apply_to_gd_array<'a>(
+                values: impl IntoIterator<Item = &'a Self>,
+                res: &mut Self::GdArray
+            ) -> bool {
+                ::as_gd_res::apply_to_array(
+                    values,
+                    res,
+                    <dyn SpellResourceExtractVariant>::from_rust,
+                    <dyn SpellResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Spell {
+            type GdElement = SpellResource;
+            fn to_gd_element(&self) -> Self::GdElement {
+                <dyn SpellResourceExtractVariant>::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                <dyn SpellResourceExtractVariant>::apply_rust(self, res)
+            }
+        }
+
+        ::as_gd_res::impl_from_gd_res_by_element!(Spell);
+        impl dyn SpellResourceExtractVariant {
+            /// Builds the resource for the variant held by `value`.
+            pub fn from_rust(value: &Spell) -> SpellResource {
+                match value {
+                    Spell::Fireball { radius, damage } =>
+                        ::godot::obj::Gd::into_dyn::<dyn SpellResourceExtractVariant>(
+                            mod_spell_fireball::SpellFireballResource::from_fields((radius, damage))
+                        )
+                        .upcast(),
+                    Spell::Heal(v) => ::godot::obj::Gd::into_dyn::<dyn SpellResourceExtractVariant>(
+                        mod_spell_heal::PayloadResource::from_rust(v)
+                    )
+                    .upcast(),
+                }
+            }
+            /// Writes `value` onto `res` in place if it already holds a resource for
+            /// the same variant, and replaces it otherwise. Returns `true` if `res`
+            /// was replaced.
+            pub fn apply_rust(value: &Spell, res: &mut SpellResource) -> bool {
+                match value {
+                    Spell::Fireball { radius, damage } => {
+                        if let Ok(mut gd) = (**res)
+                            .clone()
+                            .try_cast::<mod_spell_fireball::SpellFireballResource>()
+                        {
+                            mod_spell_fireball::SpellFireballResource::apply_fields(
+                                &mut gd,
+                                (radius, damage)
+                            );
+                            return false;
+                        }
+                    }
+                    Spell::Heal(v) => {
+                        if let Ok(mut gd) = (**res).clone().try_cast::<mod_spell_heal::PayloadResource>() {
+                            v.apply_to(&mut gd);
+                            return false;
+                        }
+                    }
+                }
+                *res = Self::from_rust(value);
+                true
+            }
+        }
+
+        impl ::as_gd_res::ExtractGd for dyn SpellResourceExtractVariant {
+            type Extracted = Spell;
+            fn extract(&self) -> Self::Extracted {
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for dyn SpellResourceExtractVariant {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(ctx)
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Spell {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match res.type_name() {
+                    "SpellFireballResource" => {
+                        let v: mod_spell_fireball::SpellFireballFields =
+                            ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?;
+                        Ok(Spell::Fireball {
+                            radius: v.radius,
+                            damage: v.damage
+                        })
+                    }
+                    name if name == <HealData as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Spell::Heal(
+                        ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?
+                    )),
+                    other => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a resource for any variant of `{}`",
+                        other, "Spell"
+                    ))
+                    .in_resource("Spell", &doc.resource_path(res))),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Spell {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(
+                    value,
+                    <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+                )
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Spell {
+            fn tres_type_name(&self) -> String {
+                match self {
+                    Spell::Fireball { .. } => "SpellFireballResource".to_string(),
+                    Spell::Heal(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                }
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                match self {
+                    Spell::Fireball { radius, damage } =>
+                        mod_spell_fireball::SpellFireballFields::fields_to_tres((radius, damage), writer),
+                    Spell::Heal(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Spell {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "Resource".to_string()
+            }
+        }
+
+        pub mod mod_spell_fireball {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub struct SpellFireballFields {
+                pub radius: f32,
+                pub damage: i32,
+            }
+            #[derive(::godot::prelude::GodotClass)]
+            #[class(tool,init,base = Resource)]
+            pub struct SpellFireballResource {
+                #[base]
+                base: ::godot::obj::Base<::godot::classes::Resource>,
+                #[export(range = (0.0, 10.0))]
+                pub radius: <f32 as ::as_gd_res::AsGdRes>::ResType,
+                #[export]
+                pub damage: <i32 as ::as_gd_res::AsGdRes>::ResType,
+            }
+            impl ::as_gd_res::ExtractGd for SpellFireballResource {
+                type Extracted = SpellFireballFields;
+                fn extract(&self) -> Self::Extracted {
+                    ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+                }
+            }
+            impl ::as_gd_res::TryExtractGd for SpellFireballResource {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+                }
+                fn try_extract_with(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                        Ok(Self::Extracted {
+                            radius: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.radius, ctx)
+                                .map_err(|e| e.in_field("radius"))?,
+                            damage: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.damage, ctx)
+                                .map_err(|e| e.in_field("damage"))?,
+                        })
+                    })();
+                    extracted.map_err(|e| {
+                        let path = ::godot::obj::WithBaseField::base(self)
+                            .get_path()
+                            .to_string();
+                        e.in_resource("SpellFireball", &path)
+                    })
+                }
+            }
+            impl SpellFireballResource {
+                /// Builds a new resource from the fields of a variant, bound by reference.
+                pub fn from_fields(fields: (&f32, &i32)) -> ::godot::obj::Gd<Self> {
+                    ::godot::obj::Gd::from_init_fn(|base| Self {
+                        base,
+                        radius: ::as_gd_res::ToGdRes::to_gd_res(&(*fields.0)),
+                        damage: ::as_gd_res::ToGdRes::to_gd_res(&(*fields.1)),
+                    })
+                }
+                /// Writes the fields of a variant, bound by reference, onto an existing
+                /// resource in place, like `apply_to` on a struct.
+                pub fn apply_fields(res: &mut ::godot::obj::Gd<Self>, fields: (&f32, &i32)) {
+                    let changed = {
+                        let mut bound = res.bind_mut();
+                        false
+                            | ::as_gd_res::ToGdRes::apply_to_gd_res(&(*fields.0), &mut bound.radius)
+                            | ::as_gd_res::ToGdRes::apply_to_gd_res(&(*fields.1), &mut bound.damage)
+                    };
+                    if changed {
+                        res.clone()
+                            .upcast::<::godot::classes::Resource>()
+                            .emit_changed();
+                    }
+                }
+            }
+            impl ::as_gd_res::tres::FromTresResource for SpellFireballFields {
+                fn from_tres_resource(
+                    res: &::as_gd_res::tres::TresResource,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                        res.expect_type("SpellFireballResource")?;
+                        Ok(Self {
+                            radius: ::as_gd_res::tres::field(res, doc, "radius")?,
+                            damage: ::as_gd_res::tres::field(res, doc, "damage")?,
+                        })
+                    })();
+                    extracted.map_err(|e| e.in_resource("SpellFireball", &doc.resource_path(res)))
+                }
+            }
+            impl SpellFireballFields {
+                /// The `.tres` properties of the variant resource, from the fields of the
+                /// variant bound by reference.
+                pub fn fields_to_tres(
+                    fields: (&f32, &i32),
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                    vec![
+                        (
+                            "radius".to_string(),
+                            ::as_gd_res::tres::ToTres::to_tres(&(*fields.0), writer)
+                        ),
+                        (
+                            "damage".to_string(),
+                            ::as_gd_res::tres::ToTres::to_tres(&(*fields.1), writer)
+                        ),
+                    ]
+                }
+            }
             #[godot_dyn]
-            impl PickupResourceExtractVariant for HealDataResource {
-                fn extract_enum_variant(&self) -> Pickup {
-                    Pickup::Heal(self.extract())
+            impl SpellResourceExtractVariant for SpellFireballResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Spell, ::as_gd_res::ExtractError> {
+                    let v = ::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?;
+                    Ok(Spell::Fireball {
+                        radius: v.radius,
+                        damage: v.damage
+                    })
+                }
+            }
+        }
+
+        pub mod mod_spell_heal {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub type PayloadResource = <HealData as ::as_gd_res::AsGdResClass>::GdClass;
+
+            #[godot_dyn]
+            impl SpellResourceExtractVariant for PayloadResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Spell, ::as_gd_res::ExtractError> {
+                    Ok(Spell::Heal(::as_gd_res::TryExtractGd::try_extract_with(
+                        self, ctx
+                    )?))
                 }
             }
         }
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 #[test]
-fn test_enum_mixed_variants_error() {
+fn test_enum_with_wrapped_and_repeated_payloads() {
     let input: syn::DeriveInput = parse_quote! {
-        pub enum Mixed {
-            Unit,
-            Tuple(u32),
-            Struct { x: i32 }
+        pub enum Effect {
+            Buff(StatMod),
+            Debuff(StatMod),
+            #[as_gd_res(wrap)]
+            Shield(ShieldData),
         }
     };
+
     let expected = quote! {
-        compile_error!("`derive(AsGdRes)` only supports unit enums or single-tuple enums. Unsupported variants: Struct");
+        pub trait EffectResourceExtractVariant {
+            fn try_extract_enum_variant(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Effect, ::as_gd_res::ExtractError>;
+        }
+
+        type EffectResource =
+            ::godot::obj::DynGd<::godot::classes::Resource, dyn EffectResourceExtractVariant>;
+        impl ::as_gd_res::AsGdRes for Effect {
+            type ResType = ::godot::prelude::OnEditor<EffectResource>;
+        }
+
+        impl ::as_gd_res::AsGdResOpt for Effect {
+            type GdOption = Option<EffectResource>;
+        }
+
+        impl ::as_gd_res::AsGdResArray for Effect {
+            type GdArray = ::godot::prelude::Array<EffectResource>;
+        }
+
+        impl ::as_gd_res::ToGdRes for Effect {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(<dyn EffectResourceExtractVariant>::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(
+                    self,
+                    res,
+                    <dyn EffectResourceExtractVariant>::from_rust,
+                    <dyn EffectResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResOpt for Effect {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(<dyn EffectResourceExtractVariant>::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(
+                    value,
+                    res,
+                    <dyn EffectResourceExtractVariant>::from_rust,
+                    <dyn EffectResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResArray for Effect {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values
+                    .into_iter()
+                    .map(<dyn EffectResourceExtractVariant>::from_rust)
+                    .collect()
+            }
+            fn apply_to_gd_array<'a>(
+                values: impl IntoIterator<Item = &'a Self>,
+                res: &mut Self::GdArray
+            ) -> bool {
+                ::as_gd_res::apply_to_array(
+                    values,
+                    res,
+                    <dyn EffectResourceExtractVariant>::from_rust,
+                    <dyn EffectResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Effect {
+            type GdElement = EffectResource;
+            fn to_gd_element(&self) -> Self::GdElement {
+                <dyn EffectResourceExtractVariant>::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                <dyn EffectResourceExtractVariant>::apply_rust(self, res)
+            }
+        }
+
+        ::as_gd_res::impl_from_gd_res_by_element!(Effect);
+        impl dyn EffectResourceExtractVariant {
+            /// Builds the resource for the variant held by `value`.
+            pub fn from_rust(value: &Effect) -> EffectResource {
+                match value {
+                    Effect::Buff(field_0) => ::godot::obj::Gd::into_dyn::<dyn EffectResourceExtractVariant>(
+                        mod_effect_buff::EffectBuffResource::from_fields((field_0,))
+                    )
+                    .upcast(),
+                    Effect::Debuff(field_0) => ::godot::obj::Gd::into_dyn::<dyn EffectResourceExtractVariant>(
+                        mod_effect_debuff::EffectDebuffResource::from_fields((field_0,))
+                    )
+                    .upcast(),
+                    Effect::Shield(field_0) => ::godot::obj::Gd::into_dyn::<dyn EffectResourceExtractVariant>(
+                        mod_effect_shield::EffectShieldResource::from_fields((field_0,))
+                    )
+                    .upcast(),
+                }
+            }
+            /// Writes `value` onto `res` in place if it already holds a resource for
+            /// the same variant, and replaces it otherwise. Returns `true` if `res`
+            /// was replaced.
+            pub fn apply_rust(value: &Effect, res: &mut EffectResource) -> bool {
+                match value {
+                    Effect::Buff(field_0) => {
+                        if let Ok(mut gd) = (**res)
+                            .clone()
+                            .try_cast::<mod_effect_buff::EffectBuffResource>()
+                        {
+                            mod_effect_buff::EffectBuffResource::apply_fields(&mut gd, (field_0,));
+                            return false;
+                        }
+                    }
+                    Effect::Debuff(field_0) => {
+                        if let Ok(mut gd) = (**res)
+                            .clone()
+                            .try_cast::<mod_effect_debuff::EffectDebuffResource>()
+                        {
+                            mod_effect_debuff::EffectDebuffResource::apply_fields(&mut gd, (field_0,));
+                            return false;
+                        }
+                    }
+                    Effect::Shield(field_0) => {
+                        if let Ok(mut gd) = (**res)
+                            .clone()
+                            .try_cast::<mod_effect_shield::EffectShieldResource>()
+                        {
+                            mod_effect_shield::EffectShieldResource::apply_fields(&mut gd, (field_0,));
+                            return false;
+                        }
+                    }
+                }
+                *res = Self::from_rust(value);
+                true
+            }
+        }
+
+        impl ::as_gd_res::ExtractGd for dyn EffectResourceExtractVariant {
+            type Extracted = Effect;
+            fn extract(&self) -> Self::Extracted {
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for dyn EffectResourceExtractVariant {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(ctx)
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Effect {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match res.type_name() {
+                    "EffectBuffResource" => {
+                        let v: mod_effect_buff::EffectBuffFields =
+                            ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?;
+                        Ok(Effect::Buff(v.field_0))
+                    }
+                    "EffectDebuffResource" => {
+                        let v: mod_effect_debuff::EffectDebuffFields =
+                            ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?;
+                        Ok(Effect::Debuff(v.field_0))
+                    }
+                    "EffectShieldResource" => {
+                        let v: mod_effect_shield::EffectShieldFields =
+                            ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?;
+                        Ok(Effect::Shield(v.field_0))
+                    }
+                    other => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a resource for any variant of `{}`",
+                        other, "Effect"
+                    ))
+                    .in_resource("Effect", &doc.resource_path(res))),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Effect {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(
+                    value,
+                    <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+                )
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Effect {
+            fn tres_type_name(&self) -> String {
+                match self {
+                    Effect::Buff(..) => "EffectBuffResource".to_string(),
+                    Effect::Debuff(..) => "EffectDebuffResource".to_string(),
+                    Effect::Shield(..) => "EffectShieldResource".to_string(),
+                }
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                match self {
+                    Effect::Buff(field_0) =>
+                        mod_effect_buff::EffectBuffFields::fields_to_tres((field_0,), writer),
+                    Effect::Debuff(field_0) =>
+                        mod_effect_debuff::EffectDebuffFields::fields_to_tres((field_0,), writer),
+                    Effect::Shield(field_0) =>
+                        mod_effect_shield::EffectShieldFields::fields_to_tres((field_0,), writer),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Effect {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "Resource".to_string()
+            }
+        }
+
+        pub mod mod_effect_buff {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub struct EffectBuffFields {
+                pub field_0: StatMod,
+            }
+            #[derive(::godot::prelude::GodotClass)]
+            #[class(tool,init,base = Resource)]
+            pub struct EffectBuffResource {
+                #[base]
+                base: ::godot::obj::Base<::godot::classes::Resource>,
+                #[export]
+                pub field_0: <StatMod as ::as_gd_res::AsGdRes>::ResType,
+            }
+            impl ::as_gd_res::ExtractGd for EffectBuffResource {
+                type Extracted = EffectBuffFields;
+                fn extract(&self) -> Self::Extracted {
+                    ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+                }
+            }
+            impl ::as_gd_res::TryExtractGd for EffectBuffResource {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+                }
+                fn try_extract_with(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                        Ok(Self::Extracted {
+                            field_0: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field_0, ctx)
+                                .map_err(|e| e.in_field("field_0"))?,
+                        })
+                    })();
+                    extracted.map_err(|e| {
+                        let path = ::godot::obj::WithBaseField::base(self)
+                            .get_path()
+                            .to_string();
+                        e.in_resource("EffectBuff", &path)
+                    })
+                }
+            }
+            impl EffectBuffResource {
+                /// Builds a new resource from the fields of a variant, bound by reference.
+                pub fn from_fields(fields: (&StatMod,)) -> ::godot::obj::Gd<Self> {
+                    ::godot::obj::Gd::from_init_fn(|base| Self {
+                        base,
+                        field_0: ::as_gd_res::ToGdRes::to_gd_res(&(*fields.0)),
+                    })
+                }
+                /// Writes the fields of a variant, bound by reference, onto an existing
+                /// resource in place, like `apply_to` on a struct.
+                pub fn apply_fields(res: &mut ::godot::obj::Gd<Self>, fields: (&StatMod,)) {
+                    let changed = {
+                        let mut bound = res.bind_mut();
+                        false | ::as_gd_res::ToGdRes::apply_to_gd_res(&(*fields.0), &mut bound.field_0)
+                    };
+                    if changed {
+                        res.clone()
+                            .upcast::<::godot::classes::Resource>()
+                            .emit_changed();
+                    }
+                }
+            }
+            impl ::as_gd_res::tres::FromTresResource for EffectBuffFields {
+                fn from_tres_resource(
+                    res: &::as_gd_res::tres::TresResource,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                        res.expect_type("EffectBuffResource")?;
+                        Ok(Self {
+                            field_0: ::as_gd_res::tres::field(res, doc, "field_0")?,
+                        })
+                    })();
+                    extracted.map_err(|e| e.in_resource("EffectBuff", &doc.resource_path(res)))
+                }
+            }
+            impl EffectBuffFields {
+                /// The `.tres` properties of the variant resource, from the fields of the
+                /// variant bound by reference.
+                pub fn fields_to_tres(
+                    fields: (&StatMod,),
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                    vec![(
+                        "field_0".to_string(),
+                        ::as_gd_res::tres::ToTres::to_tres(&(*fields.0), writer)),
+                    ]
+                }
+            }
+            #[godot_dyn]
+            impl EffectResourceExtractVariant for EffectBuffResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Effect, ::as_gd_res::ExtractError> {
+                    let v = ::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?;
+                    Ok(Effect::Buff(v.field_0))
+                }
+            }
+        }
+
+        pub mod mod_effect_debuff {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub struct EffectDebuffFields {
+                pub field_0: StatMod,
+            }
+            #[derive(::godot::prelude::GodotClass)]
+            #[class(tool,init,base = Resource)]
+            pub struct EffectDebuffResource {
+                #[base]
+                base: ::godot::obj::Base<::godot::classes::Resource>,
+                #[export]
+                pub field_0: <StatMod as ::as_gd_res::AsGdRes>::ResType,
+            }
+            impl ::as_gd_res::ExtractGd for EffectDebuffResource {
+                type Extracted = EffectDebuffFields;
+                fn extract(&self) -> Self::Extracted {
+                    ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+                }
+            }
+            impl ::as_gd_res::TryExtractGd for EffectDebuffResource {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+                }
+                fn try_extract_with(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                        Ok(Self::Extracted {
+                            field_0: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field_0, ctx)
+                                .map_err(|e| e.in_field("field_0"))?,
+                        })
+                    })();
+                    extracted.map_err(|e| {
+                        let path = ::godot::obj::WithBaseField::base(self)
+                            .get_path()
+                            .to_string();
+                        e.in_resource("EffectDebuff", &path)
+                    })
+                }
+            }
+            impl EffectDebuffResource {
+                /// Builds a new resource from the fields of a variant, bound by reference.
+                pub fn from_fields(fields: (&StatMod,)) -> ::godot::obj::Gd<Self> {
+                    ::godot::obj::Gd::from_init_fn(|base| Self {
+                        base,
+                        field_0: ::as_gd_res::ToGdRes::to_gd_res(&(*fields.0)),
+                    })
+                }
+                /// Writes the fields of a variant, bound by reference, onto an existing
+                /// resource in place, like `apply_to` on a struct.
+                pub fn apply_fields(res: &mut ::godot::obj::Gd<Self>, fields: (&StatMod,)) {
+                    let changed = {
+                        let mut bound = res.bind_mut();
+                        false | ::as_gd_res::ToGdRes::apply_to_gd_res(&(*fields.0), &mut bound.field_0)
+                    };
+                    if changed {
+                        res.clone()
+                            .upcast::<::godot::classes::Resource>()
+                            .emit_changed();
+                    }
+                }
+            }
+            impl ::as_gd_res::tres::FromTresResource for EffectDebuffFields {
+                fn from_tres_resource(
+                    res: &::as_gd_res::tres::TresResource,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                        res.expect_type("EffectDebuffResource")?;
+                        Ok(Self {
+                            field_0: ::as_gd_res::tres::field(res, doc, "field_0")?,
+                        })
+                    })();
+                    extracted.map_err(|e| e.in_resource("EffectDebuff", &doc.resource_path(res)))
+                }
+            }
+            impl EffectDebuffFields {
+                /// The `.tres` properties of the variant resource, from the fields of the
+                /// variant bound by reference.
+                pub fn fields_to_tres(
+                    fields: (&StatMod,),
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                    vec![(
+                        "field_0".to_string(),
+                        ::as_gd_res::tres::ToTres::to_tres(&(*fields.0), writer)),
+                    ]
+                }
+            }
+            #[godot_dyn]
+            impl EffectResourceExtractVariant for EffectDebuffResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Effect, ::as_gd_res::ExtractError> {
+                    let v = ::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?;
+                    Ok(Effect::Debuff(v.field_0))
+                }
+            }
+        }
+
+        pub mod mod_effect_shield {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub struct EffectShieldFields {
+                pub field_0: ShieldData,
+            }
+            #[derive(::godot::prelude::GodotClass)]
+            #[class(tool,init,base = Resource)]
+            pub struct EffectShieldResource {
+                #[base]
+                base: ::godot::obj::Base<::godot::classes::Resource>,
+                #[export]
+                pub field_0: <ShieldData as ::as_gd_res::AsGdRes>::ResType,
+            }
+            impl ::as_gd_res::ExtractGd for EffectShieldResource {
+                type Extracted = EffectShieldFields;
+                fn extract(&self) -> Self::Extracted {
+                    ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+                }
+            }
+            impl ::as_gd_res::TryExtractGd for EffectShieldResource {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+                }
+                fn try_extract_with(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                        Ok(Self::Extracted {
+                            field_0: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field_0, ctx)
+                                .map_err(|e| e.in_field("field_0"))?,
+                        })
+                    })();
+                    extracted.map_err(|e| {
+                        let path = ::godot::obj::WithBaseField::base(self)
+                            .get_path()
+                            .to_string();
+                        e.in_resource("EffectShield", &path)
+                    })
+                }
+            }
+            impl EffectShieldResource {
+                /// Builds a new resource from the fields of a variant, bound by reference.
+                pub fn from_fields(fields: (&ShieldData,)) -> ::godot::obj::Gd<Self> {
+                    ::godot::obj::Gd::from_init_fn(|base| Self {
+                        base,
+                        field_0: ::as_gd_res::ToGdRes::to_gd_res(&(*fields.0)),
+                    })
+                }
+                /// Writes the fields of a variant, bound by reference, onto an existing
+                /// resource in place, like `apply_to` on a struct.
+                pub fn apply_fields(res: &mut ::godot::obj::Gd<Self>, fields: (&ShieldData,)) {
+                    let changed = {
+                        let mut bound = res.bind_mut();
+                        false | ::as_gd_res::ToGdRes::apply_to_gd_res(&(*fields.0), &mut bound.field_0)
+                    };
+                    if changed {
+                        res.clone()
+                            .upcast::<::godot::classes::Resource>()
+                            .emit_changed();
+                    }
+                }
+            }
+            impl ::as_gd_res::tres::FromTresResource for EffectShieldFields {
+                fn from_tres_resource(
+                    res: &::as_gd_res::tres::TresResource,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                        res.expect_type("EffectShieldResource")?;
+                        Ok(Self {
+                            field_0: ::as_gd_res::tres::field(res, doc, "field_0")?,
+                        })
+                    })();
+                    extracted.map_err(|e| e.in_resource("EffectShield", &doc.resource_path(res)))
+                }
+            }
+            impl EffectShieldFields {
+                /// The `.tres` properties of the variant resource, from the fields of the
+                /// variant bound by reference.
+                pub fn fields_to_tres(
+                    fields: (&ShieldData,),
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                    vec![(
+                        "field_0".to_string(),
+                        ::as_gd_res::tres::ToTres::to_tres(&(*fields.0), writer)),
+                    ]
+                }
+            }
+            #[godot_dyn]
+            impl EffectResourceExtractVariant for EffectShieldResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Effect, ::as_gd_res::ExtractError> {
+                    let v = ::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?;
+                    Ok(Effect::Shield(v.field_0))
+                }
+            }
+        }
     };
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
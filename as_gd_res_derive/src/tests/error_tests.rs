@@ -12,7 +12,10 @@ fn test_union_error() {
     let expected = quote! {
         compile_error!("`derive(AsGdRes)` only supports structs with named fields, enums with unit variants, or enums with single-tuple variants");
     };
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 #[test]
@@ -23,7 +26,10 @@ fn test_tuple_struct_error() {
     let expected = quote! {
         compile_error!("`derive(AsGdRes)` only supports structs with named fields");
     };
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 #[test]
@@ -34,5 +40,73 @@ fn test_generic_struct_error() {
     let expected = quote! {
         compile_error!("`derive(AsGdRes)` requires #[as_gd_res_types(...)] attribute when using generics");
     };
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_packed_attribute_on_non_vec_error() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub struct Foo {
+            #[as_gd_res(packed)]
+            val: Option<u8>,
+        }
+    };
+    let expected = quote! {
+        compile_error!("`#[as_gd_res(packed)]` is only supported on `Vec` fields");
+    };
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_multiple_instantiations_without_name_error() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_res_types(name = "IntWrapper", T = i32)]
+        #[as_gd_res_types(T = f32)]
+        pub struct Wrapper<T> { value: T }
+    };
+    let expected = quote! {
+        compile_error!("each `#[as_gd_res_types(...)]` needs a `name = \"...\"` when there is more than one");
+    };
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_invalid_instantiation_name_error() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_res_types(name = "Int Wrapper", T = i32)]
+        pub struct Wrapper<T> { value: T }
+    };
+    let expected = quote! {
+        compile_error!("`as_gd_res_types` names must be identifiers in quotes, e.g. `name = \"IntStrPair\"`");
+    };
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_pad_attribute_on_non_array_error() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub struct Foo {
+            #[as_gd_res(pad)]
+            values: Vec<i32>,
+        }
+    };
+    let expected = quote! {
+        compile_error!("`#[as_gd_res(pad)]` is only supported on `[T; N]` fields");
+    };
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
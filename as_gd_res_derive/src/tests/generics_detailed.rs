@@ -14,11 +14,14 @@ fn test_simple_generic_substitution() {
             pub field: T,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for SimpleGeneric<i32> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<SimpleGenericResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for SimpleGeneric<i32> {
+            type GdClass = SimpleGenericResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for SimpleGeneric<i32> {
             type GdOption = Option<::godot::obj::Gd<SimpleGenericResource>>;
@@ -28,6 +31,80 @@ fn test_simple_generic_substitution() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<SimpleGenericResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for SimpleGeneric<i32> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(SimpleGenericResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, SimpleGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for SimpleGeneric<i32> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(SimpleGenericResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, SimpleGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for SimpleGeneric<i32> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(SimpleGenericResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, SimpleGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for SimpleGeneric<i32> {
+            type GdElement = ::godot::obj::Gd<SimpleGenericResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                SimpleGenericResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(SimpleGeneric<i32>);
+
+        impl SimpleGeneric<i32> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<SimpleGenericResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field, &mut bound.field)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct SimpleGenericResource {
@@ -40,10 +117,84 @@ fn test_simple_generic_substitution() {
         impl ::as_gd_res::ExtractGd for SimpleGenericResource {
             type Extracted = SimpleGeneric<i32>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field: self.field.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for SimpleGenericResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field, ctx).map_err(|e| e.in_field("field"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("SimpleGeneric", &path)
+                })
+            }
+        }
+
+        impl SimpleGenericResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &SimpleGeneric<i32>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field: ::as_gd_res::ToGdRes::to_gd_res(&value.field),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for SimpleGeneric<i32> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("SimpleGenericResource")?;
+                    Ok(Self {
+                        field: ::as_gd_res::tres::field(res, doc, "field")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("SimpleGeneric", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for SimpleGeneric<i32> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for SimpleGeneric<i32> {
+            fn tres_type_name(&self) -> String {
+                "SimpleGenericResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for SimpleGeneric<i32> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "SimpleGenericResource".to_string()
             }
         }
     };
@@ -61,11 +212,14 @@ fn test_multiple_generic_parameters() {
             pub field_c: C,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for MultiGeneric<i32, f32, bool> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<MultiGenericResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for MultiGeneric<i32, f32, bool> {
+            type GdClass = MultiGenericResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for MultiGeneric<i32, f32, bool> {
             type GdOption = Option<::godot::obj::Gd<MultiGenericResource>>;
@@ -75,6 +229,82 @@ fn test_multiple_generic_parameters() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<MultiGenericResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for MultiGeneric<i32, f32, bool> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(MultiGenericResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, MultiGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for MultiGeneric<i32, f32, bool> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(MultiGenericResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, MultiGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for MultiGeneric<i32, f32, bool> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(MultiGenericResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, MultiGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for MultiGeneric<i32, f32, bool> {
+            type GdElement = ::godot::obj::Gd<MultiGenericResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                MultiGenericResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(MultiGeneric<i32, f32, bool>);
+
+        impl MultiGeneric<i32, f32, bool> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<MultiGenericResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field_a, &mut bound.field_a)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field_b, &mut bound.field_b)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field_c, &mut bound.field_c)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct MultiGenericResource {
@@ -91,12 +321,92 @@ fn test_multiple_generic_parameters() {
         impl ::as_gd_res::ExtractGd for MultiGenericResource {
             type Extracted = MultiGeneric<i32, f32, bool>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field_a: self.field_a.extract(),
-                    field_b: self.field_b.extract(),
-                    field_c: self.field_c.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for MultiGenericResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field_a: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field_a, ctx).map_err(|e| e.in_field("field_a"))?,
+                        field_b: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field_b, ctx).map_err(|e| e.in_field("field_b"))?,
+                        field_c: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field_c, ctx).map_err(|e| e.in_field("field_c"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("MultiGeneric", &path)
+                })
+            }
+        }
+
+        impl MultiGenericResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &MultiGeneric<i32, f32, bool>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field_a: ::as_gd_res::ToGdRes::to_gd_res(&value.field_a),
+                    field_b: ::as_gd_res::ToGdRes::to_gd_res(&value.field_b),
+                    field_c: ::as_gd_res::ToGdRes::to_gd_res(&value.field_c),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for MultiGeneric<i32, f32, bool> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("MultiGenericResource")?;
+                    Ok(Self {
+                        field_a: ::as_gd_res::tres::field(res, doc, "field_a")?,
+                        field_b: ::as_gd_res::tres::field(res, doc, "field_b")?,
+                        field_c: ::as_gd_res::tres::field(res, doc, "field_c")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("MultiGeneric", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for MultiGeneric<i32, f32, bool> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for MultiGeneric<i32, f32, bool> {
+            fn tres_type_name(&self) -> String {
+                "MultiGenericResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field_a".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field_a, writer)),
+                    ("field_b".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field_b, writer)),
+                    ("field_c".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field_c, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for MultiGeneric<i32, f32, bool> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "MultiGenericResource".to_string()
             }
         }
     };
@@ -112,11 +422,14 @@ fn test_generic_with_complex_type() {
             pub field: T,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for WithComplexType<SomeComplexType> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<WithComplexTypeResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for WithComplexType<SomeComplexType> {
+            type GdClass = WithComplexTypeResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for WithComplexType<SomeComplexType> {
             type GdOption = Option<::godot::obj::Gd<WithComplexTypeResource>>;
@@ -126,6 +439,80 @@ fn test_generic_with_complex_type() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<WithComplexTypeResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for WithComplexType<SomeComplexType> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(WithComplexTypeResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, WithComplexTypeResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for WithComplexType<SomeComplexType> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(WithComplexTypeResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, WithComplexTypeResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for WithComplexType<SomeComplexType> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(WithComplexTypeResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, WithComplexTypeResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for WithComplexType<SomeComplexType> {
+            type GdElement = ::godot::obj::Gd<WithComplexTypeResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                WithComplexTypeResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(WithComplexType<SomeComplexType>);
+
+        impl WithComplexType<SomeComplexType> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<WithComplexTypeResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field, &mut bound.field)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct WithComplexTypeResource {
@@ -138,10 +525,84 @@ fn test_generic_with_complex_type() {
         impl ::as_gd_res::ExtractGd for WithComplexTypeResource {
             type Extracted = WithComplexType<SomeComplexType>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field: self.field.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for WithComplexTypeResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field, ctx).map_err(|e| e.in_field("field"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("WithComplexType", &path)
+                })
+            }
+        }
+
+        impl WithComplexTypeResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &WithComplexType<SomeComplexType>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field: ::as_gd_res::ToGdRes::to_gd_res(&value.field),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for WithComplexType<SomeComplexType> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("WithComplexTypeResource")?;
+                    Ok(Self {
+                        field: ::as_gd_res::tres::field(res, doc, "field")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("WithComplexType", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for WithComplexType<SomeComplexType> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for WithComplexType<SomeComplexType> {
+            fn tres_type_name(&self) -> String {
+                "WithComplexTypeResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for WithComplexType<SomeComplexType> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "WithComplexTypeResource".to_string()
             }
         }
     };
@@ -158,11 +619,14 @@ fn test_nested_generic_substituted() {
             pub field: Vec<T>,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for NestedGeneric<i32> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<NestedGenericResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for NestedGeneric<i32> {
+            type GdClass = NestedGenericResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for NestedGeneric<i32> {
             type GdOption = Option<::godot::obj::Gd<NestedGenericResource>>;
@@ -172,6 +636,80 @@ fn test_nested_generic_substituted() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<NestedGenericResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for NestedGeneric<i32> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(NestedGenericResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, NestedGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for NestedGeneric<i32> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(NestedGenericResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, NestedGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for NestedGeneric<i32> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(NestedGenericResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, NestedGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for NestedGeneric<i32> {
+            type GdElement = ::godot::obj::Gd<NestedGenericResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                NestedGenericResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(NestedGeneric<i32>);
+
+        impl NestedGeneric<i32> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<NestedGenericResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field, &mut bound.field)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct NestedGenericResource {
@@ -184,10 +722,84 @@ fn test_nested_generic_substituted() {
         impl ::as_gd_res::ExtractGd for NestedGenericResource {
             type Extracted = NestedGeneric<i32>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field: self.field.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for NestedGenericResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field, ctx).map_err(|e| e.in_field("field"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("NestedGeneric", &path)
+                })
+            }
+        }
+
+        impl NestedGenericResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &NestedGeneric<i32>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field: ::as_gd_res::ToGdRes::to_gd_res(&value.field),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for NestedGeneric<i32> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("NestedGenericResource")?;
+                    Ok(Self {
+                        field: ::as_gd_res::tres::field(res, doc, "field")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("NestedGeneric", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for NestedGeneric<i32> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for NestedGeneric<i32> {
+            fn tres_type_name(&self) -> String {
+                "NestedGenericResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for NestedGeneric<i32> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "NestedGenericResource".to_string()
             }
         }
     };
@@ -203,11 +815,14 @@ fn test_option_generic_substituted() {
             pub field: Option<T>,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for OptionGeneric<i32> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<OptionGenericResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for OptionGeneric<i32> {
+            type GdClass = OptionGenericResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for OptionGeneric<i32> {
             type GdOption = Option<::godot::obj::Gd<OptionGenericResource>>;
@@ -217,22 +832,174 @@ fn test_option_generic_substituted() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<OptionGenericResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for OptionGeneric<i32> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(OptionGenericResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, OptionGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for OptionGeneric<i32> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(OptionGenericResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, OptionGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for OptionGeneric<i32> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(OptionGenericResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, OptionGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for OptionGeneric<i32> {
+            type GdElement = ::godot::obj::Gd<OptionGenericResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                OptionGenericResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(OptionGeneric<i32>);
+
+        impl OptionGeneric<i32> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<OptionGenericResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::apply_to_gd_toggle(self.field.as_ref(), &mut bound.field_enabled, &mut bound.field)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct OptionGenericResource {
             #[base]
             base: ::godot::obj::Base<::godot::classes::Resource>,
             #[export]
-            pub field: <Option<i32> as ::as_gd_res::AsGdRes>::ResType,
+            pub field_enabled: bool,
+            #[export]
+            pub field: <i32 as ::as_gd_res::AsGdRes>::ResType,
         }
 
         impl ::as_gd_res::ExtractGd for OptionGenericResource {
             type Extracted = OptionGeneric<i32>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field: self.field.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for OptionGenericResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field: ::as_gd_res::try_from_gd_toggle(self.field_enabled, &self.field, ctx).map_err(|e| e.in_field("field"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("OptionGeneric", &path)
+                })
+            }
+        }
+
+        impl OptionGenericResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &OptionGeneric<i32>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field_enabled: value.field.is_some(),
+                    field: ::as_gd_res::to_gd_toggle(value.field.as_ref()),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for OptionGeneric<i32> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("OptionGenericResource")?;
+                    Ok(Self {
+                        field: ::as_gd_res::tres::toggle_field(res, doc, "field_enabled", "field")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("OptionGeneric", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for OptionGeneric<i32> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for OptionGeneric<i32> {
+            fn tres_type_name(&self) -> String {
+                "OptionGenericResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field_enabled".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field.is_some(), writer)),
+                    ("field".to_string(), ::as_gd_res::tres::toggle_to_tres(self.field.as_ref(), writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for OptionGeneric<i32> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "OptionGenericResource".to_string()
             }
         }
     };
@@ -250,12 +1017,15 @@ fn test_partial_generic_mapping() {
             pub field_b: B,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     // With partial mapping, only A is in concrete_type_args
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for PartialGeneric<i32> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<PartialGenericResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for PartialGeneric<i32> {
+            type GdClass = PartialGenericResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for PartialGeneric<i32> {
             type GdOption = Option<::godot::obj::Gd<PartialGenericResource>>;
@@ -265,6 +1035,81 @@ fn test_partial_generic_mapping() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<PartialGenericResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for PartialGeneric<i32> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(PartialGenericResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, PartialGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for PartialGeneric<i32> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(PartialGenericResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, PartialGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for PartialGeneric<i32> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(PartialGenericResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, PartialGenericResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for PartialGeneric<i32> {
+            type GdElement = ::godot::obj::Gd<PartialGenericResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                PartialGenericResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(PartialGeneric<i32>);
+
+        impl PartialGeneric<i32> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<PartialGenericResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field_a, &mut bound.field_a)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field_b, &mut bound.field_b)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct PartialGenericResource {
@@ -280,10 +1125,1605 @@ fn test_partial_generic_mapping() {
         impl ::as_gd_res::ExtractGd for PartialGenericResource {
             type Extracted = PartialGeneric<i32>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field_a: self.field_a.extract(),
-                    field_b: self.field_b.extract(),
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for PartialGenericResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field_a: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field_a, ctx).map_err(|e| e.in_field("field_a"))?,
+                        field_b: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field_b, ctx).map_err(|e| e.in_field("field_b"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("PartialGeneric", &path)
+                })
+            }
+        }
+
+        impl PartialGenericResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &PartialGeneric<i32>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field_a: ::as_gd_res::ToGdRes::to_gd_res(&value.field_a),
+                    field_b: ::as_gd_res::ToGdRes::to_gd_res(&value.field_b),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for PartialGeneric<i32> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("PartialGenericResource")?;
+                    Ok(Self {
+                        field_a: ::as_gd_res::tres::field(res, doc, "field_a")?,
+                        field_b: ::as_gd_res::tres::field(res, doc, "field_b")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("PartialGeneric", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for PartialGeneric<i32> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for PartialGeneric<i32> {
+            fn tres_type_name(&self) -> String {
+                "PartialGenericResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field_a".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field_a, writer)),
+                    ("field_b".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field_b, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for PartialGeneric<i32> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "PartialGenericResource".to_string()
+            }
+        }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_generic_enum_substituted() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_res_types(C = i32)]
+        pub enum Reward<C> {
+            Coins(CoinData<C>),
+            Item(ItemData<C>),
+            Chest { gold: C },
+        }
+    };
+    let actual = expand_as_gd_res(input, true);
+    let expected = quote! {
+        pub trait RewardResourceExtractVariant {
+            fn try_extract_enum_variant(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Reward<i32>, ::as_gd_res::ExtractError>;
+        }
+
+        type RewardResource =
+            ::godot::obj::DynGd<::godot::classes::Resource, dyn RewardResourceExtractVariant>;
+        impl ::as_gd_res::AsGdRes for Reward<i32> {
+            type ResType = ::godot::prelude::OnEditor<RewardResource>;
+        }
+
+        impl ::as_gd_res::AsGdResOpt for Reward<i32> {
+            type GdOption = Option<RewardResource>;
+        }
+
+        impl ::as_gd_res::AsGdResArray for Reward<i32> {
+            type GdArray = ::godot::prelude::Array<RewardResource>;
+        }
+
+        impl ::as_gd_res::ToGdRes for Reward<i32> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(<dyn RewardResourceExtractVariant>::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(
+                    self,
+                    res,
+                    <dyn RewardResourceExtractVariant>::from_rust,
+                    <dyn RewardResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResOpt for Reward<i32> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(<dyn RewardResourceExtractVariant>::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(
+                    value,
+                    res,
+                    <dyn RewardResourceExtractVariant>::from_rust,
+                    <dyn RewardResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResArray for Reward<i32> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values
+                    .into_iter()
+                    .map(<dyn RewardResourceExtractVariant>::from_rust)
+                    .collect()
+            }
+            fn apply_to_gd_array<'a>(
+                values: impl IntoIterator<Item = &'a Self>,
+                res: &mut Self::GdArray
+            ) -> bool {
+                ::as_gd_res::apply_to_array(
+                    values,
+                    res,
+                    <dyn RewardResourceExtractVariant>::from_rust,
+                    <dyn RewardResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Reward<i32> {
+            type GdElement = RewardResource;
+            fn to_gd_element(&self) -> Self::GdElement {
+                <dyn RewardResourceExtractVariant>::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                <dyn RewardResourceExtractVariant>::apply_rust(self, res)
+            }
+        }
+
+        ::as_gd_res::impl_from_gd_res_by_element!(Reward<i32>);
+        impl dyn RewardResourceExtractVariant {
+            /// Builds the resource for the variant held by `value`.
+            pub fn from_rust(value: &Reward<i32>) -> RewardResource {
+                match value {
+                    Reward::Coins(v) => ::godot::obj::Gd::into_dyn::<dyn RewardResourceExtractVariant>(
+                        mod_reward_coins::PayloadResource::from_rust(v)
+                    )
+                    .upcast(),
+                    Reward::Item(v) => ::godot::obj::Gd::into_dyn::<dyn RewardResourceExtractVariant>(
+                        mod_reward_item::PayloadResource::from_rust(v)
+                    )
+                    .upcast(),
+                    Reward::Chest { gold } =>
+                        ::godot::obj::Gd::into_dyn::<dyn RewardResourceExtractVariant>(
+                            mod_reward_chest::RewardChestResource::from_fields((gold,))
+                        )
+                        .upcast(),
+                }
+            }
+            /// Writes `value` onto `res` in place if it already holds a resource for
+            /// the same variant, and replaces it otherwise. Returns `true` if `res`
+            /// was replaced.
+            pub fn apply_rust(value: &Reward<i32>, res: &mut RewardResource) -> bool {
+                match value {
+                    Reward::Coins(v) => {
+                        if let Ok(mut gd) = (**res).clone().try_cast::<mod_reward_coins::PayloadResource>() {
+                            v.apply_to(&mut gd);
+                            return false;
+                        }
+                    }
+                    Reward::Item(v) => {
+                        if let Ok(mut gd) = (**res).clone().try_cast::<mod_reward_item::PayloadResource>() {
+                            v.apply_to(&mut gd);
+                            return false;
+                        }
+                    }
+                    Reward::Chest { gold } => {
+                        if let Ok(mut gd) = (**res)
+                            .clone()
+                            .try_cast::<mod_reward_chest::RewardChestResource>()
+                        {
+                            mod_reward_chest::RewardChestResource::apply_fields(&mut gd, (gold,));
+                            return false;
+                        }
+                    }
+                }
+                *res = Self::from_rust(value);
+                true
+            }
+        }
+
+        impl ::as_gd_res::ExtractGd for dyn RewardResourceExtractVariant {
+            type Extracted = Reward<i32>;
+            fn extract(&self) -> Self::Extracted {
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for dyn RewardResourceExtractVariant {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(ctx)
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Reward<i32> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match res.type_name() {
+                    name if name == <CoinData<i32> as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Reward::Coins(
+                        ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?
+                    )),
+                    name if name == <ItemData<i32> as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Reward::Item(
+                        ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?
+                    )),
+                    "RewardChestResource" => {
+                        let v: mod_reward_chest::RewardChestFields =
+                            ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?;
+                        Ok(Reward::Chest { gold: v.gold })
+                    }
+                    other => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a resource for any variant of `{}`",
+                        other, "Reward"
+                    ))
+                    .in_resource("Reward", &doc.resource_path(res))),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Reward<i32> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(
+                    value,
+                    <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+                )
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Reward<i32> {
+            fn tres_type_name(&self) -> String {
+                match self {
+                    Reward::Coins(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                    Reward::Item(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                    Reward::Chest { .. } => "RewardChestResource".to_string(),
+                }
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                match self {
+                    Reward::Coins(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                    Reward::Item(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                    Reward::Chest { gold } =>
+                        mod_reward_chest::RewardChestFields::fields_to_tres((gold,), writer),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Reward<i32> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "Resource".to_string()
+            }
+        }
+
+        pub mod mod_reward_coins {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub type PayloadResource = <CoinData<i32> as ::as_gd_res::AsGdResClass>::GdClass;
+
+            #[godot_dyn]
+            impl RewardResourceExtractVariant for PayloadResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Reward<i32>, ::as_gd_res::ExtractError> {
+                    Ok(Reward::Coins(::as_gd_res::TryExtractGd::try_extract_with(
+                        self, ctx
+                    )?))
+                }
+            }
+        }
+
+        pub mod mod_reward_item {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub type PayloadResource = <ItemData<i32> as ::as_gd_res::AsGdResClass>::GdClass;
+
+            #[godot_dyn]
+            impl RewardResourceExtractVariant for PayloadResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Reward<i32>, ::as_gd_res::ExtractError> {
+                    Ok(Reward::Item(::as_gd_res::TryExtractGd::try_extract_with(
+                        self, ctx
+                    )?))
+                }
+            }
+        }
+
+        pub mod mod_reward_chest {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub struct RewardChestFields {
+                pub gold: i32,
+            }
+            #[derive(::godot::prelude::GodotClass)]
+            #[class(tool,init,base = Resource)]
+            pub struct RewardChestResource {
+                #[base]
+                base: ::godot::obj::Base<::godot::classes::Resource>,
+                #[export]
+                pub gold: <i32 as ::as_gd_res::AsGdRes>::ResType,
+            }
+            impl ::as_gd_res::ExtractGd for RewardChestResource {
+                type Extracted = RewardChestFields;
+                fn extract(&self) -> Self::Extracted {
+                    ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+                }
+            }
+            impl ::as_gd_res::TryExtractGd for RewardChestResource {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+                }
+                fn try_extract_with(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                        Ok(Self::Extracted {
+                            gold: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.gold, ctx)
+                                .map_err(|e| e.in_field("gold"))?,
+                        })
+                    })();
+                    extracted.map_err(|e| {
+                        let path = ::godot::obj::WithBaseField::base(self)
+                            .get_path()
+                            .to_string();
+                        e.in_resource("RewardChest", &path)
+                    })
+                }
+            }
+            impl RewardChestResource {
+                /// Builds a new resource from the fields of a variant, bound by reference.
+                pub fn from_fields(fields: (&i32,)) -> ::godot::obj::Gd<Self> {
+                    ::godot::obj::Gd::from_init_fn(|base| Self {
+                        base,
+                        gold: ::as_gd_res::ToGdRes::to_gd_res(&(*fields.0)),
+                    })
+                }
+                /// Writes the fields of a variant, bound by reference, onto an existing
+                /// resource in place, like `apply_to` on a struct.
+                pub fn apply_fields(res: &mut ::godot::obj::Gd<Self>, fields: (&i32,)) {
+                    let changed = {
+                        let mut bound = res.bind_mut();
+                        false | ::as_gd_res::ToGdRes::apply_to_gd_res(&(*fields.0), &mut bound.gold)
+                    };
+                    if changed {
+                        res.clone()
+                            .upcast::<::godot::classes::Resource>()
+                            .emit_changed();
+                    }
+                }
+            }
+            impl ::as_gd_res::tres::FromTresResource for RewardChestFields {
+                fn from_tres_resource(
+                    res: &::as_gd_res::tres::TresResource,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                        res.expect_type("RewardChestResource")?;
+                        Ok(Self {
+                            gold: ::as_gd_res::tres::field(res, doc, "gold")?,
+                        })
+                    })();
+                    extracted.map_err(|e| e.in_resource("RewardChest", &doc.resource_path(res)))
+                }
+            }
+            impl RewardChestFields {
+                /// The `.tres` properties of the variant resource, from the fields of the
+                /// variant bound by reference.
+                pub fn fields_to_tres(
+                    fields: (&i32,),
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                    vec![(
+                        "gold".to_string(),
+                        ::as_gd_res::tres::ToTres::to_tres(&(*fields.0), writer)),
+                    ]
+                }
+            }
+            #[godot_dyn]
+            impl RewardResourceExtractVariant for RewardChestResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Reward<i32>, ::as_gd_res::ExtractError> {
+                    let v = ::as_gd_res::TryExtractGd::try_extract_with(self, ctx)?;
+                    Ok(Reward::Chest { gold: v.gold })
+                }
+            }
+        }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_multiple_instantiations() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_res_types(name = "IntWrapper", T = i32)]
+        #[as_gd_res_types(name = "FloatWrapper", T = f32)]
+        pub struct Wrapper<T> {
+            pub value: T,
+        }
+    };
+    let actual = expand_as_gd_res(input, true);
+    let expected = quote! {
+        impl ::as_gd_res::AsGdRes for Wrapper<i32> {
+            type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<IntWrapperResource>>;
+        }
+        impl ::as_gd_res::AsGdResClass for Wrapper<i32> {
+            type GdClass = IntWrapperResource;
+        }
+
+        impl ::as_gd_res::AsGdResOpt for Wrapper<i32> {
+            type GdOption = Option<::godot::obj::Gd<IntWrapperResource>>;
+        }
+
+        impl ::as_gd_res::AsGdResArray for Wrapper<i32> {
+            type GdArray = ::godot::prelude::Array<::godot::obj::Gd<IntWrapperResource>>;
+        }
+
+        impl ::as_gd_res::ToGdRes for Wrapper<i32> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(IntWrapperResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, IntWrapperResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::ToGdResOpt for Wrapper<i32> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(IntWrapperResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, IntWrapperResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::ToGdResArray for Wrapper<i32> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(IntWrapperResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, IntWrapperResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Wrapper<i32> {
+            type GdElement = ::godot::obj::Gd<IntWrapperResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                IntWrapperResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+
+        ::as_gd_res::impl_from_gd_res_by_element!(Wrapper<i32>);
+        impl Wrapper<i32> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<IntWrapperResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.value, &mut bound.value)
+                };
+                if changed {
+                    res.clone()
+                        .upcast::<::godot::classes::Resource>()
+                        .emit_changed();
+                }
+            }
+        }
+
+        #[derive(::godot::prelude::GodotClass)]
+        #[class(tool,init,base = Resource)]
+        pub struct IntWrapperResource {
+            #[base]
+            base: ::godot::obj::Base<::godot::classes::Resource>,
+            #[export]
+            pub value: <i32 as ::as_gd_res::AsGdRes>::ResType,
+        }
+
+        impl ::as_gd_res::ExtractGd for IntWrapperResource {
+            type Extracted = Wrapper<i32>;
+            fn extract(&self) -> Self::Extracted {
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for IntWrapperResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        value: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.value, ctx)
+                            .map_err(|e| e.in_field("value"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self)
+                        .get_path()
+                        .to_string();
+                    e.in_resource("Wrapper", &path)
+                })
+            }
+        }
+
+        impl IntWrapperResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &Wrapper<i32>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    value: ::as_gd_res::ToGdRes::to_gd_res(&value.value),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Wrapper<i32> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("IntWrapperResource")?;
+                    Ok(Self {
+                        value: ::as_gd_res::tres::field(res, doc, "value")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("Wrapper", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Wrapper<i32> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(
+                    value,
+                    <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+                )
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Wrapper<i32> {
+            fn tres_type_name(&self) -> String {
+                "IntWrapperResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![(
+                    "value".to_string(),
+                    ::as_gd_res::tres::ToTres::to_tres(&self.value, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Wrapper<i32> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "IntWrapperResource".to_string()
+            }
+        }
+
+        impl ::as_gd_res::AsGdRes for Wrapper<f32> {
+            type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<FloatWrapperResource>>;
+        }
+        impl ::as_gd_res::AsGdResClass for Wrapper<f32> {
+            type GdClass = FloatWrapperResource;
+        }
+
+        impl ::as_gd_res::AsGdResOpt for Wrapper<f32> {
+            type GdOption = Option<::godot::obj::Gd<FloatWrapperResource>>;
+        }
+
+        impl ::as_gd_res::AsGdResArray for Wrapper<f32> {
+            type GdArray = ::godot::prelude::Array<::godot::obj::Gd<FloatWrapperResource>>;
+        }
+
+        impl ::as_gd_res::ToGdRes for Wrapper<f32> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(FloatWrapperResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, FloatWrapperResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::ToGdResOpt for Wrapper<f32> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(FloatWrapperResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, FloatWrapperResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::ToGdResArray for Wrapper<f32> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(FloatWrapperResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, FloatWrapperResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Wrapper<f32> {
+            type GdElement = ::godot::obj::Gd<FloatWrapperResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                FloatWrapperResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+
+        ::as_gd_res::impl_from_gd_res_by_element!(Wrapper<f32>);
+        impl Wrapper<f32> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<FloatWrapperResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.value, &mut bound.value)
+                };
+                if changed {
+                    res.clone()
+                        .upcast::<::godot::classes::Resource>()
+                        .emit_changed();
+                }
+            }
+        }
+
+        #[derive(::godot::prelude::GodotClass)]
+        #[class(tool,init,base = Resource)]
+        pub struct FloatWrapperResource {
+            #[base]
+            base: ::godot::obj::Base<::godot::classes::Resource>,
+            #[export]
+            pub value: <f32 as ::as_gd_res::AsGdRes>::ResType,
+        }
+
+        impl ::as_gd_res::ExtractGd for FloatWrapperResource {
+            type Extracted = Wrapper<f32>;
+            fn extract(&self) -> Self::Extracted {
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for FloatWrapperResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        value: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.value, ctx)
+                            .map_err(|e| e.in_field("value"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self)
+                        .get_path()
+                        .to_string();
+                    e.in_resource("Wrapper", &path)
+                })
+            }
+        }
+
+        impl FloatWrapperResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &Wrapper<f32>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    value: ::as_gd_res::ToGdRes::to_gd_res(&value.value),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Wrapper<f32> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("FloatWrapperResource")?;
+                    Ok(Self {
+                        value: ::as_gd_res::tres::field(res, doc, "value")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("Wrapper", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Wrapper<f32> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(
+                    value,
+                    <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+                )
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Wrapper<f32> {
+            fn tres_type_name(&self) -> String {
+                "FloatWrapperResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![(
+                    "value".to_string(),
+                    ::as_gd_res::tres::ToTres::to_tres(&self.value, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Wrapper<f32> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "FloatWrapperResource".to_string()
+            }
+        }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_const_generic_substituted() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_res_types(N = 4)]
+        pub struct Loadout<const N: usize> {
+            pub slots: [Slot; N],
+            pub history: Ring<N>,
+        }
+    };
+    let actual = expand_as_gd_res(input, true);
+    let expected = quote! {
+        impl ::as_gd_res::AsGdRes for Loadout<4> {
+            type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<LoadoutResource>>;
+        }
+        impl ::as_gd_res::AsGdResClass for Loadout<4> {
+            type GdClass = LoadoutResource;
+        }
+
+        impl ::as_gd_res::AsGdResOpt for Loadout<4> {
+            type GdOption = Option<::godot::obj::Gd<LoadoutResource>>;
+        }
+
+        impl ::as_gd_res::AsGdResArray for Loadout<4> {
+            type GdArray = ::godot::prelude::Array<::godot::obj::Gd<LoadoutResource>>;
+        }
+
+        impl ::as_gd_res::ToGdRes for Loadout<4> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(LoadoutResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, LoadoutResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::ToGdResOpt for Loadout<4> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(LoadoutResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, LoadoutResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::ToGdResArray for Loadout<4> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(LoadoutResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, LoadoutResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Loadout<4> {
+            type GdElement = ::godot::obj::Gd<LoadoutResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                LoadoutResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+
+        ::as_gd_res::impl_from_gd_res_by_element!(Loadout<4>);
+        impl Loadout<4> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<LoadoutResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                        | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.slots, &mut bound.slots)
+                        | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.history, &mut bound.history)
+                };
+                if changed {
+                    res.clone()
+                        .upcast::<::godot::classes::Resource>()
+                        .emit_changed();
+                }
+            }
+        }
+
+        #[derive(::godot::prelude::GodotClass)]
+        #[class(tool,init,base = Resource)]
+        pub struct LoadoutResource {
+            #[base]
+            base: ::godot::obj::Base<::godot::classes::Resource>,
+            #[export]
+            #[init(val = ::as_gd_res::sized_gd_array::<Slot>(4))]
+            pub slots: <[Slot; 4] as ::as_gd_res::AsGdRes>::ResType,
+            #[export]
+            pub history: <Ring<4> as ::as_gd_res::AsGdRes>::ResType,
+        }
+
+        impl ::as_gd_res::ExtractGd for LoadoutResource {
+            type Extracted = Loadout<4>;
+            fn extract(&self) -> Self::Extracted {
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for LoadoutResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        slots: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.slots, ctx)
+                            .map_err(|e| e.in_field("slots"))?,
+                        history: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.history, ctx)
+                            .map_err(|e| e.in_field("history"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self)
+                        .get_path()
+                        .to_string();
+                    e.in_resource("Loadout", &path)
+                })
+            }
+        }
+
+        impl LoadoutResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &Loadout<4>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    slots: ::as_gd_res::ToGdRes::to_gd_res(&value.slots),
+                    history: ::as_gd_res::ToGdRes::to_gd_res(&value.history),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Loadout<4> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("LoadoutResource")?;
+                    Ok(Self {
+                        slots: ::as_gd_res::tres::field(res, doc, "slots")?,
+                        history: ::as_gd_res::tres::field(res, doc, "history")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("Loadout", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Loadout<4> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(
+                    value,
+                    <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+                )
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Loadout<4> {
+            fn tres_type_name(&self) -> String {
+                "LoadoutResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    (
+                        "slots".to_string(),
+                        ::as_gd_res::tres::ToTres::to_tres(&self.slots, writer)
+                    ),
+                    (
+                        "history".to_string(),
+                        ::as_gd_res::tres::ToTres::to_tres(&self.history, writer)
+                    ),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Loadout<4> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "LoadoutResource".to_string()
+            }
+        }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_named_instantiations_share_generic_payload() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_res_types(name = "IntReward", T = i32)]
+        #[as_gd_res_types(name = "FloatReward", T = f32)]
+        pub enum Reward<T> {
+            Coins(CoinData<T>),
+            Nothing,
+        }
+    };
+    let actual = expand_as_gd_res(input, true);
+    let expected = quote! {
+        pub trait IntRewardResourceExtractVariant {
+            fn try_extract_enum_variant(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Reward<i32>, ::as_gd_res::ExtractError>;
+        }
+
+        type IntRewardResource =
+            ::godot::obj::DynGd<::godot::classes::Resource, dyn IntRewardResourceExtractVariant>;
+        impl ::as_gd_res::AsGdRes for Reward<i32> {
+            type ResType = ::godot::prelude::OnEditor<IntRewardResource>;
+        }
+
+        impl ::as_gd_res::AsGdResOpt for Reward<i32> {
+            type GdOption = Option<IntRewardResource>;
+        }
+
+        impl ::as_gd_res::AsGdResArray for Reward<i32> {
+            type GdArray = ::godot::prelude::Array<IntRewardResource>;
+        }
+
+        impl ::as_gd_res::ToGdRes for Reward<i32> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(<dyn IntRewardResourceExtractVariant>::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(
+                    self,
+                    res,
+                    <dyn IntRewardResourceExtractVariant>::from_rust,
+                    <dyn IntRewardResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResOpt for Reward<i32> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(<dyn IntRewardResourceExtractVariant>::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(
+                    value,
+                    res,
+                    <dyn IntRewardResourceExtractVariant>::from_rust,
+                    <dyn IntRewardResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResArray for Reward<i32> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values
+                    .into_iter()
+                    .map(<dyn IntRewardResourceExtractVariant>::from_rust)
+                    .collect()
+            }
+            fn apply_to_gd_array<'a>(
+                values: impl IntoIterator<Item = &'a Self>,
+                res: &mut Self::GdArray
+            ) -> bool {
+                ::as_gd_res::apply_to_array(
+                    values,
+                    res,
+                    <dyn IntRewardResourceExtractVariant>::from_rust,
+                    <dyn IntRewardResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Reward<i32> {
+            type GdElement = IntRewardResource;
+            fn to_gd_element(&self) -> Self::GdElement {
+                <dyn IntRewardResourceExtractVariant>::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                <dyn IntRewardResourceExtractVariant>::apply_rust(self, res)
+            }
+        }
+
+        ::as_gd_res::impl_from_gd_res_by_element!(Reward<i32>);
+        impl dyn IntRewardResourceExtractVariant {
+            /// Builds the resource for the variant held by `value`.
+            pub fn from_rust(value: &Reward<i32>) -> IntRewardResource {
+                match value {
+                    Reward::Coins(v) => ::godot::obj::Gd::into_dyn::<dyn IntRewardResourceExtractVariant>(
+                        mod_intreward_coins::PayloadResource::from_rust(v)
+                    )
+                    .upcast(),
+                    Reward::Nothing => ::godot::obj::Gd::into_dyn::<dyn IntRewardResourceExtractVariant>(
+                        ::godot::obj::Gd::<IntRewardNothingResource>::default()
+                    )
+                    .upcast(),
+                }
+            }
+            /// Writes `value` onto `res` in place if it already holds a resource for
+            /// the same variant, and replaces it otherwise. Returns `true` if `res`
+            /// was replaced.
+            pub fn apply_rust(value: &Reward<i32>, res: &mut IntRewardResource) -> bool {
+                match value {
+                    Reward::Coins(v) => {
+                        if let Ok(mut gd) = (**res)
+                            .clone()
+                            .try_cast::<mod_intreward_coins::PayloadResource>()
+                        {
+                            v.apply_to(&mut gd);
+                            return false;
+                        }
+                    }
+                    Reward::Nothing => {
+                        if (**res)
+                            .clone()
+                            .try_cast::<IntRewardNothingResource>()
+                            .is_ok()
+                        {
+                            return false;
+                        }
+                    }
+                }
+                *res = Self::from_rust(value);
+                true
+            }
+        }
+
+        impl ::as_gd_res::ExtractGd for dyn IntRewardResourceExtractVariant {
+            type Extracted = Reward<i32>;
+            fn extract(&self) -> Self::Extracted {
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for dyn IntRewardResourceExtractVariant {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(ctx)
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Reward<i32> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match res.type_name() {
+                    name if name == <CoinData<i32> as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Reward::Coins(
+                        ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?
+                    )),
+                    "IntRewardNothingResource" => Ok(Reward::Nothing),
+                    other => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a resource for any variant of `{}`",
+                        other, "Reward"
+                    ))
+                    .in_resource("Reward", &doc.resource_path(res))),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Reward<i32> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(
+                    value,
+                    <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+                )
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Reward<i32> {
+            fn tres_type_name(&self) -> String {
+                match self {
+                    Reward::Coins(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                    Reward::Nothing => "IntRewardNothingResource".to_string(),
+                }
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                match self {
+                    Reward::Coins(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                    Reward::Nothing => Vec::new(),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Reward<i32> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "Resource".to_string()
+            }
+        }
+
+        pub mod mod_intreward_coins {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub type PayloadResource = <CoinData<i32> as ::as_gd_res::AsGdResClass>::GdClass;
+            #[godot_dyn]
+            impl IntRewardResourceExtractVariant for PayloadResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Reward<i32>, ::as_gd_res::ExtractError> {
+                    Ok(Reward::Coins(::as_gd_res::TryExtractGd::try_extract_with(
+                        self, ctx
+                    )?))
+                }
+            }
+        }
+
+        #[derive(::godot::prelude::GodotClass)]
+        #[class(tool,init,base = Resource)]
+        pub struct IntRewardNothingResource {
+            #[base]
+            base: ::godot::obj::Base<::godot::classes::Resource>,
+        }
+
+        pub mod mod_intreward_nothing {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            #[godot_dyn]
+            impl IntRewardResourceExtractVariant for IntRewardNothingResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Reward<i32>, ::as_gd_res::ExtractError> {
+                    Ok(Reward::Nothing)
+                }
+            }
+        }
+
+        pub trait FloatRewardResourceExtractVariant {
+            fn try_extract_enum_variant(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Reward<f32>, ::as_gd_res::ExtractError>;
+        }
+
+        type FloatRewardResource =
+            ::godot::obj::DynGd<::godot::classes::Resource, dyn FloatRewardResourceExtractVariant>;
+        impl ::as_gd_res::AsGdRes for Reward<f32> {
+            type ResType = ::godot::prelude::OnEditor<FloatRewardResource>;
+        }
+
+        impl ::as_gd_res::AsGdResOpt for Reward<f32> {
+            type GdOption = Option<FloatRewardResource>;
+        }
+
+        impl ::as_gd_res::AsGdResArray for Reward<f32> {
+            type GdArray = ::godot::prelude::Array<FloatRewardResource>;
+        }
+
+        impl ::as_gd_res::ToGdRes for Reward<f32> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(<dyn FloatRewardResourceExtractVariant>::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(
+                    self,
+                    res,
+                    <dyn FloatRewardResourceExtractVariant>::from_rust,
+                    <dyn FloatRewardResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResOpt for Reward<f32> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(<dyn FloatRewardResourceExtractVariant>::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(
+                    value,
+                    res,
+                    <dyn FloatRewardResourceExtractVariant>::from_rust,
+                    <dyn FloatRewardResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::ToGdResArray for Reward<f32> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values
+                    .into_iter()
+                    .map(<dyn FloatRewardResourceExtractVariant>::from_rust)
+                    .collect()
+            }
+            fn apply_to_gd_array<'a>(
+                values: impl IntoIterator<Item = &'a Self>,
+                res: &mut Self::GdArray
+            ) -> bool {
+                ::as_gd_res::apply_to_array(
+                    values,
+                    res,
+                    <dyn FloatRewardResourceExtractVariant>::from_rust,
+                    <dyn FloatRewardResourceExtractVariant>::apply_rust
+                )
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Reward<f32> {
+            type GdElement = FloatRewardResource;
+            fn to_gd_element(&self) -> Self::GdElement {
+                <dyn FloatRewardResourceExtractVariant>::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                <dyn FloatRewardResourceExtractVariant>::apply_rust(self, res)
+            }
+        }
+
+        ::as_gd_res::impl_from_gd_res_by_element!(Reward<f32>);
+        impl dyn FloatRewardResourceExtractVariant {
+            /// Builds the resource for the variant held by `value`.
+            pub fn from_rust(value: &Reward<f32>) -> FloatRewardResource {
+                match value {
+                    Reward::Coins(v) => ::godot::obj::Gd::into_dyn::<dyn FloatRewardResourceExtractVariant>(
+                        mod_floatreward_coins::PayloadResource::from_rust(v)
+                    )
+                    .upcast(),
+                    Reward::Nothing => ::godot::obj::Gd::into_dyn::<dyn FloatRewardResourceExtractVariant>(
+                        ::godot::obj::Gd::<FloatRewardNothingResource>::default()
+                    )
+                    .upcast(),
+                }
+            }
+            /// Writes `value` onto `res` in place if it already holds a resource for
+            /// the same variant, and replaces it otherwise. Returns `true` if `res`
+            /// was replaced.
+            pub fn apply_rust(value: &Reward<f32>, res: &mut FloatRewardResource) -> bool {
+                match value {
+                    Reward::Coins(v) => {
+                        if let Ok(mut gd) = (**res)
+                            .clone()
+                            .try_cast::<mod_floatreward_coins::PayloadResource>()
+                        {
+                            v.apply_to(&mut gd);
+                            return false;
+                        }
+                    }
+                    Reward::Nothing => {
+                        if (**res)
+                            .clone()
+                            .try_cast::<FloatRewardNothingResource>()
+                            .is_ok()
+                        {
+                            return false;
+                        }
+                    }
+                }
+                *res = Self::from_rust(value);
+                true
+            }
+        }
+
+        impl ::as_gd_res::ExtractGd for dyn FloatRewardResourceExtractVariant {
+            type Extracted = Reward<f32>;
+            fn extract(&self) -> Self::Extracted {
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for dyn FloatRewardResourceExtractVariant {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_enum_variant(ctx)
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Reward<f32> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match res.type_name() {
+                    name if name == <CoinData<f32> as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Reward::Coins(
+                        ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?
+                    )),
+                    "FloatRewardNothingResource" => Ok(Reward::Nothing),
+                    other => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a resource for any variant of `{}`",
+                        other, "Reward"
+                    ))
+                    .in_resource("Reward", &doc.resource_path(res))),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Reward<f32> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(
+                    value,
+                    <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+                )
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Reward<f32> {
+            fn tres_type_name(&self) -> String {
+                match self {
+                    Reward::Coins(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                    Reward::Nothing => "FloatRewardNothingResource".to_string(),
+                }
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                match self {
+                    Reward::Coins(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                    Reward::Nothing => Vec::new(),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Reward<f32> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "Resource".to_string()
+            }
+        }
+
+        pub mod mod_floatreward_coins {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            pub type PayloadResource = <CoinData<f32> as ::as_gd_res::AsGdResClass>::GdClass;
+            #[godot_dyn]
+            impl FloatRewardResourceExtractVariant for PayloadResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Reward<f32>, ::as_gd_res::ExtractError> {
+                    Ok(Reward::Coins(::as_gd_res::TryExtractGd::try_extract_with(
+                        self, ctx
+                    )?))
+                }
+            }
+        }
+
+        #[derive(::godot::prelude::GodotClass)]
+        #[class(tool,init,base = Resource)]
+        pub struct FloatRewardNothingResource {
+            #[base]
+            base: ::godot::obj::Base<::godot::classes::Resource>,
+        }
+
+        pub mod mod_floatreward_nothing {
+            use super::*;
+            use ::godot::prelude::godot_dyn;
+            #[godot_dyn]
+            impl FloatRewardResourceExtractVariant for FloatRewardNothingResource {
+                fn try_extract_enum_variant(
+                    &self,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Reward<f32>, ::as_gd_res::ExtractError> {
+                    Ok(Reward::Nothing)
                 }
             }
         }
@@ -32,6 +32,9 @@ fn test_attr_pass_through() {
         impl ::as_gd_res::AsGdRes for DropParams2 {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<DropParams2Resource>>;
         }
+        impl ::as_gd_res::AsGdResClass for DropParams2 {
+            type GdClass = DropParams2Resource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for DropParams2 {
             type GdOption = Option<::godot::obj::Gd<DropParams2Resource>>;
@@ -41,6 +44,84 @@ fn test_attr_pass_through() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<DropParams2Resource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for DropParams2 {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(DropParams2Resource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, DropParams2Resource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for DropParams2 {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(DropParams2Resource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, DropParams2Resource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for DropParams2 {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(DropParams2Resource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, DropParams2Resource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for DropParams2 {
+            type GdElement = ::godot::obj::Gd<DropParams2Resource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                DropParams2Resource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(DropParams2);
+
+        impl DropParams2 {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<DropParams2Resource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.total_value, &mut bound.total_value)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.max_value_per_coin, &mut bound.max_value_per_coin)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.coin_scene_1, &mut bound.coin_scene_1)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.coin_scene_2, &mut bound.coin_scene_2)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.non_exported_field, &mut bound.non_exported_field)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
       #[derive(::godot::prelude::GodotClass)]
       #[class(tool,init,base=Resource)]
       pub struct DropParams2Resource {
@@ -68,18 +149,1005 @@ fn test_attr_pass_through() {
       impl ::as_gd_res::ExtractGd for DropParams2Resource {
           type Extracted = DropParams2;
           fn extract(&self) -> Self::Extracted {
-              use ::as_gd_res::ExtractGd;
-              Self::Extracted {
-                  total_value: self.total_value.extract(),
-                  max_value_per_coin: self.max_value_per_coin.extract(),
-                  coin_scene_1: self.coin_scene_1.extract(),
-                  coin_scene_2: self.coin_scene_2.extract(),
-                  non_exported_field: self.non_exported_field.extract(),
+              ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+          }
+      }
+
+      impl ::as_gd_res::TryExtractGd for DropParams2Resource {
+          fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+          }
+          fn try_extract_with(
+              &self,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                  Ok(Self::Extracted {
+                      total_value: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.total_value, ctx).map_err(|e| e.in_field("total_value"))?,
+                      max_value_per_coin: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.max_value_per_coin, ctx).map_err(|e| e.in_field("max_value_per_coin"))?,
+                      coin_scene_1: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.coin_scene_1, ctx).map_err(|e| e.in_field("coin_scene_1"))?,
+                      coin_scene_2: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.coin_scene_2, ctx).map_err(|e| e.in_field("coin_scene_2"))?,
+                      non_exported_field: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.non_exported_field, ctx).map_err(|e| e.in_field("non_exported_field"))?,
+                  })
+              })();
+              extracted.map_err(|e| {
+                  let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                  e.in_resource("DropParams2", &path)
+              })
+          }
+      }
+
+      impl DropParams2Resource {
+          /// Builds a new resource, including all sub-resources, from a Rust value.
+          pub fn from_rust(value: &DropParams2) -> ::godot::obj::Gd<Self> {
+              ::godot::obj::Gd::from_init_fn(|base| Self {
+                  base,
+                  total_value: ::as_gd_res::ToGdRes::to_gd_res(&value.total_value),
+                  max_value_per_coin: ::as_gd_res::ToGdRes::to_gd_res(&value.max_value_per_coin),
+                  coin_scene_1: ::as_gd_res::ToGdRes::to_gd_res(&value.coin_scene_1),
+                  coin_scene_2: ::as_gd_res::ToGdRes::to_gd_res(&value.coin_scene_2),
+                  non_exported_field: ::as_gd_res::ToGdRes::to_gd_res(&value.non_exported_field),
+              })
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTresResource for DropParams2 {
+          fn from_tres_resource(
+              res: &::as_gd_res::tres::TresResource,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                  res.expect_type("DropParams2Resource")?;
+                  Ok(Self {
+                      total_value: ::as_gd_res::tres::field_or_else(res, doc, "total_value", || 200.0.into())?,
+                      max_value_per_coin: ::as_gd_res::tres::field_or_else(res, doc, "max_value_per_coin", || 3.0.into())?,
+                      coin_scene_1: ::as_gd_res::tres::field(res, doc, "coin_scene_1")?,
+                      coin_scene_2: ::as_gd_res::tres::field(res, doc, "coin_scene_2")?,
+                      non_exported_field: ::as_gd_res::tres::field(res, doc, "non_exported_field")?,
+                  })
+              })();
+              extracted.map_err(|e| e.in_resource("DropParams2", &doc.resource_path(res)))
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTres for DropParams2 {
+          fn from_tres(
+              value: &::as_gd_res::tres::TresValue,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTresResource for DropParams2 {
+          fn tres_type_name(&self) -> String {
+              "DropParams2Resource".to_string()
+          }
+          fn to_tres_properties(
+              &self,
+              writer: &mut ::as_gd_res::tres::TresWriter,
+          ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+              vec![
+                  ("total_value".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.total_value, writer)),
+                  ("max_value_per_coin".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.max_value_per_coin, writer)),
+                  ("coin_scene_1".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.coin_scene_1, writer)),
+                  ("coin_scene_2".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.coin_scene_2, writer)),
+                  ("non_exported_field".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.non_exported_field, writer)),
+              ]
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTres for DropParams2 {
+          fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+              writer.add_sub_resource(self)
+          }
+          fn tres_type() -> String {
+              "DropParams2Resource".to_string()
+          }
+      }
+
+    };
+
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+// `#[as_gd_res(packed)]` stores a `Vec` of numbers as a packed array instead of a
+// typed `Array`.
+#[test]
+fn test_packed_attribute() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub struct ByteSamples {
+            a: Vec<f32>,
+            #[as_gd_res(packed)]
+            b: Vec<u8>,
+        }
+    };
+    let actual = expand_as_gd_res(input, true);
+    let expected = quote! {
+      impl ::as_gd_res::AsGdRes for ByteSamples {
+          type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<ByteSamplesResource>>;
+      }
+      impl ::as_gd_res::AsGdResClass for ByteSamples {
+          type GdClass = ByteSamplesResource;
+      }
+
+      impl ::as_gd_res::AsGdResOpt for ByteSamples {
+          type GdOption = Option<::godot::obj::Gd<ByteSamplesResource>>;
+      }
+
+      impl ::as_gd_res::AsGdResArray for ByteSamples {
+          type GdArray = ::godot::prelude::Array<::godot::obj::Gd<ByteSamplesResource>>;
+      }
+
+      impl ::as_gd_res::ToGdRes for ByteSamples {
+          fn to_gd_res(&self) -> Self::ResType {
+              ::as_gd_res::on_editor(ByteSamplesResource::from_rust(self))
+          }
+          fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+              ::as_gd_res::apply_to_on_editor(self, res, ByteSamplesResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+      impl ::as_gd_res::ToGdResOpt for ByteSamples {
+          fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+              value.map(ByteSamplesResource::from_rust)
+          }
+          fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+              ::as_gd_res::apply_to_option(value, res, ByteSamplesResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+      impl ::as_gd_res::ToGdResArray for ByteSamples {
+          fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+              values.into_iter().map(ByteSamplesResource::from_rust).collect()
+          }
+          fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+              ::as_gd_res::apply_to_array(values, res, ByteSamplesResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::AsGdResDictElement for ByteSamples {
+          type GdElement = ::godot::obj::Gd<ByteSamplesResource>;
+          fn to_gd_element(&self) -> Self::GdElement {
+              ByteSamplesResource::from_rust(self)
+          }
+          fn try_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+          }
+          fn try_arc_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+              ::as_gd_res::try_arc_from_object(element, ctx)
+          }
+          fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+              self.apply_to(res);
+              false
+          }
+      }
+      ::as_gd_res::impl_from_gd_res_by_element!(ByteSamples);
+
+      impl ByteSamples {
+          /// Writes `self` onto an existing resource in place, keeping nested
+          /// sub-resources where possible. `emit_changed()` is only called on
+          /// the resources whose own properties changed.
+          pub fn apply_to(&self, res: &mut ::godot::obj::Gd<ByteSamplesResource>) {
+              let changed = {
+                  let mut bound = res.bind_mut();
+                  false
+                  | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.a, &mut bound.a)
+                  | ::as_gd_res::apply_to_gd_packed_of(&self.b, &mut bound.b)
+              };
+              if changed {
+                  res.clone().upcast::<::godot::classes::Resource>().emit_changed();
               }
           }
       }
 
+      #[derive(::godot::prelude::GodotClass)]
+      #[class(tool,init,base=Resource)]
+      pub struct ByteSamplesResource {
+          #[base]
+          base: ::godot::obj::Base<::godot::classes::Resource>,
+          #[export]
+          pub a: <Vec<f32> as ::as_gd_res::AsGdRes>::ResType,
+          #[export]
+          pub b: ::as_gd_res::GdPackedOf<u8>,
+      }
+
+      impl ::as_gd_res::ExtractGd for ByteSamplesResource {
+          type Extracted = ByteSamples;
+          fn extract(&self) -> Self::Extracted {
+              ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+          }
+      }
+
+      impl ::as_gd_res::TryExtractGd for ByteSamplesResource {
+          fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+          }
+          fn try_extract_with(
+              &self,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                  Ok(Self::Extracted {
+                      a: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.a, ctx).map_err(|e| e.in_field("a"))?,
+                      b: ::as_gd_res::from_gd_packed_of(&self.b),
+                  })
+              })();
+              extracted.map_err(|e| {
+                  let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                  e.in_resource("ByteSamples", &path)
+              })
+          }
+      }
+
+      impl ByteSamplesResource {
+          /// Builds a new resource, including all sub-resources, from a Rust value.
+          pub fn from_rust(value: &ByteSamples) -> ::godot::obj::Gd<Self> {
+              ::godot::obj::Gd::from_init_fn(|base| Self {
+                  base,
+                  a: ::as_gd_res::ToGdRes::to_gd_res(&value.a),
+                  b: ::as_gd_res::to_gd_packed_of(&value.b),
+              })
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTresResource for ByteSamples {
+          fn from_tres_resource(
+              res: &::as_gd_res::tres::TresResource,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                  res.expect_type("ByteSamplesResource")?;
+                  Ok(Self {
+                      a: ::as_gd_res::tres::field(res, doc, "a")?,
+                      b: ::as_gd_res::tres::field(res, doc, "b")?,
+                  })
+              })();
+              extracted.map_err(|e| e.in_resource("ByteSamples", &doc.resource_path(res)))
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTres for ByteSamples {
+          fn from_tres(
+              value: &::as_gd_res::tres::TresValue,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTresResource for ByteSamples {
+          fn tres_type_name(&self) -> String {
+              "ByteSamplesResource".to_string()
+          }
+          fn to_tres_properties(
+              &self,
+              writer: &mut ::as_gd_res::tres::TresWriter,
+          ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+              vec![
+                  ("a".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.a, writer)),
+                  ("b".to_string(), ::as_gd_res::tres::packed_to_tres(&self.b, writer)),
+              ]
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTres for ByteSamples {
+          fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+              writer.add_sub_resource(self)
+          }
+          fn tres_type() -> String {
+              "ByteSamplesResource".to_string()
+          }
+      }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+// `[T; N]` fields start with `N` elements in a new resource. `#[as_gd_res(pad)]`
+// pads or truncates to `N` on extraction instead of failing.
+#[test]
+fn test_fixed_size_array_fields() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub struct Tiers {
+            pub weights: [f32; 4],
+            #[as_gd_res(pad)]
+            pub tiers: [SimpleData; 3],
+        }
     };
+    let actual = expand_as_gd_res(input, true);
+    let expected = quote! {
+      impl ::as_gd_res::AsGdRes for Tiers {
+          type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<TiersResource>>;
+      }
+      impl ::as_gd_res::AsGdResClass for Tiers {
+          type GdClass = TiersResource;
+      }
+
+      impl ::as_gd_res::AsGdResOpt for Tiers {
+          type GdOption = Option<::godot::obj::Gd<TiersResource>>;
+      }
+
+      impl ::as_gd_res::AsGdResArray for Tiers {
+          type GdArray = ::godot::prelude::Array<::godot::obj::Gd<TiersResource>>;
+      }
+
+      impl ::as_gd_res::ToGdRes for Tiers {
+          fn to_gd_res(&self) -> Self::ResType {
+              ::as_gd_res::on_editor(TiersResource::from_rust(self))
+          }
+          fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+              ::as_gd_res::apply_to_on_editor(self, res, TiersResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+      impl ::as_gd_res::ToGdResOpt for Tiers {
+          fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+              value.map(TiersResource::from_rust)
+          }
+          fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+              ::as_gd_res::apply_to_option(value, res, TiersResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::ToGdResArray for Tiers {
+          fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+              values.into_iter().map(TiersResource::from_rust).collect()
+          }
+          fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+              ::as_gd_res::apply_to_array(values, res, TiersResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::AsGdResDictElement for Tiers {
+          type GdElement = ::godot::obj::Gd<TiersResource>;
+          fn to_gd_element(&self) -> Self::GdElement {
+              TiersResource::from_rust(self)
+          }
+          fn try_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+          }
+          fn try_arc_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+              ::as_gd_res::try_arc_from_object(element, ctx)
+          }
+          fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+              self.apply_to(res);
+              false
+          }
+      }
+
+      ::as_gd_res::impl_from_gd_res_by_element!(Tiers);
+      impl Tiers {
+          /// Writes `self` onto an existing resource in place, keeping nested
+          /// sub-resources where possible. `emit_changed()` is only called on
+          /// the resources whose own properties changed.
+          pub fn apply_to(&self, res: &mut ::godot::obj::Gd<TiersResource>) {
+              let changed = {
+                  let mut bound = res.bind_mut();
+                  false
+                      | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.weights, &mut bound.weights)
+                      | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.tiers, &mut bound.tiers)
+              };
+              if changed {
+                  res.clone()
+                      .upcast::<::godot::classes::Resource>()
+                      .emit_changed();
+              }
+          }
+      }
+
+      #[derive(::godot::prelude::GodotClass)]
+      #[class(tool,init,base = Resource)]
+      pub struct TiersResource {
+          #[base]
+          base: ::godot::obj::Base<::godot::classes::Resource>,
+          #[export]
+          #[init(val = ::as_gd_res::sized_gd_array::<f32>(4))]
+          pub weights: <[f32; 4] as ::as_gd_res::AsGdRes>::ResType,
+          #[export]
+          #[init(val = ::as_gd_res::sized_gd_array::<SimpleData>(3))]
+          pub tiers: <[SimpleData; 3] as ::as_gd_res::AsGdRes>::ResType,
+      }
+
+      impl ::as_gd_res::ExtractGd for TiersResource {
+          type Extracted = Tiers;
+          fn extract(&self) -> Self::Extracted {
+              ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+          }
+      }
+
+      impl ::as_gd_res::TryExtractGd for TiersResource {
+          fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+          }
+          fn try_extract_with(
+              &self,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                  Ok(Self::Extracted {
+                      weights: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.weights, ctx)
+                          .map_err(|e| e.in_field("weights"))?,
+                      tiers: ::as_gd_res::pad_array(
+                          ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.tiers, ctx)
+                              .map_err(|e| e.in_field("tiers"))?
+                      ),
+                  })
+              })();
+              extracted.map_err(|e| {
+                  let path = ::godot::obj::WithBaseField::base(self)
+                      .get_path()
+                      .to_string();
+                  e.in_resource("Tiers", &path)
+              })
+          }
+      }
+
+      impl TiersResource {
+          /// Builds a new resource, including all sub-resources, from a Rust value.
+          pub fn from_rust(value: &Tiers) -> ::godot::obj::Gd<Self> {
+              ::godot::obj::Gd::from_init_fn(|base| Self {
+                  base,
+                  weights: ::as_gd_res::ToGdRes::to_gd_res(&value.weights),
+                  tiers: ::as_gd_res::ToGdRes::to_gd_res(&value.tiers),
+              })
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTresResource for Tiers {
+          fn from_tres_resource(
+              res: &::as_gd_res::tres::TresResource,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                  res.expect_type("TiersResource")?;
+                  Ok(Self {
+                      weights: ::as_gd_res::tres::field(res, doc, "weights")?,
+                      tiers: ::as_gd_res::pad_array(::as_gd_res::tres::field(res, doc, "tiers")?),
+                  })
+              })();
+              extracted.map_err(|e| e.in_resource("Tiers", &doc.resource_path(res)))
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTres for Tiers {
+          fn from_tres(
+              value: &::as_gd_res::tres::TresValue,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              doc.resolve(
+                  value,
+                  <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+              )
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTresResource for Tiers {
+          fn tres_type_name(&self) -> String {
+              "TiersResource".to_string()
+          }
+          fn to_tres_properties(
+              &self,
+              writer: &mut ::as_gd_res::tres::TresWriter,
+          ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+              vec![
+                  (
+                      "weights".to_string(),
+                      ::as_gd_res::tres::ToTres::to_tres(&self.weights, writer)
+                  ),
+                  (
+                      "tiers".to_string(),
+                      ::as_gd_res::tres::ToTres::to_tres(&self.tiers, writer)
+                  ),
+              ]
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTres for Tiers {
+          fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+              writer.add_sub_resource(self)
+          }
+          fn tres_type() -> String {
+              "TiersResource".to_string()
+          }
+      }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_tuple_fields() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub struct LootTable {
+            pub drops: Vec<(String, i32)>,
+            pub offset: (f32, f32),
+        }
+    };
+    let actual = expand_as_gd_res(input, true);
+    let expected = quote! {
+      impl ::as_gd_res::AsGdRes for LootTable {
+          type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<LootTableResource>>;
+      }
+      impl ::as_gd_res::AsGdResClass for LootTable {
+          type GdClass = LootTableResource;
+      }
+
+      impl ::as_gd_res::AsGdResOpt for LootTable {
+          type GdOption = Option<::godot::obj::Gd<LootTableResource>>;
+      }
+
+      impl ::as_gd_res::AsGdResArray for LootTable {
+          type GdArray = ::godot::prelude::Array<::godot::obj::Gd<LootTableResource>>;
+      }
+
+      impl ::as_gd_res::ToGdRes for LootTable {
+          fn to_gd_res(&self) -> Self::ResType {
+              ::as_gd_res::on_editor(LootTableResource::from_rust(self))
+          }
+          fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+              ::as_gd_res::apply_to_on_editor(self, res, LootTableResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::ToGdResOpt for LootTable {
+          fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+              value.map(LootTableResource::from_rust)
+          }
+          fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+              ::as_gd_res::apply_to_option(value, res, LootTableResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::ToGdResArray for LootTable {
+          fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+              values.into_iter().map(LootTableResource::from_rust).collect()
+          }
+          fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+              ::as_gd_res::apply_to_array(values, res, LootTableResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::AsGdResDictElement for LootTable {
+          type GdElement = ::godot::obj::Gd<LootTableResource>;
+          fn to_gd_element(&self) -> Self::GdElement {
+              LootTableResource::from_rust(self)
+          }
+          fn try_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+          }
+          fn try_arc_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+              ::as_gd_res::try_arc_from_object(element, ctx)
+          }
+          fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+              self.apply_to(res);
+              false
+          }
+      }
+
+      ::as_gd_res::impl_from_gd_res_by_element!(LootTable);
+      impl LootTable {
+          /// Writes `self` onto an existing resource in place, keeping nested
+          /// sub-resources where possible. `emit_changed()` is only called on
+          /// the resources whose own properties changed.
+          pub fn apply_to(&self, res: &mut ::godot::obj::Gd<LootTableResource>) {
+              let changed = {
+                  let mut bound = res.bind_mut();
+                  false
+                      | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.drops, &mut bound.drops)
+                      | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.offset, &mut bound.offset)
+              };
+              if changed {
+                  res.clone()
+                      .upcast::<::godot::classes::Resource>()
+                      .emit_changed();
+              }
+          }
+      }
+
+      #[derive(::godot::prelude::GodotClass)]
+      #[class(tool,init,base = Resource)]
+      pub struct LootTableResource {
+          #[base]
+          base: ::godot::obj::Base<::godot::classes::Resource>,
+          #[export]
+          pub drops: <Vec<(String, i32)> as ::as_gd_res::AsGdRes>::ResType,
+          #[export]
+          #[init(val = ::as_gd_res::sized_gd_tuple::<(f32, f32)>())]
+          pub offset: <(f32, f32) as ::as_gd_res::AsGdRes>::ResType,
+      }
+
+      impl ::as_gd_res::ExtractGd for LootTableResource {
+          type Extracted = LootTable;
+          fn extract(&self) -> Self::Extracted {
+              ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+          }
+      }
+
+      impl ::as_gd_res::TryExtractGd for LootTableResource {
+          fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+          }
+          fn try_extract_with(
+              &self,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                  Ok(Self::Extracted {
+                      drops: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.drops, ctx)
+                          .map_err(|e| e.in_field("drops"))?,
+                      offset: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.offset, ctx)
+                          .map_err(|e| e.in_field("offset"))?,
+                  })
+              })();
+              extracted.map_err(|e| {
+                  let path = ::godot::obj::WithBaseField::base(self)
+                      .get_path()
+                      .to_string();
+                  e.in_resource("LootTable", &path)
+              })
+          }
+      }
+
+      impl LootTableResource {
+          /// Builds a new resource, including all sub-resources, from a Rust value.
+          pub fn from_rust(value: &LootTable) -> ::godot::obj::Gd<Self> {
+              ::godot::obj::Gd::from_init_fn(|base| Self {
+                  base,
+                  drops: ::as_gd_res::ToGdRes::to_gd_res(&value.drops),
+                  offset: ::as_gd_res::ToGdRes::to_gd_res(&value.offset),
+              })
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTresResource for LootTable {
+          fn from_tres_resource(
+              res: &::as_gd_res::tres::TresResource,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                  res.expect_type("LootTableResource")?;
+                  Ok(Self {
+                      drops: ::as_gd_res::tres::field(res, doc, "drops")?,
+                      offset: ::as_gd_res::tres::field(res, doc, "offset")?,
+                  })
+              })();
+              extracted.map_err(|e| e.in_resource("LootTable", &doc.resource_path(res)))
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTres for LootTable {
+          fn from_tres(
+              value: &::as_gd_res::tres::TresValue,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              doc.resolve(
+                  value,
+                  <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+              )
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTresResource for LootTable {
+          fn tres_type_name(&self) -> String {
+              "LootTableResource".to_string()
+          }
+          fn to_tres_properties(
+              &self,
+              writer: &mut ::as_gd_res::tres::TresWriter,
+          ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+              vec![
+                  (
+                      "drops".to_string(),
+                      ::as_gd_res::tres::ToTres::to_tres(&self.drops, writer)
+                  ),
+                  (
+                      "offset".to_string(),
+                      ::as_gd_res::tres::ToTres::to_tres(&self.offset, writer)
+                  ),
+              ]
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTres for LootTable {
+          fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+              writer.add_sub_resource(self)
+          }
+          fn tres_type() -> String {
+              "LootTableResource".to_string()
+          }
+      }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+// `Option`s of numbers, `bool`s and strings are exported as a `{field}_enabled`
+// toggle plus a typed value property, which gets the field's attributes.
+#[test]
+fn test_optional_value_fields() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub struct Tuning {
+            #[export(range = (0.0, 10.0))]
+            speed: Option<f32>,
+            label: Option<String>,
+        }
+    };
+    let actual = expand_as_gd_res(input, true);
+    let expected = quote! {
+      impl ::as_gd_res::AsGdRes for Tuning {
+          type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<TuningResource>>;
+      }
+      impl ::as_gd_res::AsGdResClass for Tuning {
+          type GdClass = TuningResource;
+      }
+
+      impl ::as_gd_res::AsGdResOpt for Tuning {
+          type GdOption = Option<::godot::obj::Gd<TuningResource>>;
+      }
+
+      impl ::as_gd_res::AsGdResArray for Tuning {
+          type GdArray = ::godot::prelude::Array<::godot::obj::Gd<TuningResource>>;
+      }
+
+      impl ::as_gd_res::ToGdRes for Tuning {
+          fn to_gd_res(&self) -> Self::ResType {
+              ::as_gd_res::on_editor(TuningResource::from_rust(self))
+          }
+          fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+              ::as_gd_res::apply_to_on_editor(self, res, TuningResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::ToGdResOpt for Tuning {
+          fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+              value.map(TuningResource::from_rust)
+          }
+          fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+              ::as_gd_res::apply_to_option(value, res, TuningResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::ToGdResArray for Tuning {
+          fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+              values.into_iter().map(TuningResource::from_rust).collect()
+          }
+          fn apply_to_gd_array<'a>(
+              values: impl IntoIterator<Item = &'a Self>,
+              res: &mut Self::GdArray
+          ) -> bool {
+              ::as_gd_res::apply_to_array(values, res, TuningResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::AsGdResDictElement for Tuning {
+          type GdElement = ::godot::obj::Gd<TuningResource>;
+          fn to_gd_element(&self) -> Self::GdElement {
+              TuningResource::from_rust(self)
+          }
+          fn try_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+          }
+          fn try_arc_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+              ::as_gd_res::try_arc_from_object(element, ctx)
+          }
+          fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+              self.apply_to(res);
+              false
+          }
+      }
+
+      ::as_gd_res::impl_from_gd_res_by_element!(Tuning);
+      impl Tuning {
+          /// Writes `self` onto an existing resource in place, keeping nested
+          /// sub-resources where possible. `emit_changed()` is only called on
+          /// the resources whose own properties changed.
+          pub fn apply_to(&self, res: &mut ::godot::obj::Gd<TuningResource>) {
+              let changed = {
+                  let mut bound = res.bind_mut();
+                  false
+                      | ::as_gd_res::apply_to_gd_toggle(
+                          self.speed.as_ref(),
+                          &mut bound.speed_enabled,
+                          &mut bound.speed
+                      )
+                      | ::as_gd_res::apply_to_gd_toggle(
+                          self.label.as_ref(),
+                          &mut bound.label_enabled,
+                          &mut bound.label
+                      )
+              };
+              if changed {
+                  res.clone()
+                      .upcast::<::godot::classes::Resource>()
+                      .emit_changed();
+              }
+          }
+      }
+
+      #[derive(::godot::prelude::GodotClass)]
+      #[class(tool,init,base = Resource)]
+      pub struct TuningResource {
+          #[base]
+          base: ::godot::obj::Base<::godot::classes::Resource>,
+          #[export]
+          pub speed_enabled: bool,
+          #[export(range = (0.0, 10.0))]
+          pub speed: <f32 as ::as_gd_res::AsGdRes>::ResType,
+          #[export]
+          pub label_enabled: bool,
+          #[export]
+          pub label: <String as ::as_gd_res::AsGdRes>::ResType,
+      }
+
+      impl ::as_gd_res::ExtractGd for TuningResource {
+          type Extracted = Tuning;
+          fn extract(&self) -> Self::Extracted {
+              ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+          }
+      }
+
+      impl ::as_gd_res::TryExtractGd for TuningResource {
+          fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+          }
+          fn try_extract_with(
+              &self,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                  Ok(Self::Extracted {
+                      speed: ::as_gd_res::try_from_gd_toggle(self.speed_enabled, &self.speed, ctx)
+                          .map_err(|e| e.in_field("speed"))?,
+                      label: ::as_gd_res::try_from_gd_toggle(self.label_enabled, &self.label, ctx)
+                          .map_err(|e| e.in_field("label"))?,
+                  })
+              })();
+              extracted.map_err(|e| {
+                  let path = ::godot::obj::WithBaseField::base(self)
+                      .get_path()
+                      .to_string();
+                  e.in_resource("Tuning", &path)
+              })
+          }
+      }
+
+      impl TuningResource {
+          /// Builds a new resource, including all sub-resources, from a Rust value.
+          pub fn from_rust(value: &Tuning) -> ::godot::obj::Gd<Self> {
+              ::godot::obj::Gd::from_init_fn(|base| Self {
+                  base,
+                  speed_enabled: value.speed.is_some(),
+                  speed: ::as_gd_res::to_gd_toggle(value.speed.as_ref()),
+                  label_enabled: value.label.is_some(),
+                  label: ::as_gd_res::to_gd_toggle(value.label.as_ref()),
+              })
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTresResource for Tuning {
+          fn from_tres_resource(
+              res: &::as_gd_res::tres::TresResource,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                  res.expect_type("TuningResource")?;
+                  Ok(Self {
+                      speed: ::as_gd_res::tres::toggle_field(res, doc, "speed_enabled", "speed")?,
+                      label: ::as_gd_res::tres::toggle_field(res, doc, "label_enabled", "label")?,
+                  })
+              })();
+              extracted.map_err(|e| e.in_resource("Tuning", &doc.resource_path(res)))
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTres for Tuning {
+          fn from_tres(
+              value: &::as_gd_res::tres::TresValue,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              doc.resolve(
+                  value,
+                  <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+              )
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTresResource for Tuning {
+          fn tres_type_name(&self) -> String {
+              "TuningResource".to_string()
+          }
+          fn to_tres_properties(
+              &self,
+              writer: &mut ::as_gd_res::tres::TresWriter,
+          ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+              vec![
+                  (
+                      "speed_enabled".to_string(),
+                      ::as_gd_res::tres::ToTres::to_tres(&self.speed.is_some(), writer)
+                  ),
+                  (
+                      "speed".to_string(),
+                      ::as_gd_res::tres::toggle_to_tres(self.speed.as_ref(), writer)
+                  ),
+                  (
+                      "label_enabled".to_string(),
+                      ::as_gd_res::tres::ToTres::to_tres(&self.label.is_some(), writer)
+                  ),
+                  (
+                      "label".to_string(),
+                      ::as_gd_res::tres::toggle_to_tres(self.label.as_ref(), writer)
+                  ),
+              ]
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTres for Tuning {
+          fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+              writer.add_sub_resource(self)
+          }
+          fn tres_type() -> String {
+              "TuningResource".to_string()
+          }
+      }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
 }
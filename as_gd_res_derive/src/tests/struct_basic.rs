@@ -9,11 +9,14 @@ fn test_simple() {
             b: f32,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
       impl ::as_gd_res::AsGdRes for SimpleStructParams {
           type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<SimpleStructParamsResource>>;
       }
+      impl ::as_gd_res::AsGdResClass for SimpleStructParams {
+          type GdClass = SimpleStructParamsResource;
+      }
 
       impl ::as_gd_res::AsGdResOpt for SimpleStructParams {
           type GdOption = Option<::godot::obj::Gd<SimpleStructParamsResource>>;
@@ -23,6 +26,81 @@ fn test_simple() {
           type GdArray = ::godot::prelude::Array<::godot::obj::Gd<SimpleStructParamsResource>>;
       }
 
+      impl ::as_gd_res::ToGdRes for SimpleStructParams {
+          fn to_gd_res(&self) -> Self::ResType {
+              ::as_gd_res::on_editor(SimpleStructParamsResource::from_rust(self))
+          }
+          fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+              ::as_gd_res::apply_to_on_editor(self, res, SimpleStructParamsResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+      impl ::as_gd_res::ToGdResOpt for SimpleStructParams {
+          fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+              value.map(SimpleStructParamsResource::from_rust)
+          }
+          fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+              ::as_gd_res::apply_to_option(value, res, SimpleStructParamsResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+      impl ::as_gd_res::ToGdResArray for SimpleStructParams {
+          fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+              values.into_iter().map(SimpleStructParamsResource::from_rust).collect()
+          }
+          fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+              ::as_gd_res::apply_to_array(values, res, SimpleStructParamsResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::AsGdResDictElement for SimpleStructParams {
+          type GdElement = ::godot::obj::Gd<SimpleStructParamsResource>;
+          fn to_gd_element(&self) -> Self::GdElement {
+              SimpleStructParamsResource::from_rust(self)
+          }
+          fn try_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+          }
+          fn try_arc_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+              ::as_gd_res::try_arc_from_object(element, ctx)
+          }
+          fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+              self.apply_to(res);
+              false
+          }
+      }
+      ::as_gd_res::impl_from_gd_res_by_element!(SimpleStructParams);
+
+      impl SimpleStructParams {
+          /// Writes `self` onto an existing resource in place, keeping nested
+          /// sub-resources where possible. `emit_changed()` is only called on
+          /// the resources whose own properties changed.
+          pub fn apply_to(&self, res: &mut ::godot::obj::Gd<SimpleStructParamsResource>) {
+              let changed = {
+                  let mut bound = res.bind_mut();
+                  false
+                  | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.a, &mut bound.a)
+                  | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.b, &mut bound.b)
+              };
+              if changed {
+                  res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+              }
+          }
+      }
+
       #[derive(::godot::prelude::GodotClass)]
       #[class(tool,init,base=Resource)]
       pub struct SimpleStructParamsResource {
@@ -37,11 +115,88 @@ fn test_simple() {
       impl ::as_gd_res::ExtractGd for SimpleStructParamsResource {
           type Extracted = SimpleStructParams;
           fn extract(&self) -> Self::Extracted {
-              use ::as_gd_res::ExtractGd;
-              Self::Extracted {
-                  a: self.a.extract(),
-                  b: self.b.extract(),
-              }
+              ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+          }
+      }
+
+      impl ::as_gd_res::TryExtractGd for SimpleStructParamsResource {
+          fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+          }
+          fn try_extract_with(
+              &self,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                  Ok(Self::Extracted {
+                      a: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.a, ctx).map_err(|e| e.in_field("a"))?,
+                      b: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.b, ctx).map_err(|e| e.in_field("b"))?,
+                  })
+              })();
+              extracted.map_err(|e| {
+                  let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                  e.in_resource("SimpleStructParams", &path)
+              })
+          }
+      }
+
+      impl SimpleStructParamsResource {
+          /// Builds a new resource, including all sub-resources, from a Rust value.
+          pub fn from_rust(value: &SimpleStructParams) -> ::godot::obj::Gd<Self> {
+              ::godot::obj::Gd::from_init_fn(|base| Self {
+                  base,
+                  a: ::as_gd_res::ToGdRes::to_gd_res(&value.a),
+                  b: ::as_gd_res::ToGdRes::to_gd_res(&value.b),
+              })
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTresResource for SimpleStructParams {
+          fn from_tres_resource(
+              res: &::as_gd_res::tres::TresResource,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                  res.expect_type("SimpleStructParamsResource")?;
+                  Ok(Self {
+                      a: ::as_gd_res::tres::field(res, doc, "a")?,
+                      b: ::as_gd_res::tres::field(res, doc, "b")?,
+                  })
+              })();
+              extracted.map_err(|e| e.in_resource("SimpleStructParams", &doc.resource_path(res)))
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTres for SimpleStructParams {
+          fn from_tres(
+              value: &::as_gd_res::tres::TresValue,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTresResource for SimpleStructParams {
+          fn tres_type_name(&self) -> String {
+              "SimpleStructParamsResource".to_string()
+          }
+          fn to_tres_properties(
+              &self,
+              writer: &mut ::as_gd_res::tres::TresWriter,
+          ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+              vec![
+                  ("a".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.a, writer)),
+                  ("b".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.b, writer)),
+              ]
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTres for SimpleStructParams {
+          fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+              writer.add_sub_resource(self)
+          }
+          fn tres_type() -> String {
+              "SimpleStructParamsResource".to_string()
           }
       }
     };
@@ -58,12 +213,15 @@ fn test_2() {
             pub coin_scene_2: OnEditorInit<PackedScenePath>,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
 
             impl ::as_gd_res::AsGdRes for DropParams2 {
                 type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<DropParams2Resource>>;
             }
+            impl ::as_gd_res::AsGdResClass for DropParams2 {
+                type GdClass = DropParams2Resource;
+            }
 
             impl ::as_gd_res::AsGdResOpt for DropParams2 {
                 type GdOption = Option<::godot::obj::Gd<DropParams2Resource>>;
@@ -73,6 +231,83 @@ fn test_2() {
                 type GdArray = ::godot::prelude::Array<::godot::obj::Gd<DropParams2Resource>>;
             }
 
+            impl ::as_gd_res::ToGdRes for DropParams2 {
+                fn to_gd_res(&self) -> Self::ResType {
+                    ::as_gd_res::on_editor(DropParams2Resource::from_rust(self))
+                }
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    ::as_gd_res::apply_to_on_editor(self, res, DropParams2Resource::from_rust, |v, gd| {
+                        v.apply_to(gd);
+                        false
+                    })
+                }
+            }
+            impl ::as_gd_res::ToGdResOpt for DropParams2 {
+                fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                    value.map(DropParams2Resource::from_rust)
+                }
+                fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                    ::as_gd_res::apply_to_option(value, res, DropParams2Resource::from_rust, |v, gd| {
+                        v.apply_to(gd);
+                        false
+                    })
+                }
+            }
+            impl ::as_gd_res::ToGdResArray for DropParams2 {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    values.into_iter().map(DropParams2Resource::from_rust).collect()
+                }
+                fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                    ::as_gd_res::apply_to_array(values, res, DropParams2Resource::from_rust, |v, gd| {
+                        v.apply_to(gd);
+                        false
+                    })
+                }
+            }
+
+            impl ::as_gd_res::AsGdResDictElement for DropParams2 {
+                type GdElement = ::godot::obj::Gd<DropParams2Resource>;
+                fn to_gd_element(&self) -> Self::GdElement {
+                    DropParams2Resource::from_rust(self)
+                }
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+                }
+                fn try_arc_from_gd_element(
+                    element: &Self::GdElement,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::try_arc_from_object(element, ctx)
+                }
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    self.apply_to(res);
+                    false
+                }
+            }
+            ::as_gd_res::impl_from_gd_res_by_element!(DropParams2);
+
+            impl DropParams2 {
+                /// Writes `self` onto an existing resource in place, keeping nested
+                /// sub-resources where possible. `emit_changed()` is only called on
+                /// the resources whose own properties changed.
+                pub fn apply_to(&self, res: &mut ::godot::obj::Gd<DropParams2Resource>) {
+                    let changed = {
+                        let mut bound = res.bind_mut();
+                        false
+                        | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.total_value, &mut bound.total_value)
+                        | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.max_value_per_coin, &mut bound.max_value_per_coin)
+                        | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.coin_scene_1, &mut bound.coin_scene_1)
+                        | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.coin_scene_2, &mut bound.coin_scene_2)
+                    };
+                    if changed {
+                        res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                    }
+                }
+            }
+
             #[derive(::godot::prelude::GodotClass)]
             #[class(tool,init,base=Resource)]
 
@@ -92,13 +327,96 @@ fn test_2() {
             impl ::as_gd_res::ExtractGd for DropParams2Resource {
                 type Extracted = DropParams2;
                 fn extract(&self) -> Self::Extracted {
-                    use ::as_gd_res::ExtractGd;
-                    Self::Extracted {
-                        total_value: self.total_value.extract(),
-                        max_value_per_coin: self.max_value_per_coin.extract(),
-                        coin_scene_1: self.coin_scene_1.extract(),
-                        coin_scene_2: self.coin_scene_2.extract(),
-                    }
+                    ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+                }
+            }
+
+            impl ::as_gd_res::TryExtractGd for DropParams2Resource {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+                }
+                fn try_extract_with(
+                    &self,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                        Ok(Self::Extracted {
+                            total_value: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.total_value, ctx).map_err(|e| e.in_field("total_value"))?,
+                            max_value_per_coin: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.max_value_per_coin, ctx).map_err(|e| e.in_field("max_value_per_coin"))?,
+                            coin_scene_1: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.coin_scene_1, ctx).map_err(|e| e.in_field("coin_scene_1"))?,
+                            coin_scene_2: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.coin_scene_2, ctx).map_err(|e| e.in_field("coin_scene_2"))?,
+                        })
+                    })();
+                    extracted.map_err(|e| {
+                        let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                        e.in_resource("DropParams2", &path)
+                    })
+                }
+            }
+
+            impl DropParams2Resource {
+                /// Builds a new resource, including all sub-resources, from a Rust value.
+                pub fn from_rust(value: &DropParams2) -> ::godot::obj::Gd<Self> {
+                    ::godot::obj::Gd::from_init_fn(|base| Self {
+                        base,
+                        total_value: ::as_gd_res::ToGdRes::to_gd_res(&value.total_value),
+                        max_value_per_coin: ::as_gd_res::ToGdRes::to_gd_res(&value.max_value_per_coin),
+                        coin_scene_1: ::as_gd_res::ToGdRes::to_gd_res(&value.coin_scene_1),
+                        coin_scene_2: ::as_gd_res::ToGdRes::to_gd_res(&value.coin_scene_2),
+                    })
+                }
+            }
+
+            impl ::as_gd_res::tres::FromTresResource for DropParams2 {
+                fn from_tres_resource(
+                    res: &::as_gd_res::tres::TresResource,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                        res.expect_type("DropParams2Resource")?;
+                        Ok(Self {
+                            total_value: ::as_gd_res::tres::field(res, doc, "total_value")?,
+                            max_value_per_coin: ::as_gd_res::tres::field(res, doc, "max_value_per_coin")?,
+                            coin_scene_1: ::as_gd_res::tres::field(res, doc, "coin_scene_1")?,
+                            coin_scene_2: ::as_gd_res::tres::field(res, doc, "coin_scene_2")?,
+                        })
+                    })();
+                    extracted.map_err(|e| e.in_resource("DropParams2", &doc.resource_path(res)))
+                }
+            }
+
+            impl ::as_gd_res::tres::FromTres for DropParams2 {
+                fn from_tres(
+                    value: &::as_gd_res::tres::TresValue,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+                }
+            }
+
+            impl ::as_gd_res::tres::ToTresResource for DropParams2 {
+                fn tres_type_name(&self) -> String {
+                    "DropParams2Resource".to_string()
+                }
+                fn to_tres_properties(
+                    &self,
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                    vec![
+                        ("total_value".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.total_value, writer)),
+                        ("max_value_per_coin".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.max_value_per_coin, writer)),
+                        ("coin_scene_1".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.coin_scene_1, writer)),
+                        ("coin_scene_2".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.coin_scene_2, writer)),
+                    ]
+                }
+            }
+
+            impl ::as_gd_res::tres::ToTres for DropParams2 {
+                fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                    writer.add_sub_resource(self)
+                }
+                fn tres_type() -> String {
+                    "DropParams2Resource".to_string()
                 }
             }
 
@@ -13,12 +13,15 @@ fn test_complex_nested_struct() {
       }
     };
 
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
 
         impl ::as_gd_res::AsGdRes for EnemyParams {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<EnemyParamsResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for EnemyParams {
+            type GdClass = EnemyParamsResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for EnemyParams {
             type GdOption = Option<::godot::obj::Gd<EnemyParamsResource>>;
@@ -28,6 +31,84 @@ fn test_complex_nested_struct() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<EnemyParamsResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for EnemyParams {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(EnemyParamsResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, EnemyParamsResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for EnemyParams {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(EnemyParamsResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, EnemyParamsResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for EnemyParams {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(EnemyParamsResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, EnemyParamsResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for EnemyParams {
+            type GdElement = ::godot::obj::Gd<EnemyParamsResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                EnemyParamsResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(EnemyParams);
+
+        impl EnemyParams {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<EnemyParamsResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.brain_params_required, &mut bound.brain_params_required)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.brain_params_optional, &mut bound.brain_params_optional)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.brains_vec, &mut bound.brains_vec)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.drop_params, &mut bound.drop_params)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.damage_team, &mut bound.damage_team)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
 
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
@@ -52,14 +133,100 @@ fn test_complex_nested_struct() {
         impl ::as_gd_res::ExtractGd for EnemyParamsResource {
             type Extracted = EnemyParams;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    brain_params_required: self.brain_params_required.extract(),
-                    brain_params_optional: self.brain_params_optional.extract(),
-                    brains_vec: self.brains_vec.extract(),
-                    drop_params: self.drop_params.extract(),
-                    damage_team: self.damage_team.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for EnemyParamsResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        brain_params_required: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.brain_params_required, ctx).map_err(|e| e.in_field("brain_params_required"))?,
+                        brain_params_optional: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.brain_params_optional, ctx).map_err(|e| e.in_field("brain_params_optional"))?,
+                        brains_vec: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.brains_vec, ctx).map_err(|e| e.in_field("brains_vec"))?,
+                        drop_params: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.drop_params, ctx).map_err(|e| e.in_field("drop_params"))?,
+                        damage_team: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.damage_team, ctx).map_err(|e| e.in_field("damage_team"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("EnemyParams", &path)
+                })
+            }
+        }
+
+        impl EnemyParamsResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &EnemyParams) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    brain_params_required: ::as_gd_res::ToGdRes::to_gd_res(&value.brain_params_required),
+                    brain_params_optional: ::as_gd_res::ToGdRes::to_gd_res(&value.brain_params_optional),
+                    brains_vec: ::as_gd_res::ToGdRes::to_gd_res(&value.brains_vec),
+                    drop_params: ::as_gd_res::ToGdRes::to_gd_res(&value.drop_params),
+                    damage_team: ::as_gd_res::ToGdRes::to_gd_res(&value.damage_team),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for EnemyParams {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("EnemyParamsResource")?;
+                    Ok(Self {
+                        brain_params_required: ::as_gd_res::tres::field(res, doc, "brain_params_required")?,
+                        brain_params_optional: ::as_gd_res::tres::field(res, doc, "brain_params_optional")?,
+                        brains_vec: ::as_gd_res::tres::field(res, doc, "brains_vec")?,
+                        drop_params: ::as_gd_res::tres::field(res, doc, "drop_params")?,
+                        damage_team: ::as_gd_res::tres::field(res, doc, "damage_team")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("EnemyParams", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for EnemyParams {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for EnemyParams {
+            fn tres_type_name(&self) -> String {
+                "EnemyParamsResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("brain_params_required".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.brain_params_required, writer)),
+                    ("brain_params_optional".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.brain_params_optional, writer)),
+                    ("brains_vec".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.brains_vec, writer)),
+                    ("drop_params".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.drop_params, writer)),
+                    ("damage_team".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.damage_team, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for EnemyParams {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "EnemyParamsResource".to_string()
             }
         }
 
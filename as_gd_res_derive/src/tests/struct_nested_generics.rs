@@ -13,11 +13,14 @@ fn test_nested_generic_struct_fields() {
             pub nested: ChildStruct<T1, T2>,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for Parent<i32, String> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<ParentResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for Parent<i32, String> {
+            type GdClass = ParentResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for Parent<i32, String> {
             type GdOption = Option<::godot::obj::Gd<ParentResource>>;
@@ -27,6 +30,82 @@ fn test_nested_generic_struct_fields() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<ParentResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for Parent<i32, String> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(ParentResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for Parent<i32, String> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(ParentResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for Parent<i32, String> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(ParentResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Parent<i32, String> {
+            type GdElement = ::godot::obj::Gd<ParentResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                ParentResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(Parent<i32, String>);
+
+        impl Parent<i32, String> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<ParentResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field1, &mut bound.field1)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field2, &mut bound.field2)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.nested, &mut bound.nested)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct ParentResource {
@@ -43,12 +122,92 @@ fn test_nested_generic_struct_fields() {
         impl ::as_gd_res::ExtractGd for ParentResource {
             type Extracted = Parent<i32, String>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field1: self.field1.extract(),
-                    field2: self.field2.extract(),
-                    nested: self.nested.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for ParentResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field1: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field1, ctx).map_err(|e| e.in_field("field1"))?,
+                        field2: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field2, ctx).map_err(|e| e.in_field("field2"))?,
+                        nested: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.nested, ctx).map_err(|e| e.in_field("nested"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("Parent", &path)
+                })
+            }
+        }
+
+        impl ParentResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &Parent<i32, String>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field1: ::as_gd_res::ToGdRes::to_gd_res(&value.field1),
+                    field2: ::as_gd_res::ToGdRes::to_gd_res(&value.field2),
+                    nested: ::as_gd_res::ToGdRes::to_gd_res(&value.nested),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Parent<i32, String> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("ParentResource")?;
+                    Ok(Self {
+                        field1: ::as_gd_res::tres::field(res, doc, "field1")?,
+                        field2: ::as_gd_res::tres::field(res, doc, "field2")?,
+                        nested: ::as_gd_res::tres::field(res, doc, "nested")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("Parent", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Parent<i32, String> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Parent<i32, String> {
+            fn tres_type_name(&self) -> String {
+                "ParentResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field1".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field1, writer)),
+                    ("field2".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field2, writer)),
+                    ("nested".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.nested, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Parent<i32, String> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "ParentResource".to_string()
             }
         }
     };
@@ -67,11 +226,14 @@ fn test_multiple_nested_generic_struct_fields() {
             pub nested2: ChildB<T1, T2>,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
         impl ::as_gd_res::AsGdRes for Parent<i32, String> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<ParentResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for Parent<i32, String> {
+            type GdClass = ParentResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for Parent<i32, String> {
             type GdOption = Option<::godot::obj::Gd<ParentResource>>;
@@ -81,6 +243,83 @@ fn test_multiple_nested_generic_struct_fields() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<ParentResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for Parent<i32, String> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(ParentResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for Parent<i32, String> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(ParentResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for Parent<i32, String> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(ParentResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Parent<i32, String> {
+            type GdElement = ::godot::obj::Gd<ParentResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                ParentResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(Parent<i32, String>);
+
+        impl Parent<i32, String> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<ParentResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field1, &mut bound.field1)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field2, &mut bound.field2)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.nested1, &mut bound.nested1)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.nested2, &mut bound.nested2)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct ParentResource {
@@ -99,13 +338,96 @@ fn test_multiple_nested_generic_struct_fields() {
         impl ::as_gd_res::ExtractGd for ParentResource {
             type Extracted = Parent<i32, String>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field1: self.field1.extract(),
-                    field2: self.field2.extract(),
-                    nested1: self.nested1.extract(),
-                    nested2: self.nested2.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for ParentResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field1: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field1, ctx).map_err(|e| e.in_field("field1"))?,
+                        field2: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field2, ctx).map_err(|e| e.in_field("field2"))?,
+                        nested1: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.nested1, ctx).map_err(|e| e.in_field("nested1"))?,
+                        nested2: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.nested2, ctx).map_err(|e| e.in_field("nested2"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("Parent", &path)
+                })
+            }
+        }
+
+        impl ParentResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &Parent<i32, String>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field1: ::as_gd_res::ToGdRes::to_gd_res(&value.field1),
+                    field2: ::as_gd_res::ToGdRes::to_gd_res(&value.field2),
+                    nested1: ::as_gd_res::ToGdRes::to_gd_res(&value.nested1),
+                    nested2: ::as_gd_res::ToGdRes::to_gd_res(&value.nested2),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Parent<i32, String> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("ParentResource")?;
+                    Ok(Self {
+                        field1: ::as_gd_res::tres::field(res, doc, "field1")?,
+                        field2: ::as_gd_res::tres::field(res, doc, "field2")?,
+                        nested1: ::as_gd_res::tres::field(res, doc, "nested1")?,
+                        nested2: ::as_gd_res::tres::field(res, doc, "nested2")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("Parent", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Parent<i32, String> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Parent<i32, String> {
+            fn tres_type_name(&self) -> String {
+                "ParentResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field1".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field1, writer)),
+                    ("field2".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field2, writer)),
+                    ("nested1".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.nested1, writer)),
+                    ("nested2".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.nested2, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Parent<i32, String> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "ParentResource".to_string()
             }
         }
     };
@@ -124,7 +446,7 @@ fn test_option_wrapped_nested_generic() {
             pub nested_opt: Option<Child<T1, T2>>,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
 
     let opt_child_ty: syn::Type = parse_quote! { Option<Child<i32, String>> };
 
@@ -132,6 +454,9 @@ fn test_option_wrapped_nested_generic() {
         impl ::as_gd_res::AsGdRes for Parent<i32, String> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<ParentResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for Parent<i32, String> {
+            type GdClass = ParentResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for Parent<i32, String> {
             type GdOption = Option<::godot::obj::Gd<ParentResource>>;
@@ -141,6 +466,81 @@ fn test_option_wrapped_nested_generic() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<ParentResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for Parent<i32, String> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(ParentResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for Parent<i32, String> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(ParentResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for Parent<i32, String> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(ParentResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Parent<i32, String> {
+            type GdElement = ::godot::obj::Gd<ParentResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                ParentResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(Parent<i32, String>);
+
+        impl Parent<i32, String> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<ParentResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field1, &mut bound.field1)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.nested_opt, &mut bound.nested_opt)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct ParentResource {
@@ -155,11 +555,88 @@ fn test_option_wrapped_nested_generic() {
         impl ::as_gd_res::ExtractGd for ParentResource {
             type Extracted = Parent<i32, String>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field1: self.field1.extract(),
-                    nested_opt: self.nested_opt.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for ParentResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field1: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field1, ctx).map_err(|e| e.in_field("field1"))?,
+                        nested_opt: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.nested_opt, ctx).map_err(|e| e.in_field("nested_opt"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("Parent", &path)
+                })
+            }
+        }
+
+        impl ParentResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &Parent<i32, String>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field1: ::as_gd_res::ToGdRes::to_gd_res(&value.field1),
+                    nested_opt: ::as_gd_res::ToGdRes::to_gd_res(&value.nested_opt),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Parent<i32, String> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("ParentResource")?;
+                    Ok(Self {
+                        field1: ::as_gd_res::tres::field(res, doc, "field1")?,
+                        nested_opt: ::as_gd_res::tres::field(res, doc, "nested_opt")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("Parent", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Parent<i32, String> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Parent<i32, String> {
+            fn tres_type_name(&self) -> String {
+                "ParentResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field1".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field1, writer)),
+                    ("nested_opt".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.nested_opt, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Parent<i32, String> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "ParentResource".to_string()
             }
         }
     };
@@ -176,7 +653,7 @@ fn test_vec_wrapped_nested_generic() {
             pub items: Vec<Child<T>>,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
 
     let vec_child_ty: syn::Type = parse_quote! { Vec<Child<f64>> };
 
@@ -184,6 +661,9 @@ fn test_vec_wrapped_nested_generic() {
         impl ::as_gd_res::AsGdRes for Parent<f64> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<ParentResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for Parent<f64> {
+            type GdClass = ParentResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for Parent<f64> {
             type GdOption = Option<::godot::obj::Gd<ParentResource>>;
@@ -193,6 +673,80 @@ fn test_vec_wrapped_nested_generic() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<ParentResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for Parent<f64> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(ParentResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for Parent<f64> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(ParentResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for Parent<f64> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(ParentResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, ParentResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Parent<f64> {
+            type GdElement = ::godot::obj::Gd<ParentResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                ParentResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(Parent<f64>);
+
+        impl Parent<f64> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<ParentResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.items, &mut bound.items)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct ParentResource {
@@ -205,10 +759,84 @@ fn test_vec_wrapped_nested_generic() {
         impl ::as_gd_res::ExtractGd for ParentResource {
             type Extracted = Parent<f64>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    items: self.items.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for ParentResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        items: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.items, ctx).map_err(|e| e.in_field("items"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("Parent", &path)
+                })
+            }
+        }
+
+        impl ParentResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &Parent<f64>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    items: ::as_gd_res::ToGdRes::to_gd_res(&value.items),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for Parent<f64> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("ParentResource")?;
+                    Ok(Self {
+                        items: ::as_gd_res::tres::field(res, doc, "items")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("Parent", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Parent<f64> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Parent<f64> {
+            fn tres_type_name(&self) -> String {
+                "ParentResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("items".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.items, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Parent<f64> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "ParentResource".to_string()
             }
         }
     };
@@ -229,7 +857,7 @@ fn test_deeply_nested_generics() {
             pub nested2: Option<ChildB<T1, T2>>,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
 
     let opt_child_a_ty: syn::Type = parse_quote! { Option<ChildA<i32, String>> };
     let opt_child_b_ty: syn::Type = parse_quote! { Option<ChildB<i32, String>> };
@@ -238,6 +866,9 @@ fn test_deeply_nested_generics() {
         impl ::as_gd_res::AsGdRes for DeepNest<i32, String> {
             type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<DeepNestResource>>;
         }
+        impl ::as_gd_res::AsGdResClass for DeepNest<i32, String> {
+            type GdClass = DeepNestResource;
+        }
 
         impl ::as_gd_res::AsGdResOpt for DeepNest<i32, String> {
             type GdOption = Option<::godot::obj::Gd<DeepNestResource>>;
@@ -247,6 +878,83 @@ fn test_deeply_nested_generics() {
             type GdArray = ::godot::prelude::Array<::godot::obj::Gd<DeepNestResource>>;
         }
 
+        impl ::as_gd_res::ToGdRes for DeepNest<i32, String> {
+            fn to_gd_res(&self) -> Self::ResType {
+                ::as_gd_res::on_editor(DeepNestResource::from_rust(self))
+            }
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_to_on_editor(self, res, DeepNestResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResOpt for DeepNest<i32, String> {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                value.map(DeepNestResource::from_rust)
+            }
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_to_option(value, res, DeepNestResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+        impl ::as_gd_res::ToGdResArray for DeepNest<i32, String> {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                values.into_iter().map(DeepNestResource::from_rust).collect()
+            }
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_to_array(values, res, DeepNestResource::from_rust, |v, gd| {
+                    v.apply_to(gd);
+                    false
+                })
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for DeepNest<i32, String> {
+            type GdElement = ::godot::obj::Gd<DeepNestResource>;
+            fn to_gd_element(&self) -> Self::GdElement {
+                DeepNestResource::from_rust(self)
+            }
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+            }
+            fn try_arc_from_gd_element(
+                element: &Self::GdElement,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::try_arc_from_object(element, ctx)
+            }
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                self.apply_to(res);
+                false
+            }
+        }
+        ::as_gd_res::impl_from_gd_res_by_element!(DeepNest<i32, String>);
+
+        impl DeepNest<i32, String> {
+            /// Writes `self` onto an existing resource in place, keeping nested
+            /// sub-resources where possible. `emit_changed()` is only called on
+            /// the resources whose own properties changed.
+            pub fn apply_to(&self, res: &mut ::godot::obj::Gd<DeepNestResource>) {
+                let changed = {
+                    let mut bound = res.bind_mut();
+                    false
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field1, &mut bound.field1)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field2, &mut bound.field2)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.nested1, &mut bound.nested1)
+                    | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.nested2, &mut bound.nested2)
+                };
+                if changed {
+                    res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+                }
+            }
+        }
+
         #[derive(::godot::prelude::GodotClass)]
         #[class(tool,init,base=Resource)]
         pub struct DeepNestResource {
@@ -265,13 +973,96 @@ fn test_deeply_nested_generics() {
         impl ::as_gd_res::ExtractGd for DeepNestResource {
             type Extracted = DeepNest<i32, String>;
             fn extract(&self) -> Self::Extracted {
-                use ::as_gd_res::ExtractGd;
-                Self::Extracted {
-                    field1: self.field1.extract(),
-                    field2: self.field2.extract(),
-                    nested1: self.nested1.extract(),
-                    nested2: self.nested2.extract(),
-                }
+                ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for DeepNestResource {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+            }
+            fn try_extract_with(
+                &self,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(Self::Extracted {
+                        field1: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field1, ctx).map_err(|e| e.in_field("field1"))?,
+                        field2: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field2, ctx).map_err(|e| e.in_field("field2"))?,
+                        nested1: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.nested1, ctx).map_err(|e| e.in_field("nested1"))?,
+                        nested2: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.nested2, ctx).map_err(|e| e.in_field("nested2"))?,
+                    })
+                })();
+                extracted.map_err(|e| {
+                    let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                    e.in_resource("DeepNest", &path)
+                })
+            }
+        }
+
+        impl DeepNestResource {
+            /// Builds a new resource, including all sub-resources, from a Rust value.
+            pub fn from_rust(value: &DeepNest<i32, String>) -> ::godot::obj::Gd<Self> {
+                ::godot::obj::Gd::from_init_fn(|base| Self {
+                    base,
+                    field1: ::as_gd_res::ToGdRes::to_gd_res(&value.field1),
+                    field2: ::as_gd_res::ToGdRes::to_gd_res(&value.field2),
+                    nested1: ::as_gd_res::ToGdRes::to_gd_res(&value.nested1),
+                    nested2: ::as_gd_res::ToGdRes::to_gd_res(&value.nested2),
+                })
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTresResource for DeepNest<i32, String> {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                    res.expect_type("DeepNestResource")?;
+                    Ok(Self {
+                        field1: ::as_gd_res::tres::field(res, doc, "field1")?,
+                        field2: ::as_gd_res::tres::field(res, doc, "field2")?,
+                        nested1: ::as_gd_res::tres::field(res, doc, "nested1")?,
+                        nested2: ::as_gd_res::tres::field(res, doc, "nested2")?,
+                    })
+                })();
+                extracted.map_err(|e| e.in_resource("DeepNest", &doc.resource_path(res)))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for DeepNest<i32, String> {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for DeepNest<i32, String> {
+            fn tres_type_name(&self) -> String {
+                "DeepNestResource".to_string()
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                vec![
+                    ("field1".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field1, writer)),
+                    ("field2".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field2, writer)),
+                    ("nested1".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.nested1, writer)),
+                    ("nested2".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.nested2, writer)),
+                ]
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for DeepNest<i32, String> {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "DeepNestResource".to_string()
             }
         }
     };
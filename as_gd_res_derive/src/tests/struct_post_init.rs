@@ -51,12 +51,98 @@ fn test_post_init_attr() {
     impl ::as_gd_res::AsGdRes for JumpParams {
         type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<JumpParamsResource>>;
     }
+    impl ::as_gd_res::AsGdResClass for JumpParams {
+        type GdClass = JumpParamsResource;
+    }
     impl ::as_gd_res::AsGdResOpt for JumpParams {
         type GdOption = Option<::godot::obj::Gd<JumpParamsResource>>;
     }
     impl ::as_gd_res::AsGdResArray for JumpParams {
         type GdArray = ::godot::prelude::Array<::godot::obj::Gd<JumpParamsResource>>;
     }
+
+    impl ::as_gd_res::ToGdRes for JumpParams {
+        fn to_gd_res(&self) -> Self::ResType {
+            ::as_gd_res::on_editor(JumpParamsResource::from_rust(self))
+        }
+        fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+            ::as_gd_res::apply_to_on_editor(self, res, JumpParamsResource::from_rust, |v, gd| {
+                v.apply_to(gd);
+                false
+            })
+        }
+    }
+    impl ::as_gd_res::ToGdResOpt for JumpParams {
+        fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+            value.map(JumpParamsResource::from_rust)
+        }
+        fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+            ::as_gd_res::apply_to_option(value, res, JumpParamsResource::from_rust, |v, gd| {
+                v.apply_to(gd);
+                false
+            })
+        }
+    }
+    impl ::as_gd_res::ToGdResArray for JumpParams {
+        fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+            values.into_iter().map(JumpParamsResource::from_rust).collect()
+        }
+        fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+            ::as_gd_res::apply_to_array(values, res, JumpParamsResource::from_rust, |v, gd| {
+                v.apply_to(gd);
+                false
+            })
+        }
+    }
+
+    impl ::as_gd_res::AsGdResDictElement for JumpParams {
+        type GdElement = ::godot::obj::Gd<JumpParamsResource>;
+        fn to_gd_element(&self) -> Self::GdElement {
+            JumpParamsResource::from_rust(self)
+        }
+        fn try_from_gd_element(
+            element: &Self::GdElement,
+            ctx: &mut ::as_gd_res::ExtractContext,
+        ) -> Result<Self, ::as_gd_res::ExtractError> {
+            ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+        }
+        fn try_arc_from_gd_element(
+            element: &Self::GdElement,
+            ctx: &mut ::as_gd_res::ExtractContext,
+        ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+            ::as_gd_res::try_arc_from_object(element, ctx)
+        }
+        fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+            self.apply_to(res);
+            false
+        }
+    }
+    ::as_gd_res::impl_from_gd_res_by_element!(JumpParams);
+
+    impl JumpParams {
+        /// Writes `self` onto an existing resource in place, keeping nested
+        /// sub-resources where possible. `emit_changed()` is only called on
+        /// the resources whose own properties changed.
+        pub fn apply_to(&self, res: &mut ::godot::obj::Gd<JumpParamsResource>) {
+            let changed = {
+                let mut bound = res.bind_mut();
+                false
+                | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.height, &mut bound.height)
+                | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.time_up, &mut bound.time_up)
+                | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.time_down, &mut bound.time_down)
+                | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.jump_vel_end_cut, &mut bound.jump_vel_end_cut)
+                | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.terminal_vel_fall_mult, &mut bound.terminal_vel_fall_mult)
+                | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.jump_vel, &mut bound.jump_vel)
+                | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.grav_ascent_acc, &mut bound.grav_ascent_acc)
+                | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.grav_falling_acc, &mut bound.grav_falling_acc)
+                | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.jump_landing_vel, &mut bound.jump_landing_vel)
+                | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.terminal_vel, &mut bound.terminal_vel)
+            };
+            if changed {
+                res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+            }
+        }
+    }
     #[derive(::godot::prelude::GodotClass)]
     // NOTE: `#[as_gd_res(post_init = ...)]` means we do not use the "init" flag in the "#[class(...)]" attribute
     #[class(tool,base = Resource)]
@@ -102,19 +188,120 @@ fn test_post_init_attr() {
     impl ::as_gd_res::ExtractGd for JumpParamsResource {
         type Extracted = JumpParams;
         fn extract(&self) -> Self::Extracted {
-            use ::as_gd_res::ExtractGd;
-            Self::Extracted {
-                height: self.height.extract(),
-                time_up: self.time_up.extract(),
-                time_down: self.time_down.extract(),
-                jump_vel_end_cut: self.jump_vel_end_cut.extract(),
-                terminal_vel_fall_mult: self.terminal_vel_fall_mult.extract(),
-                jump_vel: self.jump_vel.extract(),
-                grav_ascent_acc: self.grav_ascent_acc.extract(),
-                grav_falling_acc: self.grav_falling_acc.extract(),
-                jump_landing_vel: self.jump_landing_vel.extract(),
-                terminal_vel: self.terminal_vel.extract(),
-            }
+            ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+        }
+    }
+
+    impl ::as_gd_res::TryExtractGd for JumpParamsResource {
+        fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+            self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+        }
+        fn try_extract_with(
+            &self,
+            ctx: &mut ::as_gd_res::ExtractContext,
+        ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+            let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                Ok(Self::Extracted {
+                    height: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.height, ctx).map_err(|e| e.in_field("height"))?,
+                    time_up: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.time_up, ctx).map_err(|e| e.in_field("time_up"))?,
+                    time_down: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.time_down, ctx).map_err(|e| e.in_field("time_down"))?,
+                    jump_vel_end_cut: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.jump_vel_end_cut, ctx).map_err(|e| e.in_field("jump_vel_end_cut"))?,
+                    terminal_vel_fall_mult: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.terminal_vel_fall_mult, ctx).map_err(|e| e.in_field("terminal_vel_fall_mult"))?,
+                    jump_vel: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.jump_vel, ctx).map_err(|e| e.in_field("jump_vel"))?,
+                    grav_ascent_acc: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.grav_ascent_acc, ctx).map_err(|e| e.in_field("grav_ascent_acc"))?,
+                    grav_falling_acc: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.grav_falling_acc, ctx).map_err(|e| e.in_field("grav_falling_acc"))?,
+                    jump_landing_vel: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.jump_landing_vel, ctx).map_err(|e| e.in_field("jump_landing_vel"))?,
+                    terminal_vel: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.terminal_vel, ctx).map_err(|e| e.in_field("terminal_vel"))?,
+                })
+            })();
+            extracted.map_err(|e| {
+                let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                e.in_resource("JumpParams", &path)
+            })
+        }
+    }
+
+    impl JumpParamsResource {
+        /// Builds a new resource, including all sub-resources, from a Rust value.
+        pub fn from_rust(value: &JumpParams) -> ::godot::obj::Gd<Self> {
+            ::godot::obj::Gd::from_init_fn(|base| Self {
+                base,
+                height: ::as_gd_res::ToGdRes::to_gd_res(&value.height),
+                time_up: ::as_gd_res::ToGdRes::to_gd_res(&value.time_up),
+                time_down: ::as_gd_res::ToGdRes::to_gd_res(&value.time_down),
+                jump_vel_end_cut: ::as_gd_res::ToGdRes::to_gd_res(&value.jump_vel_end_cut),
+                terminal_vel_fall_mult: ::as_gd_res::ToGdRes::to_gd_res(&value.terminal_vel_fall_mult),
+                jump_vel: ::as_gd_res::ToGdRes::to_gd_res(&value.jump_vel),
+                grav_ascent_acc: ::as_gd_res::ToGdRes::to_gd_res(&value.grav_ascent_acc),
+                grav_falling_acc: ::as_gd_res::ToGdRes::to_gd_res(&value.grav_falling_acc),
+                jump_landing_vel: ::as_gd_res::ToGdRes::to_gd_res(&value.jump_landing_vel),
+                terminal_vel: ::as_gd_res::ToGdRes::to_gd_res(&value.terminal_vel),
+            })
+        }
+    }
+
+    impl ::as_gd_res::tres::FromTresResource for JumpParams {
+        fn from_tres_resource(
+            res: &::as_gd_res::tres::TresResource,
+            doc: &::as_gd_res::tres::TresDocument,
+        ) -> Result<Self, ::as_gd_res::ExtractError> {
+            let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                res.expect_type("JumpParamsResource")?;
+                Ok(Self {
+                    height: ::as_gd_res::tres::field_or_else(res, doc, "height", || 3.5.into())?,
+                    time_up: ::as_gd_res::tres::field_or_else(res, doc, "time_up", || 0.5.into())?,
+                    time_down: ::as_gd_res::tres::field_or_else(res, doc, "time_down", || 0.4.into())?,
+                    jump_vel_end_cut: ::as_gd_res::tres::field_or_else(res, doc, "jump_vel_end_cut", || 0.25.into())?,
+                    terminal_vel_fall_mult: ::as_gd_res::tres::field_or_else(res, doc, "terminal_vel_fall_mult", || 1.2.into())?,
+                    jump_vel: ::as_gd_res::tres::field(res, doc, "jump_vel")?,
+                    grav_ascent_acc: ::as_gd_res::tres::field(res, doc, "grav_ascent_acc")?,
+                    grav_falling_acc: ::as_gd_res::tres::field(res, doc, "grav_falling_acc")?,
+                    jump_landing_vel: ::as_gd_res::tres::field(res, doc, "jump_landing_vel")?,
+                    terminal_vel: ::as_gd_res::tres::field(res, doc, "terminal_vel")?,
+                })
+            })();
+            extracted.map_err(|e| e.in_resource("JumpParams", &doc.resource_path(res)))
+        }
+    }
+
+    impl ::as_gd_res::tres::FromTres for JumpParams {
+        fn from_tres(
+            value: &::as_gd_res::tres::TresValue,
+            doc: &::as_gd_res::tres::TresDocument,
+        ) -> Result<Self, ::as_gd_res::ExtractError> {
+            doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+        }
+    }
+
+    impl ::as_gd_res::tres::ToTresResource for JumpParams {
+        fn tres_type_name(&self) -> String {
+            "JumpParamsResource".to_string()
+        }
+        fn to_tres_properties(
+            &self,
+            writer: &mut ::as_gd_res::tres::TresWriter,
+        ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+            vec![
+                ("height".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.height, writer)),
+                ("time_up".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.time_up, writer)),
+                ("time_down".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.time_down, writer)),
+                ("jump_vel_end_cut".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.jump_vel_end_cut, writer)),
+                ("terminal_vel_fall_mult".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.terminal_vel_fall_mult, writer)),
+                ("jump_vel".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.jump_vel, writer)),
+                ("grav_ascent_acc".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.grav_ascent_acc, writer)),
+                ("grav_falling_acc".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.grav_falling_acc, writer)),
+                ("jump_landing_vel".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.jump_landing_vel, writer)),
+                ("terminal_vel".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.terminal_vel, writer)),
+            ]
+        }
+    }
+
+    impl ::as_gd_res::tres::ToTres for JumpParams {
+        fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+            writer.add_sub_resource(self)
+        }
+        fn tres_type() -> String {
+            "JumpParamsResource".to_string()
         }
     }
 
@@ -154,7 +341,7 @@ fn test_post_init_attr() {
     }
         };
 
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
 
     assert_eq!(actual.to_string(), expected.to_string());
 }
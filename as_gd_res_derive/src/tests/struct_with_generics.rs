@@ -10,11 +10,14 @@ fn test_struct_with_generics() {
             pub field2: T2,
         }
     };
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     let expected = quote! {
       impl ::as_gd_res::AsGdRes for StructWithGenerics<i32, String> {
           type ResType = ::godot::prelude::OnEditor<::godot::obj::Gd<StructWithGenericsResource>>;
       }
+      impl ::as_gd_res::AsGdResClass for StructWithGenerics<i32, String> {
+          type GdClass = StructWithGenericsResource;
+      }
 
       impl ::as_gd_res::AsGdResOpt for StructWithGenerics<i32, String> {
           type GdOption = Option<::godot::obj::Gd<StructWithGenericsResource>>;
@@ -24,6 +27,81 @@ fn test_struct_with_generics() {
           type GdArray = ::godot::prelude::Array<::godot::obj::Gd<StructWithGenericsResource>>;
       }
 
+      impl ::as_gd_res::ToGdRes for StructWithGenerics<i32, String> {
+          fn to_gd_res(&self) -> Self::ResType {
+              ::as_gd_res::on_editor(StructWithGenericsResource::from_rust(self))
+          }
+          fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+              ::as_gd_res::apply_to_on_editor(self, res, StructWithGenericsResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+      impl ::as_gd_res::ToGdResOpt for StructWithGenerics<i32, String> {
+          fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+              value.map(StructWithGenericsResource::from_rust)
+          }
+          fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+              ::as_gd_res::apply_to_option(value, res, StructWithGenericsResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+      impl ::as_gd_res::ToGdResArray for StructWithGenerics<i32, String> {
+          fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+              values.into_iter().map(StructWithGenericsResource::from_rust).collect()
+          }
+          fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+              ::as_gd_res::apply_to_array(values, res, StructWithGenericsResource::from_rust, |v, gd| {
+                  v.apply_to(gd);
+                  false
+              })
+          }
+      }
+
+      impl ::as_gd_res::AsGdResDictElement for StructWithGenerics<i32, String> {
+          type GdElement = ::godot::obj::Gd<StructWithGenericsResource>;
+          fn to_gd_element(&self) -> Self::GdElement {
+              StructWithGenericsResource::from_rust(self)
+          }
+          fn try_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              ::as_gd_res::TryExtractGd::try_extract_with(element, ctx)
+          }
+          fn try_arc_from_gd_element(
+              element: &Self::GdElement,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<::std::sync::Arc<Self>, ::as_gd_res::ExtractError> {
+              ::as_gd_res::try_arc_from_object(element, ctx)
+          }
+          fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+              self.apply_to(res);
+              false
+          }
+      }
+      ::as_gd_res::impl_from_gd_res_by_element!(StructWithGenerics<i32, String>);
+
+      impl StructWithGenerics<i32, String> {
+          /// Writes `self` onto an existing resource in place, keeping nested
+          /// sub-resources where possible. `emit_changed()` is only called on
+          /// the resources whose own properties changed.
+          pub fn apply_to(&self, res: &mut ::godot::obj::Gd<StructWithGenericsResource>) {
+              let changed = {
+                  let mut bound = res.bind_mut();
+                  false
+                  | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field1, &mut bound.field1)
+                  | ::as_gd_res::ToGdRes::apply_to_gd_res(&self.field2, &mut bound.field2)
+              };
+              if changed {
+                  res.clone().upcast::<::godot::classes::Resource>().emit_changed();
+              }
+          }
+      }
+
       #[derive(::godot::prelude::GodotClass)]
       #[class(tool,init,base=Resource)]
       pub struct StructWithGenericsResource {
@@ -38,11 +116,88 @@ fn test_struct_with_generics() {
       impl ::as_gd_res::ExtractGd for StructWithGenericsResource {
           type Extracted = StructWithGenerics<i32, String>;
           fn extract(&self) -> Self::Extracted {
-              use ::as_gd_res::ExtractGd;
-              Self::Extracted {
-                  field1: self.field1.extract(),
-                  field2: self.field2.extract(),
-              }
+              ::as_gd_res::TryExtractGd::try_extract(self).unwrap_or_else(|e| panic!("{}", e))
+          }
+      }
+
+      impl ::as_gd_res::TryExtractGd for StructWithGenericsResource {
+          fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              self.try_extract_with(&mut ::as_gd_res::ExtractContext::new())
+          }
+          fn try_extract_with(
+              &self,
+              ctx: &mut ::as_gd_res::ExtractContext,
+          ) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                  Ok(Self::Extracted {
+                      field1: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field1, ctx).map_err(|e| e.in_field("field1"))?,
+                      field2: ::as_gd_res::FromGdRes::try_from_gd_res_with(&self.field2, ctx).map_err(|e| e.in_field("field2"))?,
+                  })
+              })();
+              extracted.map_err(|e| {
+                  let path = ::godot::obj::WithBaseField::base(self).get_path().to_string();
+                  e.in_resource("StructWithGenerics", &path)
+              })
+          }
+      }
+
+      impl StructWithGenericsResource {
+          /// Builds a new resource, including all sub-resources, from a Rust value.
+          pub fn from_rust(value: &StructWithGenerics<i32, String>) -> ::godot::obj::Gd<Self> {
+              ::godot::obj::Gd::from_init_fn(|base| Self {
+                  base,
+                  field1: ::as_gd_res::ToGdRes::to_gd_res(&value.field1),
+                  field2: ::as_gd_res::ToGdRes::to_gd_res(&value.field2),
+              })
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTresResource for StructWithGenerics<i32, String> {
+          fn from_tres_resource(
+              res: &::as_gd_res::tres::TresResource,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                  res.expect_type("StructWithGenericsResource")?;
+                  Ok(Self {
+                      field1: ::as_gd_res::tres::field(res, doc, "field1")?,
+                      field2: ::as_gd_res::tres::field(res, doc, "field2")?,
+                  })
+              })();
+              extracted.map_err(|e| e.in_resource("StructWithGenerics", &doc.resource_path(res)))
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTres for StructWithGenerics<i32, String> {
+          fn from_tres(
+              value: &::as_gd_res::tres::TresValue,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTresResource for StructWithGenerics<i32, String> {
+          fn tres_type_name(&self) -> String {
+              "StructWithGenericsResource".to_string()
+          }
+          fn to_tres_properties(
+              &self,
+              writer: &mut ::as_gd_res::tres::TresWriter,
+          ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+              vec![
+                  ("field1".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field1, writer)),
+                  ("field2".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.field2, writer)),
+              ]
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTres for StructWithGenerics<i32, String> {
+          fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+              writer.add_sub_resource(self)
+          }
+          fn tres_type() -> String {
+              "StructWithGenericsResource".to_string()
           }
       }
     };
@@ -0,0 +1,513 @@
+use super::expand_as_gd_res;
+use super::{assert_eq, parse_quote, quote};
+
+#[test]
+fn test_struct_without_godot() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub struct JumpParams {
+            #[export(range = (0.0, 10.0))]
+            #[init(val = 2.5)]
+            pub height: f32,
+            pub landing: Option<PackedScenePath>,
+        }
+    };
+    let actual = expand_as_gd_res(input, false);
+    let expected = quote! {
+      impl ::as_gd_res::tres::FromTresResource for JumpParams {
+          fn from_tres_resource(
+              res: &::as_gd_res::tres::TresResource,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                  res.expect_type("JumpParamsResource")?;
+                  Ok(Self {
+                      height: ::as_gd_res::tres::field_or_else(res, doc, "height", || 2.5.into())?,
+                      landing: ::as_gd_res::tres::field(res, doc, "landing")?,
+                  })
+              })();
+              extracted.map_err(|e| e.in_resource("JumpParams", &doc.resource_path(res)))
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTres for JumpParams {
+          fn from_tres(
+              value: &::as_gd_res::tres::TresValue,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTresResource for JumpParams {
+          fn tres_type_name(&self) -> String {
+              "JumpParamsResource".to_string()
+          }
+          fn to_tres_properties(
+              &self,
+              writer: &mut ::as_gd_res::tres::TresWriter,
+          ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+              vec![
+                  ("height".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.height, writer)),
+                  ("landing".to_string(), ::as_gd_res::tres::ToTres::to_tres(&self.landing, writer)),
+              ]
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTres for JumpParams {
+          fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+              writer.add_sub_resource(self)
+          }
+          fn tres_type() -> String {
+              "JumpParamsResource".to_string()
+          }
+      }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_post_init_without_godot() {
+    // `post_init` only affects the generated `IResource::init`, which needs Godot.
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_res(post_init = compute)]
+        pub struct Pickup {
+            pub value: i32,
+        }
+    };
+    let actual = expand_as_gd_res(input, false).to_string();
+    assert!(!actual.contains("godot"), "{}", actual);
+    assert!(!actual.contains("compute"), "{}", actual);
+}
+
+#[test]
+fn test_enum_without_godot() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Pickup {
+            Coin(CoinData),
+            Heart(HeartData),
+        }
+    };
+    let actual = expand_as_gd_res(input, false);
+    let expected = quote! {
+        impl ::as_gd_res::tres::FromTresResource for Pickup {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match res.type_name() {
+                    name if name == <CoinData as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Pickup::Coin(::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?)),
+                    name if name == <HeartData as ::as_gd_res::tres::ToTres>::tres_type() => Ok(Pickup::Heart(::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?)),
+                    other => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a resource for any variant of `{}`",
+                        other, "Pickup"
+                    ))
+                    .in_resource("Pickup", &doc.resource_path(res))),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Pickup {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(value, <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Pickup {
+            fn tres_type_name(&self) -> String {
+                match self {
+                    Pickup::Coin(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                    Pickup::Heart(v) => ::as_gd_res::tres::ToTresResource::tres_type_name(v),
+                }
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                match self {
+                    Pickup::Coin(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                    Pickup::Heart(v) => ::as_gd_res::tres::ToTresResource::to_tres_properties(v, writer),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Pickup {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "Resource".to_string()
+            }
+        }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+// Tuple fields of multi-field variants are stored as `field_0`, `field_1`, ...
+#[test]
+fn test_enum_multi_field_variant_without_godot() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Spell {
+            Teleport(i32, String),
+        }
+    };
+    let actual = expand_as_gd_res(input, false);
+    let expected = quote! {
+        impl ::as_gd_res::tres::FromTresResource for Spell {
+            fn from_tres_resource(
+                res: &::as_gd_res::tres::TresResource,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match res.type_name() {
+                    "SpellTeleportResource" => {
+                        let v: mod_spell_teleport::SpellTeleportFields =
+                            ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?;
+                        Ok(Spell::Teleport(v.field_0, v.field_1))
+                    }
+                    other => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a resource for any variant of `{}`",
+                        other, "Spell"
+                    ))
+                    .in_resource("Spell", &doc.resource_path(res))),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Spell {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                doc.resolve(
+                    value,
+                    <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+                )
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTresResource for Spell {
+            fn tres_type_name(&self) -> String {
+                match self {
+                    Spell::Teleport(..) => "SpellTeleportResource".to_string(),
+                }
+            }
+            fn to_tres_properties(
+                &self,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                match self {
+                    Spell::Teleport(field_0, field_1) =>
+                        mod_spell_teleport::SpellTeleportFields::fields_to_tres((field_0, field_1), writer),
+                }
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Spell {
+            fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                writer.add_sub_resource(self)
+            }
+            fn tres_type() -> String {
+                "Resource".to_string()
+            }
+        }
+
+        pub mod mod_spell_teleport {
+            use super::*;
+            pub struct SpellTeleportFields {
+                pub field_0: i32,
+                pub field_1: String,
+            }
+            impl ::as_gd_res::tres::FromTresResource for SpellTeleportFields {
+                fn from_tres_resource(
+                    res: &::as_gd_res::tres::TresResource,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                        res.expect_type("SpellTeleportResource")?;
+                        Ok(Self {
+                            field_0: ::as_gd_res::tres::field(res, doc, "field_0")?,
+                            field_1: ::as_gd_res::tres::field(res, doc, "field_1")?,
+                        })
+                    })();
+                    extracted.map_err(|e| e.in_resource("SpellTeleport", &doc.resource_path(res)))
+                }
+            }
+            impl SpellTeleportFields {
+                /// The `.tres` properties of the variant resource, from the fields of the
+                /// variant bound by reference.
+                pub fn fields_to_tres(
+                    fields: (&i32, &String),
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                    vec![
+                        (
+                            "field_0".to_string(),
+                            ::as_gd_res::tres::ToTres::to_tres(&(*fields.0), writer)
+                        ),
+                        (
+                            "field_1".to_string(),
+                            ::as_gd_res::tres::ToTres::to_tres(&(*fields.1), writer)
+                        ),
+                    ]
+                }
+            }
+        }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_enum_repeated_payload_without_godot() {
+    // A payload type shared by several variants can't identify the variant on its own,
+    // so each one is wrapped in its own resource.
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Effect {
+            Buff(StatMod),
+            Debuff(StatMod),
+        }
+    };
+    let actual = expand_as_gd_res(input, false);
+    let expected = quote! {
+      impl ::as_gd_res::tres::FromTresResource for Effect {
+          fn from_tres_resource(
+              res: &::as_gd_res::tres::TresResource,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              match res.type_name() {
+                  "EffectBuffResource" => {
+                      let v: mod_effect_buff::EffectBuffFields =
+                          ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?;
+                      Ok(Effect::Buff(v.field_0))
+                  }
+                  "EffectDebuffResource" => {
+                      let v: mod_effect_debuff::EffectDebuffFields =
+                          ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?;
+                      Ok(Effect::Debuff(v.field_0))
+                  }
+                  other => Err(::as_gd_res::ExtractError::invalid(format!(
+                      "`{}` is not a resource for any variant of `{}`",
+                      other, "Effect"
+                  ))
+                  .in_resource("Effect", &doc.resource_path(res))),
+              }
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTres for Effect {
+          fn from_tres(
+              value: &::as_gd_res::tres::TresValue,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              doc.resolve(
+                  value,
+                  <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+              )
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTresResource for Effect {
+          fn tres_type_name(&self) -> String {
+              match self {
+                  Effect::Buff(..) => "EffectBuffResource".to_string(),
+                  Effect::Debuff(..) => "EffectDebuffResource".to_string(),
+              }
+          }
+          fn to_tres_properties(
+              &self,
+              writer: &mut ::as_gd_res::tres::TresWriter,
+          ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+              match self {
+                  Effect::Buff(field_0) =>
+                      mod_effect_buff::EffectBuffFields::fields_to_tres((field_0,), writer),
+                  Effect::Debuff(field_0) =>
+                      mod_effect_debuff::EffectDebuffFields::fields_to_tres((field_0,), writer),
+              }
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTres for Effect {
+          fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+              writer.add_sub_resource(self)
+          }
+          fn tres_type() -> String {
+              "Resource".to_string()
+          }
+      }
+
+      pub mod mod_effect_buff {
+          use super::*;
+          pub struct EffectBuffFields {
+              pub field_0: StatMod,
+          }
+          impl ::as_gd_res::tres::FromTresResource for EffectBuffFields {
+              fn from_tres_resource(
+                  res: &::as_gd_res::tres::TresResource,
+                  doc: &::as_gd_res::tres::TresDocument,
+              ) -> Result<Self, ::as_gd_res::ExtractError> {
+                  let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                      res.expect_type("EffectBuffResource")?;
+                      Ok(Self {
+                          field_0: ::as_gd_res::tres::field(res, doc, "field_0")?,
+                      })
+                  })();
+                  extracted.map_err(|e| e.in_resource("EffectBuff", &doc.resource_path(res)))
+              }
+          }
+          impl EffectBuffFields {
+              /// The `.tres` properties of the variant resource, from the fields of the
+              /// variant bound by reference.
+              pub fn fields_to_tres(
+                  fields: (&StatMod,),
+                  writer: &mut ::as_gd_res::tres::TresWriter,
+              ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                  vec![(
+                      "field_0".to_string(),
+                      ::as_gd_res::tres::ToTres::to_tres(&(*fields.0), writer)),
+                  ]
+              }
+          }
+      }
+
+      pub mod mod_effect_debuff {
+          use super::*;
+          pub struct EffectDebuffFields {
+              pub field_0: StatMod,
+          }
+          impl ::as_gd_res::tres::FromTresResource for EffectDebuffFields {
+              fn from_tres_resource(
+                  res: &::as_gd_res::tres::TresResource,
+                  doc: &::as_gd_res::tres::TresDocument,
+              ) -> Result<Self, ::as_gd_res::ExtractError> {
+                  let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                      res.expect_type("EffectDebuffResource")?;
+                      Ok(Self {
+                          field_0: ::as_gd_res::tres::field(res, doc, "field_0")?,
+                      })
+                  })();
+                  extracted.map_err(|e| e.in_resource("EffectDebuff", &doc.resource_path(res)))
+              }
+          }
+          impl EffectDebuffFields {
+              /// The `.tres` properties of the variant resource, from the fields of the
+              /// variant bound by reference.
+              pub fn fields_to_tres(
+                  fields: (&StatMod,),
+                  writer: &mut ::as_gd_res::tres::TresWriter,
+              ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                  vec![(
+                      "field_0".to_string(),
+                      ::as_gd_res::tres::ToTres::to_tres(&(*fields.0), writer)),
+                  ]
+              }
+          }
+      }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_enum_wrap_attribute_without_godot() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Effect {
+            #[as_gd_res(wrap)]
+            Shield(ShieldData),
+        }
+    };
+    let actual = expand_as_gd_res(input, false);
+    let expected = quote! {
+      impl ::as_gd_res::tres::FromTresResource for Effect {
+          fn from_tres_resource(
+              res: &::as_gd_res::tres::TresResource,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              match res.type_name() {
+                  "EffectShieldResource" => {
+                      let v: mod_effect_shield::EffectShieldFields =
+                          ::as_gd_res::tres::FromTresResource::from_tres_resource(res, doc)?;
+                      Ok(Effect::Shield(v.field_0))
+                  }
+                  other => Err(::as_gd_res::ExtractError::invalid(format!(
+                      "`{}` is not a resource for any variant of `{}`",
+                      other, "Effect"
+                  ))
+                  .in_resource("Effect", &doc.resource_path(res))),
+              }
+          }
+      }
+
+      impl ::as_gd_res::tres::FromTres for Effect {
+          fn from_tres(
+              value: &::as_gd_res::tres::TresValue,
+              doc: &::as_gd_res::tres::TresDocument,
+          ) -> Result<Self, ::as_gd_res::ExtractError> {
+              doc.resolve(
+                  value,
+                  <Self as ::as_gd_res::tres::FromTresResource>::from_tres_resource
+              )
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTresResource for Effect {
+          fn tres_type_name(&self) -> String {
+              match self {
+                  Effect::Shield(..) => "EffectShieldResource".to_string(),
+              }
+          }
+          fn to_tres_properties(
+              &self,
+              writer: &mut ::as_gd_res::tres::TresWriter,
+          ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+              match self {
+                  Effect::Shield(field_0) =>
+                      mod_effect_shield::EffectShieldFields::fields_to_tres((field_0,), writer),
+              }
+          }
+      }
+
+      impl ::as_gd_res::tres::ToTres for Effect {
+          fn to_tres(&self, writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+              writer.add_sub_resource(self)
+          }
+          fn tres_type() -> String {
+              "Resource".to_string()
+          }
+      }
+
+      pub mod mod_effect_shield {
+          use super::*;
+          pub struct EffectShieldFields {
+              pub field_0: ShieldData,
+          }
+          impl ::as_gd_res::tres::FromTresResource for EffectShieldFields {
+              fn from_tres_resource(
+                  res: &::as_gd_res::tres::TresResource,
+                  doc: &::as_gd_res::tres::TresDocument,
+              ) -> Result<Self, ::as_gd_res::ExtractError> {
+                  let extracted = (|| -> Result<Self, ::as_gd_res::ExtractError> {
+                      res.expect_type("EffectShieldResource")?;
+                      Ok(Self {
+                          field_0: ::as_gd_res::tres::field(res, doc, "field_0")?,
+                      })
+                  })();
+                  extracted.map_err(|e| e.in_resource("EffectShield", &doc.resource_path(res)))
+              }
+          }
+          impl EffectShieldFields {
+              /// The `.tres` properties of the variant resource, from the fields of the
+              /// variant bound by reference.
+              pub fn fields_to_tres(
+                  fields: (&ShieldData,),
+                  writer: &mut ::as_gd_res::tres::TresWriter,
+              ) -> Vec<(String, ::as_gd_res::tres::TresValue)> {
+                  vec![(
+                      "field_0".to_string(),
+                      ::as_gd_res::tres::ToTres::to_tres(&(*fields.0), writer)),
+                  ]
+              }
+          }
+      }
+    };
+    assert_eq!(actual.to_string(), expected.to_string());
+}
@@ -7,12 +7,94 @@ use syn::{Data, DeriveInput, Fields};
 /// In any other case, the macro should emit an error saying that this conditions have not been met
 ///
 /// There are limitations upstream in *godot-rust* (or really: in Godot itself) that prevent the representation of certain types. You'll need work arounds in at least these cases:
-/// - `Option<{enum types}>`: If you want an "optional" enum, include a `None` variant in the enum itself, and set that as the default value.
-/// - `Array<{enum types}>` are also not supported
-#[proc_macro_derive(AsGdEnumSimple, attributes(export, init))]
+/// - `Option<{enum}>` is exported as a second Godot enum with an extra `None` choice. Enums that already have a `None` variant don't support `Option`; use that variant instead.
+/// - `Vec<{enum}>` is exported as an `Array<GString>` of variant names, since Godot can't store the enum itself in an array.
+///   The array gets the same dropdown as the enum for each element.
+///
+/// With `#[as_gd_enum(repr = int)]`, Godot and `.tres` files store the discriminants instead of the variant names
+/// (the inspector still shows the names), so variants can be renamed without breaking saved data. Discriminants
+/// must be integer literals or implicit. `None` is stored as `-1`, so an enum with a `-1` discriminant doesn't support `Option`.
+///
+/// On variants, `#[as_gd_enum(rename = "...")]` sets the Godot-side name, and `#[as_gd_enum(alias = "...")]` adds a name
+/// that is parsed as the variant but never written. Aliases are not variants of the Godot-side enums, so they don't
+/// show up in the inspector. `#[as_gd_enum(display = "...")]` sets the label the inspector's dropdown shows for the
+/// variant. With `repr = string`, Godot stores the selected label as the property's value, so labels are also parsed
+/// as the variant; the `.tres` writer still writes the Godot-side name.
+#[proc_macro_derive(AsGdEnumSimple, attributes(export, init, as_gd_enum))]
 pub fn as_gd_res_derive(input: TokenStream) -> TokenStream {
     let derive_input = syn::parse_macro_input!(input as DeriveInput);
-    TokenStream::from(expand_as_gd_res(derive_input))
+    TokenStream::from(expand_as_gd_res(derive_input, cfg!(feature = "godot")))
+}
+
+/// The `#[as_gd_enum(...)]` attributes of a variant.
+#[derive(Default)]
+struct VariantAttrs {
+    rename: Option<syn::Ident>,
+    aliases: Vec<String>,
+    display: Option<String>,
+}
+
+fn parse_variant_attrs(variant: &syn::Variant) -> syn::Result<VariantAttrs> {
+    // Godot-side names become variants of the generated enum, so they must be identifiers.
+    // Aliases are only parsed, so any string works. Labels end up in the `PropertyHint::ENUM`
+    // hint string, where `,` and `:` are separators.
+    fn gd_name(meta: &syn::meta::ParseNestedMeta) -> syn::Result<syn::Ident> {
+        let name: syn::LitStr = meta.value()?.parse()?;
+        syn::parse_str(&name.value()).map_err(|_| {
+            syn::Error::new(
+                name.span(),
+                "Godot-side variant names must be valid identifiers",
+            )
+        })
+    }
+
+    let mut attrs = VariantAttrs::default();
+    for attr in variant.attrs.iter() {
+        if attr.path().is_ident("as_gd_enum") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    attrs.rename = Some(gd_name(&meta)?);
+                } else if meta.path.is_ident("alias") {
+                    attrs
+                        .aliases
+                        .push(meta.value()?.parse::<syn::LitStr>()?.value());
+                } else if meta.path.is_ident("display") {
+                    let label: syn::LitStr = meta.value()?.parse()?;
+                    if label.value().contains([',', ':']) {
+                        return Err(syn::Error::new(
+                            label.span(),
+                            "`display` labels can't contain `,` or `:`",
+                        ));
+                    }
+                    attrs.display = Some(label.value());
+                } else {
+                    return Err(meta.error("unknown `as_gd_enum` variant attribute"));
+                }
+                Ok(())
+            })?;
+        }
+    }
+    Ok(attrs)
+}
+
+/// The discriminant that `Option::None` is stored as with `#[as_gd_enum(repr = int)]`.
+const NONE_DISCRIMINANT: i64 = -1;
+
+/// Returns the value of an integer literal discriminant like `10` or `-1`.
+fn int_literal(expr: &syn::Expr) -> Option<i64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => int_literal(expr).map(|value| -value),
+        syn::Expr::Group(group) => int_literal(&group.expr),
+        _ => None,
+    }
 }
 
 /// For an enum with _only_ unit variants, returns a token stream that:
@@ -20,17 +102,50 @@ pub fn as_gd_res_derive(input: TokenStream) -> TokenStream {
 /// - prepends these derives to the to new enum:
 /// ```text
 /// #[derive(GodotConvert, Var, Export, Clone, Copy, Debug, PartialEq, Eq)]
-/// #[godot(via = GString)]
+/// #[godot(via = GString)] // or `via = i64` with `#[as_gd_enum(repr = int)]`
 /// ```
 /// - impls `Into` in both directions between the new and preexisting enum
 /// - impls AsGdEnumSimple for the existing enum, with `AsGdEnumSimple<GdEnumType= {the new enum type}>`
-/// - impls ExtractGd for the new enum by way of `.into()`
+/// - impls AsGdRes, ToGdRes, FromGdRes and AsGdResDictElement for the existing enum, with the new enum as the `ResType`
+/// - impls ExtractGd and TryExtractGd for the new enum by way of `.into()`
+/// - impls AsGdResArray, ToGdResArray and FromGdResArray for the existing enum, with a `GdEnumArray` of what Godot stores for each variant
+/// - unless a variant is named `None`: creates `{original_enum_name}AsGdEnumOpt` with an extra `None` variant, and impls AsGdResOpt, ToGdResOpt and FromGdResOpt with it
+/// - impls `tres::FromTres` and `tres::ToTres` for the existing enum, using the variant name (or discriminant) that Godot stores
+/// - impls `SimpleEnumVariants`, `FromStr` and `Display` for the existing enum, and adds an inherent `display_name()`
+/// - with `#[as_gd_enum(variants_class)]`: creates a `{original_enum_name}AsGdEnumVariants` class exposing the same metadata to GDScript
 /// - impls Default for the new enum by way of `default().into()` from the existing enum type (the existing enum must already impl Default)
-
-fn expand_as_gd_res(input: DeriveInput) -> proc_macro2::TokenStream {
+///
+/// With `godot == false`, only the `tres` and variant metadata impls are emitted.
+fn expand_as_gd_res(input: DeriveInput, godot: bool) -> proc_macro2::TokenStream {
     if !input.generics.params.is_empty() {
         return quote! { compile_error!("`derive(AsGdEnumSimple)` does not support generics"); };
     }
+    // Detect #[as_gd_enum(repr = int, variants_class)] on the enum
+    let mut repr_int = false;
+    let mut variants_class = false;
+    for attr in input.attrs.iter() {
+        if attr.path().is_ident("as_gd_enum") {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("repr") {
+                    let repr: syn::Ident = meta.value()?.parse()?;
+                    match repr.to_string().as_str() {
+                        "int" => repr_int = true,
+                        "string" => repr_int = false,
+                        _ => return Err(meta.error("expected `repr = int` or `repr = string`")),
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("variants_class") {
+                    variants_class = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `as_gd_enum` attribute"))
+                }
+            });
+            if let Err(err) = parsed {
+                return err.to_compile_error();
+            }
+        }
+    }
     let original_name = input.ident;
     let res_name = format_ident!("{}AsGdEnum", original_name);
     let mod_name = format_ident!("mod_{}", res_name.to_string().to_lowercase());
@@ -39,11 +154,50 @@ fn expand_as_gd_res(input: DeriveInput) -> proc_macro2::TokenStream {
         Data::Enum(data) => {
             // collect unit‐only variants and any that have data
             let mut unit_variants = Vec::new();
+            // The Godot-side name of each variant (`#[as_gd_enum(rename = "...")]`)
+            let mut gd_names = Vec::new();
+            // Legacy names accepted when loading, with the variant they map to
+            let mut aliases = Vec::new();
+            // The inspector label of each variant (`#[as_gd_enum(display = "...")]`), and the
+            // labels that differ from the Godot-side name, with the variant they belong to
+            let mut display_names = Vec::new();
+            let mut labels = Vec::new();
+            // With `repr = int`: the explicit or implicit discriminant of each variant
+            let mut discriminants = Vec::new();
             let mut bad = Vec::new();
             for v in data.variants.iter() {
                 match &v.fields {
                     Fields::Unit => {
+                        let attrs = match parse_variant_attrs(v) {
+                            Ok(attrs) => attrs,
+                            Err(err) => return err.to_compile_error(),
+                        };
+                        if repr_int && !attrs.aliases.is_empty() {
+                            return quote! { compile_error!("`alias` is not supported with `#[as_gd_enum(repr = int)]`, which stores discriminants"); };
+                        }
+                        let gd_name = attrs.rename.unwrap_or_else(|| v.ident.clone());
+                        match attrs.display {
+                            Some(label) if gd_name != label => {
+                                labels.push((label.clone(), &v.ident));
+                                display_names.push(label);
+                            }
+                            _ => display_names.push(gd_name.to_string()),
+                        }
+                        aliases.extend(attrs.aliases.into_iter().map(|alias| (alias, &v.ident)));
+                        gd_names.push(gd_name);
                         unit_variants.push(&v.ident);
+                        if repr_int {
+                            let discriminant = match &v.discriminant {
+                                Some((_, expr)) => match int_literal(expr) {
+                                    Some(value) => value,
+                                    None => {
+                                        return quote! { compile_error!("`#[as_gd_enum(repr = int)]` requires integer literal discriminants"); };
+                                    }
+                                },
+                                None => discriminants.last().map_or(0, |prev| prev + 1),
+                            };
+                            discriminants.push(discriminant);
+                        }
                     }
                     Fields::Unnamed(fields) => {
                         // e.g. `Fire(u32)` or if many: `Foo(u32, f32)`
@@ -83,6 +237,503 @@ fn expand_as_gd_res(input: DeriveInput) -> proc_macro2::TokenStream {
                 };
             }
 
+            let name_str = original_name.to_string();
+            let variant_strs = gd_names.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+            let alias_strs = aliases.iter().map(|(a, _)| a).collect::<Vec<_>>();
+            let alias_variants = aliases.iter().map(|(_, v)| *v).collect::<Vec<_>>();
+            let label_strs = labels.iter().map(|(l, _)| l).collect::<Vec<_>>();
+            let label_variants = labels.iter().map(|(_, v)| *v).collect::<Vec<_>>();
+            let mut seen = std::collections::HashSet::new();
+            if let Some(dup) = variant_strs
+                .iter()
+                .chain(alias_strs.iter().copied())
+                .chain(label_strs.iter().copied())
+                .find(|name| !seen.insert(*name))
+            {
+                let msg = format!(
+                    "`{}` is used as the Godot-side name or label of more than one variant",
+                    dup
+                );
+                return quote! { compile_error!(#msg); };
+            }
+            // `Option<{enum}>` stores `None` as an extra variant, named `None` or with
+            // discriminant -1
+            let supports_option = !variant_strs
+                .iter()
+                .chain(alias_strs.iter().copied())
+                .chain(label_strs.iter().copied())
+                .any(|v| v == "None")
+                && !discriminants.contains(&NONE_DISCRIMINANT);
+
+            // What Godot and `.tres` files store for each variant
+            let (via, stored, none_stored, tres_value, read_tres, tres_type, expected) = if repr_int
+            {
+                (
+                    quote! { i64 },
+                    discriminants
+                        .iter()
+                        .map(|d| proc_macro2::Literal::i64_unsuffixed(*d).into_token_stream())
+                        .collect::<Vec<_>>(),
+                    proc_macro2::Literal::i64_unsuffixed(NONE_DISCRIMINANT).into_token_stream(),
+                    quote! { ::as_gd_res::tres::TresValue::Int },
+                    quote! { value.as_int() },
+                    "int",
+                    "discriminant",
+                )
+            } else {
+                (
+                    quote! { GString },
+                    variant_strs.iter().map(|v| quote! { #v }).collect(),
+                    quote! { "None" },
+                    quote! { ::as_gd_res::tres::TresValue::String },
+                    quote! { value.as_str() },
+                    "String",
+                    "variant name",
+                )
+            };
+            let expected_msg = format!("expected a `{{}}` {}, found `{{}}`", expected);
+            let stored_value = if repr_int {
+                quote! { #tres_value(stored) }
+            } else {
+                quote! { #tres_value(stored.to_string()) }
+            };
+            let none_value = if repr_int {
+                quote! { #tres_value(#none_stored) }
+            } else {
+                quote! { #tres_value(#none_stored.to_string()) }
+            };
+            // Godot-side variants, with their discriminants for `repr = int`. Aliases are
+            // not variants: they are only accepted when parsing, and written back under
+            // the name of the variant they map to.
+            let gd_variants = gd_names
+                .iter()
+                .enumerate()
+                .map(|(i, v)| match discriminants.get(i) {
+                    Some(d) => {
+                        let d = proc_macro2::Literal::i64_unsuffixed(*d);
+                        quote! { #v = #d }
+                    }
+                    None => quote! { #v },
+                })
+                .collect::<Vec<_>>();
+            // The `PropertyHint::ENUM` hint string, as `#[derive(Var)]` builds it for the
+            // Godot-side enum
+            let enum_hint = if repr_int {
+                display_names
+                    .iter()
+                    .zip(&discriminants)
+                    .map(|(v, d)| format!("{}:{}", v, d))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            } else {
+                display_names.join(",")
+            };
+            let stored_type = if repr_int {
+                quote! { INT }
+            } else {
+                quote! { STRING }
+            };
+            // Without aliases or labels, `#[derive(Var, Export)]` on the Godot-side enums is
+            // enough. Otherwise `Var` is implemented by hand: so that setting an alias (e.g.
+            // when Godot loads an older `.tres`) or a label stores the variant it maps to, and
+            // so that the hint shows the labels.
+            let custom_var = !aliases.is_empty() || !labels.is_empty();
+            let var_derives = if custom_var {
+                quote! {}
+            } else {
+                quote! { ::godot::prelude::Var, ::godot::prelude::Export, }
+            };
+            let custom_var_impls =
+                |gd_type: &syn::Ident,
+                 hint: String,
+                 get: proc_macro2::TokenStream,
+                 set: proc_macro2::TokenStream| {
+                    if !custom_var {
+                        return quote! {};
+                    }
+                    quote! {
+                        impl ::godot::prelude::Var for #gd_type {
+                            fn get_property(&self) -> #via {
+                                #get
+                            }
+
+                            fn set_property(&mut self, value: #via) {
+                                #set
+                            }
+
+                            fn var_hint() -> ::godot::meta::PropertyHintInfo {
+                                ::as_gd_res::enum_property_hint(#hint)
+                            }
+                        }
+
+                        impl ::godot::prelude::Export for #gd_type {}
+                    }
+                };
+            // With `repr = int`, Godot stores the discriminant whatever the label, so the
+            // hand-written `Var` converts like the derived one would
+            let int_var_get = quote! { ::godot::prelude::ToGodot::to_godot(self) };
+            let int_var_set = quote! {
+                match <Self as ::godot::prelude::FromGodot>::try_from_godot(value) {
+                    Ok(value) => *self = value,
+                    Err(err) => ::godot::global::godot_error!("{}", err),
+                }
+            };
+
+            let (from_tres_opt, to_tres_opt) = if supports_option {
+                (
+                    quote! {
+                        fn from_tres_opt(
+                            value: &::as_gd_res::tres::TresValue,
+                            doc: &::as_gd_res::tres::TresDocument,
+                        ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                            if matches!(value, ::as_gd_res::tres::TresValue::Null) || #read_tres == Some(#none_stored) {
+                                return Ok(None);
+                            }
+                            <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+                        }
+                    },
+                    quote! {
+                        fn to_tres_opt(
+                            value: Option<&Self>,
+                            writer: &mut ::as_gd_res::tres::TresWriter,
+                        ) -> ::as_gd_res::tres::TresValue {
+                            match value {
+                                Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                                None => #none_value,
+                            }
+                        }
+                    },
+                )
+            } else {
+                (quote! {}, quote! {})
+            };
+
+            // Godot stores labels with `repr = string`, and discriminants with `repr = int`
+            let tres_label_arms = if repr_int {
+                quote! {}
+            } else {
+                quote! { #( Some(#label_strs) => Ok(#original_name::#label_variants), )* }
+            };
+            let tres_impls = quote! {
+                impl ::as_gd_res::tres::FromTres for #original_name {
+                    fn from_tres(
+                        value: &::as_gd_res::tres::TresValue,
+                        _doc: &::as_gd_res::tres::TresDocument,
+                    ) -> Result<Self, ::as_gd_res::ExtractError> {
+                        match #read_tres {
+                            #( Some(#stored) => Ok(#original_name::#unit_variants), )*
+                            #( Some(#alias_strs) => Ok(#original_name::#alias_variants), )*
+                            #tres_label_arms
+                            _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                                #expected_msg,
+                                #name_str, value
+                            ))),
+                        }
+                    }
+                    fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                        Ok(#original_name::default())
+                    }
+                    #from_tres_opt
+                }
+
+                impl ::as_gd_res::tres::ToTres for #original_name {
+                    fn to_tres(&self, _writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                        let stored = match *self {
+                            #( #original_name::#unit_variants => #stored, )*
+                        };
+                        #stored_value
+                    }
+                    fn tres_type() -> String {
+                        #tres_type.to_string()
+                    }
+                    #to_tres_opt
+                }
+            };
+            let variant_indices =
+                (0..unit_variants.len()).map(proc_macro2::Literal::usize_unsuffixed);
+            let variant_impls = quote! {
+                impl #original_name {
+                    /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+                    pub fn display_name(&self) -> &'static str {
+                        match *self {
+                            #( #original_name::#unit_variants => #display_names, )*
+                        }
+                    }
+                }
+
+                impl ::as_gd_res::SimpleEnumVariants for #original_name {
+                    const VARIANTS: &'static [Self] = &[ #( #original_name::#unit_variants , )* ];
+                    fn name(&self) -> &'static str {
+                        match *self {
+                            #( #original_name::#unit_variants => #variant_strs, )*
+                        }
+                    }
+                    fn index(&self) -> usize {
+                        match *self {
+                            #( #original_name::#unit_variants => #variant_indices, )*
+                        }
+                    }
+                }
+
+                impl ::std::str::FromStr for #original_name {
+                    type Err = ::as_gd_res::ExtractError;
+                    fn from_str(s: &str) -> Result<Self, Self::Err> {
+                        match s {
+                            #( #variant_strs => Ok(#original_name::#unit_variants), )*
+                            #( #alias_strs => Ok(#original_name::#alias_variants), )*
+                            #( #label_strs => Ok(#original_name::#label_variants), )*
+                            _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                                "`{}` is not a variant of `{}`",
+                                s, #name_str
+                            ))),
+                        }
+                    }
+                }
+
+                impl ::std::fmt::Display for #original_name {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+                    }
+                }
+            };
+            if !godot {
+                return quote! {
+                    #tres_impls
+
+                    #variant_impls
+                };
+            }
+
+            // With `#[as_gd_enum(variants_class)]`: a class with the same metadata as
+            // static functions, for GDScript
+            let variants_class_impls = if variants_class {
+                let class_name = format_ident!("{}Variants", res_name);
+                quote! {
+                    use ::godot::classes::RefCounted;
+
+                    #[derive(::godot::prelude::GodotClass)]
+                    #[class(init, base = RefCounted)]
+                    pub struct #class_name {}
+
+                    #[::godot::prelude::godot_api]
+                    impl #class_name {
+                        /// The Godot-side names of all variants, in declaration order.
+                        #[func]
+                        fn names() -> ::godot::prelude::PackedStringArray {
+                            <#original_name as ::as_gd_res::SimpleEnumVariants>::VARIANTS
+                                .iter()
+                                .map(|v| GString::from(::as_gd_res::SimpleEnumVariants::name(v)))
+                                .collect()
+                        }
+
+                        /// The display labels of all variants, in declaration order.
+                        #[func]
+                        fn display_names() -> ::godot::prelude::PackedStringArray {
+                            <#original_name as ::as_gd_res::SimpleEnumVariants>::VARIANTS
+                                .iter()
+                                .map(|v| GString::from(v.display_name()))
+                                .collect()
+                        }
+
+                        /// The index of the variant with this name, alias or label, or -1.
+                        #[func]
+                        fn index_of(name: GString) -> i64 {
+                            name.to_string()
+                                .parse::<#original_name>()
+                                .map_or(-1, |v| ::as_gd_res::SimpleEnumVariants::index(&v) as i64)
+                        }
+
+                        /// The name of the variant at `index`, or an empty string.
+                        #[func]
+                        fn name_at(index: i64) -> GString {
+                            usize::try_from(index)
+                                .ok()
+                                .and_then(<#original_name as ::as_gd_res::SimpleEnumVariants>::from_index)
+                                .map_or_else(GString::new, |v| GString::from(::as_gd_res::SimpleEnumVariants::name(&v)))
+                        }
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
+            let opt_impls = if supports_option && repr_int {
+                quote! {
+                    impl ::as_gd_res::AsGdResOpt for #original_name {
+                        type GdOption = ::as_gd_res::GdEnumIntOpt<Self>;
+                    }
+
+                    impl ::as_gd_res::ToGdResOpt for #original_name {
+                        fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                            ::as_gd_res::GdEnumIntOpt::new(value.copied())
+                        }
+
+                        fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                            ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+                        }
+                    }
+
+                    impl ::as_gd_res::FromGdResOpt for #original_name {
+                        fn try_from_gd_opt(
+                            res: &Self::GdOption,
+                            _ctx: &mut ::as_gd_res::ExtractContext,
+                        ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                            ::as_gd_res::TryExtractGd::try_extract(res)
+                        }
+                    }
+                }
+            } else if supports_option {
+                let opt_name = format_ident!("{}AsGdEnumOpt", original_name);
+                let opt_var_impls = custom_var_impls(
+                    &opt_name,
+                    format!("None,{}", enum_hint),
+                    quote! {
+                        match ::as_gd_res::ExtractGd::extract(self) {
+                            Some(value) => GString::from(value.display_name()),
+                            None => GString::from(#none_stored),
+                        }
+                    },
+                    quote! {
+                        let value = value.to_string();
+                        if value == #none_stored {
+                            *self = #opt_name::None;
+                            return;
+                        }
+                        match value.parse::<#original_name>() {
+                            Ok(value) => *self = <#original_name as ::as_gd_res::ToGdResOpt>::to_gd_opt(Some(&value)),
+                            Err(err) => ::godot::global::godot_error!("{}", err),
+                        }
+                    },
+                );
+                quote! {
+                    #[derive(::godot::prelude::GodotConvert, #var_derives Clone, Copy, Debug, PartialEq, Eq, Default)]
+                    #[godot(via = #via)]
+                    pub enum #opt_name {
+                        #[default]
+                        None,
+                        #( #gd_variants , )*
+                    }
+
+                    impl ::as_gd_res::AsGdResOpt for #original_name {
+                        type GdOption = #opt_name;
+                    }
+
+                    impl ::as_gd_res::ToGdResOpt for #original_name {
+                        fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                            match value.copied() {
+                                #( Some(#original_name::#unit_variants) => #opt_name::#gd_names , )*
+                                None => #opt_name::None,
+                            }
+                        }
+
+                        fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                            ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+                        }
+                    }
+
+                    impl ::as_gd_res::FromGdResOpt for #original_name {
+                        fn try_from_gd_opt(
+                            res: &Self::GdOption,
+                            _ctx: &mut ::as_gd_res::ExtractContext,
+                        ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                            Ok(::as_gd_res::ExtractGd::extract(res))
+                        }
+                    }
+
+                    impl ::as_gd_res::ExtractGd for #opt_name {
+                        type Extracted = Option<#original_name>;
+                        fn extract(&self) -> Self::Extracted {
+                            match *self {
+                                #opt_name::None => None,
+                                #( #opt_name::#gd_names => Some(#original_name::#unit_variants) , )*
+                            }
+                        }
+                    }
+
+                    impl ::as_gd_res::TryExtractGd for #opt_name {
+                        fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                            Ok(::as_gd_res::ExtractGd::extract(self))
+                        }
+                    }
+
+                    #opt_var_impls
+                }
+            } else {
+                quote! {}
+            };
+
+            let var_impls = if repr_int {
+                custom_var_impls(&res_name, enum_hint.clone(), int_var_get, int_var_set)
+            } else {
+                custom_var_impls(
+                    &res_name,
+                    enum_hint.clone(),
+                    quote! {
+                        GString::from(#original_name::from(*self).display_name())
+                    },
+                    quote! {
+                        match value.to_string().parse::<#original_name>() {
+                            Ok(value) => *self = value.into(),
+                            Err(err) => ::godot::global::godot_error!("{}", err),
+                        }
+                    },
+                )
+            };
+
+            // With `repr = int`, fields store the raw discriminant, so that one that isn't a
+            // variant fails extraction instead of being dropped when Godot sets the property
+            let res_impls = if repr_int {
+                quote! {
+                    impl ::as_gd_res::AsGdRes for #original_name {
+                        type ResType = ::as_gd_res::GdEnumInt<Self>;
+                    }
+
+                    impl ::as_gd_res::ToGdRes for #original_name {
+                        fn to_gd_res(&self) -> Self::ResType {
+                            ::as_gd_res::GdEnumInt::new(*self)
+                        }
+
+                        fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                            ::as_gd_res::apply_by_value(res, ::as_gd_res::GdEnumInt::new(*self))
+                        }
+                    }
+
+                    impl ::as_gd_res::FromGdRes for #original_name {
+                        fn try_from_gd_res_with(
+                            res: &Self::ResType,
+                            _ctx: &mut ::as_gd_res::ExtractContext,
+                        ) -> Result<Self, ::as_gd_res::ExtractError> {
+                            ::as_gd_res::TryExtractGd::try_extract(res)
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    impl ::as_gd_res::AsGdRes for #original_name {
+                        type ResType = #res_name;
+                    }
+
+                    impl ::as_gd_res::ToGdRes for #original_name {
+                        fn to_gd_res(&self) -> Self::ResType {
+                            (*self).into()
+                        }
+
+                        fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                            ::as_gd_res::apply_by_value(res, (*self).into())
+                        }
+                    }
+
+                    impl ::as_gd_res::FromGdRes for #original_name {
+                        fn try_from_gd_res_with(
+                            res: &Self::ResType,
+                            _ctx: &mut ::as_gd_res::ExtractContext,
+                        ) -> Result<Self, ::as_gd_res::ExtractError> {
+                            Ok((*res).into())
+                        }
+                    }
+                }
+            };
+
             // all‐unit case ⇒ emit the “AsGdEnum” + trait impls
             quote! {
                 pub use #mod_name::*;
@@ -90,14 +741,68 @@ fn expand_as_gd_res(input: DeriveInput) -> proc_macro2::TokenStream {
                 use super:: #original_name ;
                 use ::godot::prelude::GString;
 
-                #[derive(::godot::prelude::GodotConvert, ::godot::prelude::Var, ::godot::prelude::Export, Clone, Copy, Debug, PartialEq, Eq)]
-                #[godot(via = GString)]
+                #[derive(::godot::prelude::GodotConvert, #var_derives Clone, Copy, Debug, PartialEq, Eq)]
+                #[godot(via = #via)]
                 pub enum #res_name {
-                    #( #unit_variants , )*
+                    #( #gd_variants , )*
                 }
 
+                #var_impls
+
                 impl ::as_gd_res::AsGdEnumSimple for #original_name {
                     type GdEnumType = #res_name;
+                    const ENUM_HINT: &'static str = #enum_hint;
+                    const STORED_TYPE: ::godot::builtin::VariantType = ::godot::builtin::VariantType::#stored_type;
+                }
+
+                #res_impls
+
+                impl ::as_gd_res::AsGdResDictElement for #original_name {
+                    type GdElement = #via;
+
+                    fn to_gd_element(&self) -> Self::GdElement {
+                        ::as_gd_res::simple_enum_to_gd_element(*self)
+                    }
+
+                    fn try_from_gd_element(
+                        element: &Self::GdElement,
+                        _ctx: &mut ::as_gd_res::ExtractContext,
+                    ) -> Result<Self, ::as_gd_res::ExtractError> {
+                        ::as_gd_res::simple_enum_from_gd_element(element)
+                    }
+
+                    fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                        ::as_gd_res::apply_by_value(res, self.to_gd_element())
+                    }
+
+                    fn default_gd_element() -> ::godot::prelude::Variant {
+                        ::godot::prelude::ToGodot::to_variant(&#original_name::default().to_gd_element())
+                    }
+                }
+
+                impl ::as_gd_res::AsGdResArray for #original_name {
+                    type GdArray = ::as_gd_res::GdEnumArray<Self>;
+                }
+
+                impl ::as_gd_res::ToGdResArray for #original_name {
+                    fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                        ::as_gd_res::GdEnumArray::new(
+                            values.into_iter().map(::as_gd_res::AsGdResDictElement::to_gd_element).collect(),
+                        )
+                    }
+
+                    fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                        ::as_gd_res::apply_by_value(res, Self::to_gd_array(values))
+                    }
+                }
+
+                impl ::as_gd_res::FromGdResArray for #original_name {
+                    fn try_from_gd_array(
+                        res: &Self::GdArray,
+                        ctx: &mut ::as_gd_res::ExtractContext,
+                    ) -> Result<Vec<Self>, ::as_gd_res::ExtractError> {
+                        ::as_gd_res::TryExtractGd::try_extract_with(res, ctx)
+                    }
                 }
 
                 impl ::as_gd_res::ExtractGd for #res_name {
@@ -107,10 +812,24 @@ fn expand_as_gd_res(input: DeriveInput) -> proc_macro2::TokenStream {
                     }
                 }
 
+                impl ::as_gd_res::TryExtractGd for #res_name {
+                    fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                        Ok((*self).into())
+                    }
+                }
+
+                #opt_impls
+
+                #tres_impls
+
+                #variant_impls
+
+                #variants_class_impls
+
                 impl From<#original_name> for #res_name {
                     fn from(value: #original_name) -> #res_name {
                         match value {
-                            #( #original_name::#unit_variants => #res_name::#unit_variants , )*
+                            #( #original_name::#unit_variants => #res_name::#gd_names , )*
                         }
                     }
                 }
@@ -118,7 +837,7 @@ fn expand_as_gd_res(input: DeriveInput) -> proc_macro2::TokenStream {
                 impl From<#res_name> for #original_name {
                     fn from(value: #res_name) -> #original_name {
                         match value {
-                            #( #res_name::#unit_variants => #original_name::#unit_variants , )*
+                            #( #res_name::#gd_names => #original_name::#unit_variants , )*
                         }
                     }
                 }
@@ -8,7 +8,7 @@ fn test_empty_enum() {
         pub enum EmptyEnum {}
     };
 
-    let actual = expand_as_gd_res(input);
+    let actual = expand_as_gd_res(input, true);
     // An empty enum has no variants, so all are vacuously unit
     let expected = quote! {
         pub use mod_emptyenumasgdenum::*;
@@ -23,6 +23,79 @@ fn test_empty_enum() {
 
             impl ::as_gd_res::AsGdEnumSimple for EmptyEnum {
                 type GdEnumType = EmptyEnumAsGdEnum;
+                const ENUM_HINT: &'static str = "";
+                const STORED_TYPE: ::godot::builtin::VariantType = ::godot::builtin::VariantType::STRING;
+            }
+
+            impl ::as_gd_res::AsGdRes for EmptyEnum {
+                type ResType = EmptyEnumAsGdEnum;
+            }
+
+            impl ::as_gd_res::ToGdRes for EmptyEnum {
+                fn to_gd_res(&self) -> Self::ResType {
+                    (*self).into()
+                }
+
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    ::as_gd_res::apply_by_value(res, (*self).into())
+                }
+            }
+
+            impl ::as_gd_res::FromGdRes for EmptyEnum {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok((*res).into())
+                }
+            }
+
+            impl ::as_gd_res::AsGdResDictElement for EmptyEnum {
+                type GdElement = GString;
+
+                fn to_gd_element(&self) -> Self::GdElement {
+                    ::as_gd_res::simple_enum_to_gd_element(*self)
+                }
+
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::simple_enum_from_gd_element(element)
+                }
+
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    ::as_gd_res::apply_by_value(res, self.to_gd_element())
+                }
+
+                fn default_gd_element() -> ::godot::prelude::Variant {
+                    ::godot::prelude::ToGodot::to_variant(&EmptyEnum::default().to_gd_element())
+                }
+            }
+
+            impl ::as_gd_res::AsGdResArray for EmptyEnum {
+                type GdArray = ::as_gd_res::GdEnumArray<Self>;
+            }
+
+            impl ::as_gd_res::ToGdResArray for EmptyEnum {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    ::as_gd_res::GdEnumArray::new(
+                        values.into_iter().map(::as_gd_res::AsGdResDictElement::to_gd_element).collect(),
+                    )
+                }
+
+                fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_array(values))
+                }
+            }
+
+            impl ::as_gd_res::FromGdResArray for EmptyEnum {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Vec<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract_with(res, ctx)
+                }
             }
 
             impl ::as_gd_res::ExtractGd for EmptyEnumAsGdEnum {
@@ -32,6 +105,145 @@ fn test_empty_enum() {
                 }
             }
 
+            impl ::as_gd_res::TryExtractGd for EmptyEnumAsGdEnum {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok((*self).into())
+                }
+            }
+
+            #[derive(::godot::prelude::GodotConvert, ::godot::prelude::Var, ::godot::prelude::Export, Clone, Copy, Debug, PartialEq, Eq, Default)]
+            #[godot(via = GString)]
+            pub enum EmptyEnumAsGdEnumOpt {
+                #[default]
+                None,
+            }
+
+            impl ::as_gd_res::AsGdResOpt for EmptyEnum {
+                type GdOption = EmptyEnumAsGdEnumOpt;
+            }
+
+            impl ::as_gd_res::ToGdResOpt for EmptyEnum {
+                fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                    match value.copied() {
+                        None => EmptyEnumAsGdEnumOpt::None,
+                    }
+                }
+
+                fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+                }
+            }
+
+            impl ::as_gd_res::FromGdResOpt for EmptyEnum {
+                fn try_from_gd_opt(
+                    res: &Self::GdOption,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(res))
+                }
+            }
+
+            impl ::as_gd_res::ExtractGd for EmptyEnumAsGdEnumOpt {
+                type Extracted = Option<EmptyEnum>;
+                fn extract(&self) -> Self::Extracted {
+                    match *self {
+                        EmptyEnumAsGdEnumOpt::None => None,
+                    }
+                }
+            }
+
+            impl ::as_gd_res::TryExtractGd for EmptyEnumAsGdEnumOpt {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(self))
+                }
+            }
+
+            impl ::as_gd_res::tres::FromTres for EmptyEnum {
+                fn from_tres(
+                    value: &::as_gd_res::tres::TresValue,
+                    _doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    match value.as_str() {
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "expected a `{}` variant name, found `{}`",
+                            "EmptyEnum", value
+                        ))),
+                    }
+                }
+                fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok(EmptyEnum::default())
+                }
+
+                fn from_tres_opt(
+                    value: &::as_gd_res::tres::TresValue,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    if matches!(value, ::as_gd_res::tres::TresValue::Null) || value.as_str() == Some("None") {
+                        return Ok(None);
+                    }
+                    <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+                }
+            }
+
+            impl ::as_gd_res::tres::ToTres for EmptyEnum {
+                fn to_tres(&self, _writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                    let stored = match *self {
+                    };
+                    ::as_gd_res::tres::TresValue::String(stored.to_string())
+                }
+                fn tres_type() -> String {
+                    "String".to_string()
+                }
+
+                fn to_tres_opt(
+                    value: Option<&Self>,
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> ::as_gd_res::tres::TresValue {
+                    match value {
+                        Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                        None => ::as_gd_res::tres::TresValue::String("None".to_string()),
+                    }
+                }
+            }
+
+            impl EmptyEnum {
+                /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+                pub fn display_name(&self) -> &'static str {
+                    match *self {
+                    }
+                }
+            }
+
+            impl ::as_gd_res::SimpleEnumVariants for EmptyEnum {
+                const VARIANTS: &'static [Self] = &[];
+                fn name(&self) -> &'static str {
+                    match *self {
+                    }
+                }
+                fn index(&self) -> usize {
+                    match *self {
+                    }
+                }
+            }
+
+            impl ::std::str::FromStr for EmptyEnum {
+                type Err = ::as_gd_res::ExtractError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "`{}` is not a variant of `{}`",
+                            s, "EmptyEnum"
+                        ))),
+                    }
+                }
+            }
+
+            impl ::std::fmt::Display for EmptyEnum {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+                }
+            }
+
             impl From<EmptyEnum> for EmptyEnumAsGdEnum {
                 fn from(value: EmptyEnum) -> EmptyEnumAsGdEnum {
                     match value {
@@ -78,6 +290,79 @@ fn test_single_variant_enum() {
 
             impl ::as_gd_res::AsGdEnumSimple for SingleVariant {
                 type GdEnumType = SingleVariantAsGdEnum;
+                const ENUM_HINT: &'static str = "Only";
+                const STORED_TYPE: ::godot::builtin::VariantType = ::godot::builtin::VariantType::STRING;
+            }
+
+            impl ::as_gd_res::AsGdRes for SingleVariant {
+                type ResType = SingleVariantAsGdEnum;
+            }
+
+            impl ::as_gd_res::ToGdRes for SingleVariant {
+                fn to_gd_res(&self) -> Self::ResType {
+                    (*self).into()
+                }
+
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    ::as_gd_res::apply_by_value(res, (*self).into())
+                }
+            }
+
+            impl ::as_gd_res::FromGdRes for SingleVariant {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok((*res).into())
+                }
+            }
+
+            impl ::as_gd_res::AsGdResDictElement for SingleVariant {
+                type GdElement = GString;
+
+                fn to_gd_element(&self) -> Self::GdElement {
+                    ::as_gd_res::simple_enum_to_gd_element(*self)
+                }
+
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::simple_enum_from_gd_element(element)
+                }
+
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    ::as_gd_res::apply_by_value(res, self.to_gd_element())
+                }
+
+                fn default_gd_element() -> ::godot::prelude::Variant {
+                    ::godot::prelude::ToGodot::to_variant(&SingleVariant::default().to_gd_element())
+                }
+            }
+
+            impl ::as_gd_res::AsGdResArray for SingleVariant {
+                type GdArray = ::as_gd_res::GdEnumArray<Self>;
+            }
+
+            impl ::as_gd_res::ToGdResArray for SingleVariant {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    ::as_gd_res::GdEnumArray::new(
+                        values.into_iter().map(::as_gd_res::AsGdResDictElement::to_gd_element).collect(),
+                    )
+                }
+
+                fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_array(values))
+                }
+            }
+
+            impl ::as_gd_res::FromGdResArray for SingleVariant {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Vec<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract_with(res, ctx)
+                }
             }
 
             impl ::as_gd_res::ExtractGd for SingleVariantAsGdEnum {
@@ -87,6 +372,154 @@ fn test_single_variant_enum() {
                 }
             }
 
+            impl ::as_gd_res::TryExtractGd for SingleVariantAsGdEnum {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok((*self).into())
+                }
+            }
+
+            #[derive(::godot::prelude::GodotConvert, ::godot::prelude::Var, ::godot::prelude::Export, Clone, Copy, Debug, PartialEq, Eq, Default)]
+            #[godot(via = GString)]
+            pub enum SingleVariantAsGdEnumOpt {
+                #[default]
+                None,
+                Only,
+            }
+
+            impl ::as_gd_res::AsGdResOpt for SingleVariant {
+                type GdOption = SingleVariantAsGdEnumOpt;
+            }
+
+            impl ::as_gd_res::ToGdResOpt for SingleVariant {
+                fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                    match value.copied() {
+                        Some(SingleVariant::Only) => SingleVariantAsGdEnumOpt::Only,
+                        None => SingleVariantAsGdEnumOpt::None,
+                    }
+                }
+
+                fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+                }
+            }
+
+            impl ::as_gd_res::FromGdResOpt for SingleVariant {
+                fn try_from_gd_opt(
+                    res: &Self::GdOption,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(res))
+                }
+            }
+
+            impl ::as_gd_res::ExtractGd for SingleVariantAsGdEnumOpt {
+                type Extracted = Option<SingleVariant>;
+                fn extract(&self) -> Self::Extracted {
+                    match *self {
+                        SingleVariantAsGdEnumOpt::None => None,
+                        SingleVariantAsGdEnumOpt::Only => Some(SingleVariant::Only),
+                    }
+                }
+            }
+
+            impl ::as_gd_res::TryExtractGd for SingleVariantAsGdEnumOpt {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(self))
+                }
+            }
+
+            impl ::as_gd_res::tres::FromTres for SingleVariant {
+                fn from_tres(
+                    value: &::as_gd_res::tres::TresValue,
+                    _doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    match value.as_str() {
+                        Some("Only") => Ok(SingleVariant::Only),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "expected a `{}` variant name, found `{}`",
+                            "SingleVariant", value
+                        ))),
+                    }
+                }
+                fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok(SingleVariant::default())
+                }
+
+                fn from_tres_opt(
+                    value: &::as_gd_res::tres::TresValue,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    if matches!(value, ::as_gd_res::tres::TresValue::Null) || value.as_str() == Some("None") {
+                        return Ok(None);
+                    }
+                    <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+                }
+            }
+
+            impl ::as_gd_res::tres::ToTres for SingleVariant {
+                fn to_tres(&self, _writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                    let stored = match *self {
+                        SingleVariant::Only => "Only",
+                    };
+                    ::as_gd_res::tres::TresValue::String(stored.to_string())
+                }
+                fn tres_type() -> String {
+                    "String".to_string()
+                }
+
+                fn to_tres_opt(
+                    value: Option<&Self>,
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> ::as_gd_res::tres::TresValue {
+                    match value {
+                        Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                        None => ::as_gd_res::tres::TresValue::String("None".to_string()),
+                    }
+                }
+            }
+
+            impl SingleVariant {
+                /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+                pub fn display_name(&self) -> &'static str {
+                    match *self {
+                    SingleVariant::Only => "Only",
+                    }
+                }
+            }
+
+            impl ::as_gd_res::SimpleEnumVariants for SingleVariant {
+                const VARIANTS: &'static [Self] = &[SingleVariant::Only,];
+                fn name(&self) -> &'static str {
+                    match *self {
+                        SingleVariant::Only => "Only",
+                    }
+                }
+                fn index(&self) -> usize {
+                    match *self {
+                        SingleVariant::Only => 0,
+                    }
+                }
+            }
+
+            impl ::std::str::FromStr for SingleVariant {
+                type Err = ::as_gd_res::ExtractError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "Only" => Ok(SingleVariant::Only),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "`{}` is not a variant of `{}`",
+                            s, "SingleVariant"
+                        ))),
+                    }
+                }
+            }
+
+            impl ::std::fmt::Display for SingleVariant {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+                }
+            }
+
             impl From<SingleVariant> for SingleVariantAsGdEnum {
                 fn from(value: SingleVariant) -> SingleVariantAsGdEnum {
                     match value {
@@ -109,7 +542,10 @@ fn test_single_variant_enum() {
         }
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 /// Test enum with many variants to ensure proper handling
@@ -139,6 +575,79 @@ fn test_many_variants_enum() {
 
             impl ::as_gd_res::AsGdEnumSimple for ManyVariants {
                 type GdEnumType = ManyVariantsAsGdEnum;
+                const ENUM_HINT: &'static str = "A,B,C,D,E";
+                const STORED_TYPE: ::godot::builtin::VariantType = ::godot::builtin::VariantType::STRING;
+            }
+
+            impl ::as_gd_res::AsGdRes for ManyVariants {
+                type ResType = ManyVariantsAsGdEnum;
+            }
+
+            impl ::as_gd_res::ToGdRes for ManyVariants {
+                fn to_gd_res(&self) -> Self::ResType {
+                    (*self).into()
+                }
+
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    ::as_gd_res::apply_by_value(res, (*self).into())
+                }
+            }
+
+            impl ::as_gd_res::FromGdRes for ManyVariants {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok((*res).into())
+                }
+            }
+
+            impl ::as_gd_res::AsGdResDictElement for ManyVariants {
+                type GdElement = GString;
+
+                fn to_gd_element(&self) -> Self::GdElement {
+                    ::as_gd_res::simple_enum_to_gd_element(*self)
+                }
+
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::simple_enum_from_gd_element(element)
+                }
+
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    ::as_gd_res::apply_by_value(res, self.to_gd_element())
+                }
+
+                fn default_gd_element() -> ::godot::prelude::Variant {
+                    ::godot::prelude::ToGodot::to_variant(&ManyVariants::default().to_gd_element())
+                }
+            }
+
+            impl ::as_gd_res::AsGdResArray for ManyVariants {
+                type GdArray = ::as_gd_res::GdEnumArray<Self>;
+            }
+
+            impl ::as_gd_res::ToGdResArray for ManyVariants {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    ::as_gd_res::GdEnumArray::new(
+                        values.into_iter().map(::as_gd_res::AsGdResDictElement::to_gd_element).collect(),
+                    )
+                }
+
+                fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_array(values))
+                }
+            }
+
+            impl ::as_gd_res::FromGdResArray for ManyVariants {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Vec<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract_with(res, ctx)
+                }
             }
 
             impl ::as_gd_res::ExtractGd for ManyVariantsAsGdEnum {
@@ -148,6 +657,190 @@ fn test_many_variants_enum() {
                 }
             }
 
+            impl ::as_gd_res::TryExtractGd for ManyVariantsAsGdEnum {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok((*self).into())
+                }
+            }
+
+            #[derive(::godot::prelude::GodotConvert, ::godot::prelude::Var, ::godot::prelude::Export, Clone, Copy, Debug, PartialEq, Eq, Default)]
+            #[godot(via = GString)]
+            pub enum ManyVariantsAsGdEnumOpt {
+                #[default]
+                None,
+                A,
+                B,
+                C,
+                D,
+                E,
+            }
+
+            impl ::as_gd_res::AsGdResOpt for ManyVariants {
+                type GdOption = ManyVariantsAsGdEnumOpt;
+            }
+
+            impl ::as_gd_res::ToGdResOpt for ManyVariants {
+                fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                    match value.copied() {
+                        Some(ManyVariants::A) => ManyVariantsAsGdEnumOpt::A,
+                        Some(ManyVariants::B) => ManyVariantsAsGdEnumOpt::B,
+                        Some(ManyVariants::C) => ManyVariantsAsGdEnumOpt::C,
+                        Some(ManyVariants::D) => ManyVariantsAsGdEnumOpt::D,
+                        Some(ManyVariants::E) => ManyVariantsAsGdEnumOpt::E,
+                        None => ManyVariantsAsGdEnumOpt::None,
+                    }
+                }
+
+                fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+                }
+            }
+
+            impl ::as_gd_res::FromGdResOpt for ManyVariants {
+                fn try_from_gd_opt(
+                    res: &Self::GdOption,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(res))
+                }
+            }
+
+            impl ::as_gd_res::ExtractGd for ManyVariantsAsGdEnumOpt {
+                type Extracted = Option<ManyVariants>;
+                fn extract(&self) -> Self::Extracted {
+                    match *self {
+                        ManyVariantsAsGdEnumOpt::None => None,
+                        ManyVariantsAsGdEnumOpt::A => Some(ManyVariants::A),
+                        ManyVariantsAsGdEnumOpt::B => Some(ManyVariants::B),
+                        ManyVariantsAsGdEnumOpt::C => Some(ManyVariants::C),
+                        ManyVariantsAsGdEnumOpt::D => Some(ManyVariants::D),
+                        ManyVariantsAsGdEnumOpt::E => Some(ManyVariants::E),
+                    }
+                }
+            }
+
+            impl ::as_gd_res::TryExtractGd for ManyVariantsAsGdEnumOpt {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(self))
+                }
+            }
+
+            impl ::as_gd_res::tres::FromTres for ManyVariants {
+                fn from_tres(
+                    value: &::as_gd_res::tres::TresValue,
+                    _doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    match value.as_str() {
+                        Some("A") => Ok(ManyVariants::A),
+                        Some("B") => Ok(ManyVariants::B),
+                        Some("C") => Ok(ManyVariants::C),
+                        Some("D") => Ok(ManyVariants::D),
+                        Some("E") => Ok(ManyVariants::E),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "expected a `{}` variant name, found `{}`",
+                            "ManyVariants", value
+                        ))),
+                    }
+                }
+                fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok(ManyVariants::default())
+                }
+
+                fn from_tres_opt(
+                    value: &::as_gd_res::tres::TresValue,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    if matches!(value, ::as_gd_res::tres::TresValue::Null) || value.as_str() == Some("None") {
+                        return Ok(None);
+                    }
+                    <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+                }
+            }
+
+            impl ::as_gd_res::tres::ToTres for ManyVariants {
+                fn to_tres(&self, _writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                    let stored = match *self {
+                        ManyVariants::A => "A",
+                        ManyVariants::B => "B",
+                        ManyVariants::C => "C",
+                        ManyVariants::D => "D",
+                        ManyVariants::E => "E",
+                    };
+                    ::as_gd_res::tres::TresValue::String(stored.to_string())
+                }
+                fn tres_type() -> String {
+                    "String".to_string()
+                }
+
+                fn to_tres_opt(
+                    value: Option<&Self>,
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> ::as_gd_res::tres::TresValue {
+                    match value {
+                        Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                        None => ::as_gd_res::tres::TresValue::String("None".to_string()),
+                    }
+                }
+            }
+
+            impl ManyVariants {
+                /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+                pub fn display_name(&self) -> &'static str {
+                    match *self {
+                    ManyVariants::A => "A",
+                    ManyVariants::B => "B",
+                    ManyVariants::C => "C",
+                    ManyVariants::D => "D",
+                    ManyVariants::E => "E",
+                    }
+                }
+            }
+
+            impl ::as_gd_res::SimpleEnumVariants for ManyVariants {
+                const VARIANTS: &'static [Self] = &[ManyVariants::A, ManyVariants::B, ManyVariants::C, ManyVariants::D, ManyVariants::E,];
+                fn name(&self) -> &'static str {
+                    match *self {
+                        ManyVariants::A => "A",
+                        ManyVariants::B => "B",
+                        ManyVariants::C => "C",
+                        ManyVariants::D => "D",
+                        ManyVariants::E => "E",
+                    }
+                }
+                fn index(&self) -> usize {
+                    match *self {
+                        ManyVariants::A => 0,
+                        ManyVariants::B => 1,
+                        ManyVariants::C => 2,
+                        ManyVariants::D => 3,
+                        ManyVariants::E => 4,
+                    }
+                }
+            }
+
+            impl ::std::str::FromStr for ManyVariants {
+                type Err = ::as_gd_res::ExtractError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "A" => Ok(ManyVariants::A),
+                        "B" => Ok(ManyVariants::B),
+                        "C" => Ok(ManyVariants::C),
+                        "D" => Ok(ManyVariants::D),
+                        "E" => Ok(ManyVariants::E),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "`{}` is not a variant of `{}`",
+                            s, "ManyVariants"
+                        ))),
+                    }
+                }
+            }
+
+            impl ::std::fmt::Display for ManyVariants {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+                }
+            }
+
             impl From<ManyVariants> for ManyVariantsAsGdEnum {
                 fn from(value: ManyVariants) -> ManyVariantsAsGdEnum {
                     match value {
@@ -178,7 +871,10 @@ fn test_many_variants_enum() {
         }
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 /// Test enum with private visibility
@@ -205,6 +901,79 @@ fn test_private_enum() {
 
             impl ::as_gd_res::AsGdEnumSimple for PrivateEnum {
                 type GdEnumType = PrivateEnumAsGdEnum;
+                const ENUM_HINT: &'static str = "A,B";
+                const STORED_TYPE: ::godot::builtin::VariantType = ::godot::builtin::VariantType::STRING;
+            }
+
+            impl ::as_gd_res::AsGdRes for PrivateEnum {
+                type ResType = PrivateEnumAsGdEnum;
+            }
+
+            impl ::as_gd_res::ToGdRes for PrivateEnum {
+                fn to_gd_res(&self) -> Self::ResType {
+                    (*self).into()
+                }
+
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    ::as_gd_res::apply_by_value(res, (*self).into())
+                }
+            }
+
+            impl ::as_gd_res::FromGdRes for PrivateEnum {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok((*res).into())
+                }
+            }
+
+            impl ::as_gd_res::AsGdResDictElement for PrivateEnum {
+                type GdElement = GString;
+
+                fn to_gd_element(&self) -> Self::GdElement {
+                    ::as_gd_res::simple_enum_to_gd_element(*self)
+                }
+
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::simple_enum_from_gd_element(element)
+                }
+
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    ::as_gd_res::apply_by_value(res, self.to_gd_element())
+                }
+
+                fn default_gd_element() -> ::godot::prelude::Variant {
+                    ::godot::prelude::ToGodot::to_variant(&PrivateEnum::default().to_gd_element())
+                }
+            }
+
+            impl ::as_gd_res::AsGdResArray for PrivateEnum {
+                type GdArray = ::as_gd_res::GdEnumArray<Self>;
+            }
+
+            impl ::as_gd_res::ToGdResArray for PrivateEnum {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    ::as_gd_res::GdEnumArray::new(
+                        values.into_iter().map(::as_gd_res::AsGdResDictElement::to_gd_element).collect(),
+                    )
+                }
+
+                fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_array(values))
+                }
+            }
+
+            impl ::as_gd_res::FromGdResArray for PrivateEnum {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Vec<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract_with(res, ctx)
+                }
             }
 
             impl ::as_gd_res::ExtractGd for PrivateEnumAsGdEnum {
@@ -214,6 +983,163 @@ fn test_private_enum() {
                 }
             }
 
+            impl ::as_gd_res::TryExtractGd for PrivateEnumAsGdEnum {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok((*self).into())
+                }
+            }
+
+            #[derive(::godot::prelude::GodotConvert, ::godot::prelude::Var, ::godot::prelude::Export, Clone, Copy, Debug, PartialEq, Eq, Default)]
+            #[godot(via = GString)]
+            pub enum PrivateEnumAsGdEnumOpt {
+                #[default]
+                None,
+                A,
+                B,
+            }
+
+            impl ::as_gd_res::AsGdResOpt for PrivateEnum {
+                type GdOption = PrivateEnumAsGdEnumOpt;
+            }
+
+            impl ::as_gd_res::ToGdResOpt for PrivateEnum {
+                fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                    match value.copied() {
+                        Some(PrivateEnum::A) => PrivateEnumAsGdEnumOpt::A,
+                        Some(PrivateEnum::B) => PrivateEnumAsGdEnumOpt::B,
+                        None => PrivateEnumAsGdEnumOpt::None,
+                    }
+                }
+
+                fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+                }
+            }
+
+            impl ::as_gd_res::FromGdResOpt for PrivateEnum {
+                fn try_from_gd_opt(
+                    res: &Self::GdOption,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(res))
+                }
+            }
+
+            impl ::as_gd_res::ExtractGd for PrivateEnumAsGdEnumOpt {
+                type Extracted = Option<PrivateEnum>;
+                fn extract(&self) -> Self::Extracted {
+                    match *self {
+                        PrivateEnumAsGdEnumOpt::None => None,
+                        PrivateEnumAsGdEnumOpt::A => Some(PrivateEnum::A),
+                        PrivateEnumAsGdEnumOpt::B => Some(PrivateEnum::B),
+                    }
+                }
+            }
+
+            impl ::as_gd_res::TryExtractGd for PrivateEnumAsGdEnumOpt {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(self))
+                }
+            }
+
+            impl ::as_gd_res::tres::FromTres for PrivateEnum {
+                fn from_tres(
+                    value: &::as_gd_res::tres::TresValue,
+                    _doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    match value.as_str() {
+                        Some("A") => Ok(PrivateEnum::A),
+                        Some("B") => Ok(PrivateEnum::B),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "expected a `{}` variant name, found `{}`",
+                            "PrivateEnum", value
+                        ))),
+                    }
+                }
+                fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok(PrivateEnum::default())
+                }
+
+                fn from_tres_opt(
+                    value: &::as_gd_res::tres::TresValue,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    if matches!(value, ::as_gd_res::tres::TresValue::Null) || value.as_str() == Some("None") {
+                        return Ok(None);
+                    }
+                    <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+                }
+            }
+
+            impl ::as_gd_res::tres::ToTres for PrivateEnum {
+                fn to_tres(&self, _writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                    let stored = match *self {
+                        PrivateEnum::A => "A",
+                        PrivateEnum::B => "B",
+                    };
+                    ::as_gd_res::tres::TresValue::String(stored.to_string())
+                }
+                fn tres_type() -> String {
+                    "String".to_string()
+                }
+
+                fn to_tres_opt(
+                    value: Option<&Self>,
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> ::as_gd_res::tres::TresValue {
+                    match value {
+                        Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                        None => ::as_gd_res::tres::TresValue::String("None".to_string()),
+                    }
+                }
+            }
+
+            impl PrivateEnum {
+                /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+                pub fn display_name(&self) -> &'static str {
+                    match *self {
+                    PrivateEnum::A => "A",
+                    PrivateEnum::B => "B",
+                    }
+                }
+            }
+
+            impl ::as_gd_res::SimpleEnumVariants for PrivateEnum {
+                const VARIANTS: &'static [Self] = &[PrivateEnum::A, PrivateEnum::B,];
+                fn name(&self) -> &'static str {
+                    match *self {
+                        PrivateEnum::A => "A",
+                        PrivateEnum::B => "B",
+                    }
+                }
+                fn index(&self) -> usize {
+                    match *self {
+                        PrivateEnum::A => 0,
+                        PrivateEnum::B => 1,
+                    }
+                }
+            }
+
+            impl ::std::str::FromStr for PrivateEnum {
+                type Err = ::as_gd_res::ExtractError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "A" => Ok(PrivateEnum::A),
+                        "B" => Ok(PrivateEnum::B),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "`{}` is not a variant of `{}`",
+                            s, "PrivateEnum"
+                        ))),
+                    }
+                }
+            }
+
+            impl ::std::fmt::Display for PrivateEnum {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+                }
+            }
+
             impl From<PrivateEnum> for PrivateEnumAsGdEnum {
                 fn from(value: PrivateEnum) -> PrivateEnumAsGdEnum {
                     match value {
@@ -238,7 +1164,10 @@ fn test_private_enum() {
         }
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 /// Test enum with struct variant (named fields) - should error
@@ -255,7 +1184,10 @@ fn test_struct_variant_error() {
         compile_error!("`derive(AsGdEnumSimple)` only supports unit enums. Unsupported variants: B{x: i32, y: i32}.\nDid you mean to derive `AsGdRes`?");
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 /// Test enum with multiple tuple fields - should error
@@ -272,5 +1204,8 @@ fn test_multi_tuple_variant_error() {
         compile_error!("`derive(AsGdEnumSimple)` only supports unit enums. Unsupported variants: B(i32, i32).\nDid you mean to derive `AsGdRes`?");
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
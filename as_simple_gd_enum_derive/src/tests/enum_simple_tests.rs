@@ -30,6 +30,79 @@ fn test_simple_enum() {
         }
         impl ::as_gd_res::AsGdEnumSimple for Element {
             type GdEnumType = ElementAsGdEnum;
+            const ENUM_HINT: &'static str = "Fire,Water,Earth,Air";
+            const STORED_TYPE: ::godot::builtin::VariantType = ::godot::builtin::VariantType::STRING;
+        }
+
+        impl ::as_gd_res::AsGdRes for Element {
+            type ResType = ElementAsGdEnum;
+        }
+
+        impl ::as_gd_res::ToGdRes for Element {
+            fn to_gd_res(&self) -> Self::ResType {
+                (*self).into()
+            }
+
+            fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                ::as_gd_res::apply_by_value(res, (*self).into())
+            }
+        }
+
+        impl ::as_gd_res::FromGdRes for Element {
+            fn try_from_gd_res_with(
+                res: &Self::ResType,
+                _ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                Ok((*res).into())
+            }
+        }
+
+        impl ::as_gd_res::AsGdResDictElement for Element {
+            type GdElement = GString;
+
+            fn to_gd_element(&self) -> Self::GdElement {
+                ::as_gd_res::simple_enum_to_gd_element(*self)
+            }
+
+            fn try_from_gd_element(
+                element: &Self::GdElement,
+                _ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                ::as_gd_res::simple_enum_from_gd_element(element)
+            }
+
+            fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                ::as_gd_res::apply_by_value(res, self.to_gd_element())
+            }
+
+            fn default_gd_element() -> ::godot::prelude::Variant {
+                ::godot::prelude::ToGodot::to_variant(&Element::default().to_gd_element())
+            }
+        }
+
+        impl ::as_gd_res::AsGdResArray for Element {
+            type GdArray = ::as_gd_res::GdEnumArray<Self>;
+        }
+
+        impl ::as_gd_res::ToGdResArray for Element {
+            fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                ::as_gd_res::GdEnumArray::new(
+                    values.into_iter().map(::as_gd_res::AsGdResDictElement::to_gd_element).collect(),
+                )
+            }
+
+            fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                ::as_gd_res::apply_by_value(res, Self::to_gd_array(values))
+            }
+        }
+
+        impl ::as_gd_res::FromGdResArray for Element {
+            fn try_from_gd_array(
+                res: &Self::GdArray,
+                ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Vec<Self>, ::as_gd_res::ExtractError> {
+                ::as_gd_res::TryExtractGd::try_extract_with(res, ctx)
+            }
         }
 
         impl ::as_gd_res::ExtractGd for ElementAsGdEnum {
@@ -39,6 +112,181 @@ fn test_simple_enum() {
             }
         }
 
+        impl ::as_gd_res::TryExtractGd for ElementAsGdEnum {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                Ok((*self).into())
+            }
+        }
+
+        #[derive(::godot::prelude::GodotConvert, ::godot::prelude::Var, ::godot::prelude::Export, Clone, Copy, Debug, PartialEq, Eq, Default)]
+        #[godot(via = GString)]
+        pub enum ElementAsGdEnumOpt {
+            #[default]
+            None,
+            Fire,
+            Water,
+            Earth,
+            Air,
+        }
+
+        impl ::as_gd_res::AsGdResOpt for Element {
+            type GdOption = ElementAsGdEnumOpt;
+        }
+
+        impl ::as_gd_res::ToGdResOpt for Element {
+            fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                match value.copied() {
+                    Some(Element::Fire) => ElementAsGdEnumOpt::Fire,
+                    Some(Element::Water) => ElementAsGdEnumOpt::Water,
+                    Some(Element::Earth) => ElementAsGdEnumOpt::Earth,
+                    Some(Element::Air) => ElementAsGdEnumOpt::Air,
+                    None => ElementAsGdEnumOpt::None,
+                }
+            }
+
+            fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+            }
+        }
+
+        impl ::as_gd_res::FromGdResOpt for Element {
+            fn try_from_gd_opt(
+                res: &Self::GdOption,
+                _ctx: &mut ::as_gd_res::ExtractContext,
+            ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                Ok(::as_gd_res::ExtractGd::extract(res))
+            }
+        }
+
+        impl ::as_gd_res::ExtractGd for ElementAsGdEnumOpt {
+            type Extracted = Option<Element>;
+            fn extract(&self) -> Self::Extracted {
+                match *self {
+                    ElementAsGdEnumOpt::None => None,
+                    ElementAsGdEnumOpt::Fire => Some(Element::Fire),
+                    ElementAsGdEnumOpt::Water => Some(Element::Water),
+                    ElementAsGdEnumOpt::Earth => Some(Element::Earth),
+                    ElementAsGdEnumOpt::Air => Some(Element::Air),
+                }
+            }
+        }
+
+        impl ::as_gd_res::TryExtractGd for ElementAsGdEnumOpt {
+            fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                Ok(::as_gd_res::ExtractGd::extract(self))
+            }
+        }
+
+        impl ::as_gd_res::tres::FromTres for Element {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                _doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match value.as_str() {
+                    Some("Fire") => Ok(Element::Fire),
+                    Some("Water") => Ok(Element::Water),
+                    Some("Earth") => Ok(Element::Earth),
+                    Some("Air") => Ok(Element::Air),
+                    _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "expected a `{}` variant name, found `{}`",
+                        "Element", value
+                    ))),
+                }
+            }
+            fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                Ok(Element::default())
+            }
+
+            fn from_tres_opt(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                if matches!(value, ::as_gd_res::tres::TresValue::Null) || value.as_str() == Some("None") {
+                    return Ok(None);
+                }
+                <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Element {
+            fn to_tres(&self, _writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                let stored = match *self {
+                    Element::Fire => "Fire",
+                    Element::Water => "Water",
+                    Element::Earth => "Earth",
+                    Element::Air => "Air",
+                };
+                ::as_gd_res::tres::TresValue::String(stored.to_string())
+            }
+            fn tres_type() -> String {
+                "String".to_string()
+            }
+
+            fn to_tres_opt(
+                value: Option<&Self>,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> ::as_gd_res::tres::TresValue {
+                match value {
+                    Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                    None => ::as_gd_res::tres::TresValue::String("None".to_string()),
+                }
+            }
+        }
+
+        impl Element {
+            /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+            pub fn display_name(&self) -> &'static str {
+                match *self {
+                    Element::Fire => "Fire",
+                    Element::Water => "Water",
+                    Element::Earth => "Earth",
+                    Element::Air => "Air",
+                }
+            }
+        }
+
+        impl ::as_gd_res::SimpleEnumVariants for Element {
+            const VARIANTS: &'static [Self] = &[Element::Fire, Element::Water, Element::Earth, Element::Air,];
+            fn name(&self) -> &'static str {
+                match *self {
+                    Element::Fire => "Fire",
+                    Element::Water => "Water",
+                    Element::Earth => "Earth",
+                    Element::Air => "Air",
+                }
+            }
+            fn index(&self) -> usize {
+                match *self {
+                    Element::Fire => 0,
+                    Element::Water => 1,
+                    Element::Earth => 2,
+                    Element::Air => 3,
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for Element {
+            type Err = ::as_gd_res::ExtractError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    "Fire" => Ok(Element::Fire),
+                    "Water" => Ok(Element::Water),
+                    "Earth" => Ok(Element::Earth),
+                    "Air" => Ok(Element::Air),
+                    _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a variant of `{}`",
+                        s, "Element"
+                    ))),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for Element {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+            }
+        }
+
         impl From<Element> for ElementAsGdEnum {
             fn from(value: Element) -> ElementAsGdEnum {
                 match value {
@@ -67,7 +315,10 @@ fn test_simple_enum() {
     }
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 #[test]
@@ -89,7 +340,10 @@ fn test_enum_with_data_error() {
 
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 #[test]
@@ -108,7 +362,10 @@ fn test_struct_error() {
                 );
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 #[test]
@@ -127,7 +384,10 @@ fn test_union_error() {
                 );
     };
 
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
 
 #[test]
@@ -141,5 +401,1463 @@ fn test_generic_enum_error() {
     let expected = quote! {
         compile_error!("`derive(AsGdEnumSimple)` does not support generics");
     };
-    assert_eq!(expand_as_gd_res(input).to_string(), expected.to_string());
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_enum_without_godot() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Surface {
+            #[default]
+            Ground,
+            Ice,
+        }
+    };
+    let expected = quote! {
+        impl ::as_gd_res::tres::FromTres for Surface {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                _doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match value.as_str() {
+                    Some("Ground") => Ok(Surface::Ground),
+                    Some("Ice") => Ok(Surface::Ice),
+                    _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "expected a `{}` variant name, found `{}`",
+                        "Surface", value
+                    ))),
+                }
+            }
+            fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                Ok(Surface::default())
+            }
+
+            fn from_tres_opt(
+                value: &::as_gd_res::tres::TresValue,
+                doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                if matches!(value, ::as_gd_res::tres::TresValue::Null) || value.as_str() == Some("None") {
+                    return Ok(None);
+                }
+                <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for Surface {
+            fn to_tres(&self, _writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                let stored = match *self {
+                    Surface::Ground => "Ground",
+                    Surface::Ice => "Ice",
+                };
+                ::as_gd_res::tres::TresValue::String(stored.to_string())
+            }
+            fn tres_type() -> String {
+                "String".to_string()
+            }
+
+            fn to_tres_opt(
+                value: Option<&Self>,
+                writer: &mut ::as_gd_res::tres::TresWriter,
+            ) -> ::as_gd_res::tres::TresValue {
+                match value {
+                    Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                    None => ::as_gd_res::tres::TresValue::String("None".to_string()),
+                }
+            }
+        }
+
+        impl Surface {
+            /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+            pub fn display_name(&self) -> &'static str {
+                match *self {
+                    Surface::Ground => "Ground",
+                    Surface::Ice => "Ice",
+                }
+            }
+        }
+
+        impl ::as_gd_res::SimpleEnumVariants for Surface {
+            const VARIANTS: &'static [Self] = &[Surface::Ground, Surface::Ice,];
+            fn name(&self) -> &'static str {
+                match *self {
+                    Surface::Ground => "Ground",
+                    Surface::Ice => "Ice",
+                }
+            }
+            fn index(&self) -> usize {
+                match *self {
+                    Surface::Ground => 0,
+                    Surface::Ice => 1,
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for Surface {
+            type Err = ::as_gd_res::ExtractError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    "Ground" => Ok(Surface::Ground),
+                    "Ice" => Ok(Surface::Ice),
+                    _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a variant of `{}`",
+                        s, "Surface"
+                    ))),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for Surface {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+            }
+        }
+    };
+    assert_eq!(
+        expand_as_gd_res(input, false).to_string(),
+        expected.to_string()
+    );
+}
+
+// An enum with its own `None` variant stores `Option::None` as `null`, like other types.
+#[test]
+fn test_enum_with_none_variant_without_godot() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum DamageType {
+            #[default]
+            None,
+            Fire,
+        }
+    };
+    let expected = quote! {
+        impl ::as_gd_res::tres::FromTres for DamageType {
+            fn from_tres(
+                value: &::as_gd_res::tres::TresValue,
+                _doc: &::as_gd_res::tres::TresDocument,
+            ) -> Result<Self, ::as_gd_res::ExtractError> {
+                match value.as_str() {
+                    Some("None") => Ok(DamageType::None),
+                    Some("Fire") => Ok(DamageType::Fire),
+                    _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "expected a `{}` variant name, found `{}`",
+                        "DamageType", value
+                    ))),
+                }
+            }
+            fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                Ok(DamageType::default())
+            }
+        }
+
+        impl ::as_gd_res::tres::ToTres for DamageType {
+            fn to_tres(&self, _writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                let stored = match *self {
+                    DamageType::None => "None",
+                    DamageType::Fire => "Fire",
+                };
+                ::as_gd_res::tres::TresValue::String(stored.to_string())
+            }
+            fn tres_type() -> String {
+                "String".to_string()
+            }
+        }
+
+        impl DamageType {
+            /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+            pub fn display_name(&self) -> &'static str {
+                match *self {
+                    DamageType::None => "None",
+                    DamageType::Fire => "Fire",
+                }
+            }
+        }
+
+        impl ::as_gd_res::SimpleEnumVariants for DamageType {
+            const VARIANTS: &'static [Self] = &[DamageType::None, DamageType::Fire,];
+            fn name(&self) -> &'static str {
+                match *self {
+                    DamageType::None => "None",
+                    DamageType::Fire => "Fire",
+                }
+            }
+            fn index(&self) -> usize {
+                match *self {
+                    DamageType::None => 0,
+                    DamageType::Fire => 1,
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for DamageType {
+            type Err = ::as_gd_res::ExtractError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    "None" => Ok(DamageType::None),
+                    "Fire" => Ok(DamageType::Fire),
+                    _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                        "`{}` is not a variant of `{}`",
+                        s, "DamageType"
+                    ))),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for DamageType {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+            }
+        }
+    };
+    assert_eq!(
+        expand_as_gd_res(input, false).to_string(),
+        expected.to_string()
+    );
+}
+
+// `repr = int` stores discriminants (implicit ones counting up from the previous one)
+// and `None` as -1.
+#[test]
+fn test_repr_int_enum() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_enum(repr = int)]
+        pub enum Priority {
+            #[default]
+            Low = 10,
+            High,
+            Off = -5,
+        }
+    };
+
+    let expected = quote! {
+        pub use mod_priorityasgdenum::*;
+        mod mod_priorityasgdenum {
+            use super::Priority;
+            use ::godot::prelude::GString;
+
+            #[derive(::godot::prelude::GodotConvert, ::godot::prelude::Var, ::godot::prelude::Export, Clone, Copy, Debug, PartialEq, Eq)]
+            #[godot(via = i64)]
+            pub enum PriorityAsGdEnum {
+                Low = 10,
+                High = 11,
+                Off = -5,
+            }
+
+            impl ::as_gd_res::AsGdEnumSimple for Priority {
+                type GdEnumType = PriorityAsGdEnum;
+                const ENUM_HINT: &'static str = "Low:10,High:11,Off:-5";
+                const STORED_TYPE: ::godot::builtin::VariantType = ::godot::builtin::VariantType::INT;
+            }
+
+            impl ::as_gd_res::AsGdRes for Priority {
+                type ResType = ::as_gd_res::GdEnumInt<Self>;
+            }
+
+            impl ::as_gd_res::ToGdRes for Priority {
+                fn to_gd_res(&self) -> Self::ResType {
+                    ::as_gd_res::GdEnumInt::new(*self)
+                }
+
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    ::as_gd_res::apply_by_value(res, ::as_gd_res::GdEnumInt::new(*self))
+                }
+            }
+
+            impl ::as_gd_res::FromGdRes for Priority {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract(res)
+                }
+            }
+
+            impl ::as_gd_res::AsGdResDictElement for Priority {
+                type GdElement = i64;
+
+                fn to_gd_element(&self) -> Self::GdElement {
+                    ::as_gd_res::simple_enum_to_gd_element(*self)
+                }
+
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::simple_enum_from_gd_element(element)
+                }
+
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    ::as_gd_res::apply_by_value(res, self.to_gd_element())
+                }
+
+                fn default_gd_element() -> ::godot::prelude::Variant {
+                    ::godot::prelude::ToGodot::to_variant(&Priority::default().to_gd_element())
+                }
+            }
+
+            impl ::as_gd_res::AsGdResArray for Priority {
+                type GdArray = ::as_gd_res::GdEnumArray<Self>;
+            }
+
+            impl ::as_gd_res::ToGdResArray for Priority {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    ::as_gd_res::GdEnumArray::new(
+                        values.into_iter().map(::as_gd_res::AsGdResDictElement::to_gd_element).collect(),
+                    )
+                }
+
+                fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_array(values))
+                }
+            }
+
+            impl ::as_gd_res::FromGdResArray for Priority {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Vec<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract_with(res, ctx)
+                }
+            }
+
+            impl ::as_gd_res::ExtractGd for PriorityAsGdEnum {
+                type Extracted = Priority;
+                fn extract(&self) -> Self::Extracted {
+                    (*self).into()
+                }
+            }
+
+            impl ::as_gd_res::TryExtractGd for PriorityAsGdEnum {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok((*self).into())
+                }
+            }
+
+            impl ::as_gd_res::AsGdResOpt for Priority {
+                type GdOption = ::as_gd_res::GdEnumIntOpt<Self>;
+            }
+
+            impl ::as_gd_res::ToGdResOpt for Priority {
+                fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                    ::as_gd_res::GdEnumIntOpt::new(value.copied())
+                }
+
+                fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+                }
+            }
+
+            impl ::as_gd_res::FromGdResOpt for Priority {
+                fn try_from_gd_opt(
+                    res: &Self::GdOption,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract(res)
+                }
+            }
+
+            impl ::as_gd_res::tres::FromTres for Priority {
+                fn from_tres(
+                    value: &::as_gd_res::tres::TresValue,
+                    _doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    match value.as_int() {
+                        Some(10) => Ok(Priority::Low),
+                        Some(11) => Ok(Priority::High),
+                        Some(-5) => Ok(Priority::Off),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "expected a `{}` discriminant, found `{}`",
+                            "Priority", value
+                        ))),
+                    }
+                }
+                fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok(Priority::default())
+                }
+
+                fn from_tres_opt(
+                    value: &::as_gd_res::tres::TresValue,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    if matches!(value, ::as_gd_res::tres::TresValue::Null) || value.as_int() == Some(-1) {
+                        return Ok(None);
+                    }
+                    <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+                }
+            }
+
+            impl ::as_gd_res::tres::ToTres for Priority {
+                fn to_tres(&self, _writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                    let stored = match *self {
+                        Priority::Low => 10,
+                        Priority::High => 11,
+                        Priority::Off => -5,
+                    };
+                    ::as_gd_res::tres::TresValue::Int(stored)
+                }
+                fn tres_type() -> String {
+                    "int".to_string()
+                }
+
+                fn to_tres_opt(
+                    value: Option<&Self>,
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> ::as_gd_res::tres::TresValue {
+                    match value {
+                        Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                        None => ::as_gd_res::tres::TresValue::Int(-1),
+                    }
+                }
+            }
+
+            impl Priority {
+                /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+                pub fn display_name(&self) -> &'static str {
+                    match *self {
+                    Priority::Low => "Low",
+                    Priority::High => "High",
+                    Priority::Off => "Off",
+                    }
+                }
+            }
+
+            impl ::as_gd_res::SimpleEnumVariants for Priority {
+                const VARIANTS: &'static [Self] = &[Priority::Low, Priority::High, Priority::Off,];
+                fn name(&self) -> &'static str {
+                    match *self {
+                        Priority::Low => "Low",
+                        Priority::High => "High",
+                        Priority::Off => "Off",
+                    }
+                }
+                fn index(&self) -> usize {
+                    match *self {
+                        Priority::Low => 0,
+                        Priority::High => 1,
+                        Priority::Off => 2,
+                    }
+                }
+            }
+
+            impl ::std::str::FromStr for Priority {
+                type Err = ::as_gd_res::ExtractError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "Low" => Ok(Priority::Low),
+                        "High" => Ok(Priority::High),
+                        "Off" => Ok(Priority::Off),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "`{}` is not a variant of `{}`",
+                            s, "Priority"
+                        ))),
+                    }
+                }
+            }
+
+            impl ::std::fmt::Display for Priority {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+                }
+            }
+
+            impl From<Priority> for PriorityAsGdEnum {
+                fn from(value: Priority) -> PriorityAsGdEnum {
+                    match value {
+                        Priority::Low => PriorityAsGdEnum::Low,
+                        Priority::High => PriorityAsGdEnum::High,
+                        Priority::Off => PriorityAsGdEnum::Off,
+                    }
+                }
+            }
+            impl From<PriorityAsGdEnum> for Priority {
+                fn from(value: PriorityAsGdEnum) -> Priority {
+                    match value {
+                        PriorityAsGdEnum::Low => Priority::Low,
+                        PriorityAsGdEnum::High => Priority::High,
+                        PriorityAsGdEnum::Off => Priority::Off,
+                    }
+                }
+            }
+            impl Default for PriorityAsGdEnum {
+                fn default() -> Self {
+                    Priority::default().into()
+                }
+            }
+        }
+    };
+
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_repr_int_non_literal_discriminant_error() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_enum(repr = int)]
+        pub enum Priority {
+            Low = BASE,
+        }
+    };
+    let expected = quote! {
+        compile_error!("`#[as_gd_enum(repr = int)]` requires integer literal discriminants");
+    };
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+// Renamed variants are stored under their Godot-side name. Aliases and labels are not Godot-side
+// variants: `Var` is implemented by hand so that setting one stores the variant it belongs to, and
+// the hint shows the labels.
+#[test]
+fn test_renamed_and_aliased_variants() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Weather {
+            #[default]
+            #[as_gd_enum(rename = "Sunny", alias = "Clear", display = "Clear skies")]
+            Sun,
+            #[as_gd_enum(alias = "Rainy", alias = "Storm")]
+            Rain,
+        }
+    };
+    let expected = quote! {
+        pub use mod_weatherasgdenum::*;
+        mod mod_weatherasgdenum {
+            use super::Weather;
+            use ::godot::prelude::GString;
+            #[derive(::godot::prelude::GodotConvert, Clone, Copy, Debug, PartialEq, Eq)]
+            #[godot(via = GString)]
+            pub enum WeatherAsGdEnum {
+                Sunny,
+                Rain,
+            }
+            impl ::godot::prelude::Var for WeatherAsGdEnum {
+                fn get_property(&self) -> GString {
+                    GString::from(Weather::from(*self).display_name())
+                }
+                fn set_property(&mut self, value: GString) {
+                    match value.to_string().parse::<Weather>() {
+                        Ok(value) => *self = value.into(),
+                        Err(err) => ::godot::global::godot_error!("{}", err),
+                    }
+                }
+                fn var_hint() -> ::godot::meta::PropertyHintInfo {
+                    ::as_gd_res::enum_property_hint("Clear skies,Rain")
+                }
+            }
+            impl ::godot::prelude::Export for WeatherAsGdEnum {}
+            impl ::as_gd_res::AsGdEnumSimple for Weather {
+                type GdEnumType = WeatherAsGdEnum;
+                const ENUM_HINT: &'static str = "Clear skies,Rain";
+                const STORED_TYPE: ::godot::builtin::VariantType = ::godot::builtin::VariantType::STRING;
+            }
+            impl ::as_gd_res::AsGdRes for Weather {
+                type ResType = WeatherAsGdEnum;
+            }
+            impl ::as_gd_res::ToGdRes for Weather {
+                fn to_gd_res(&self) -> Self::ResType {
+                    (*self).into()
+                }
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    ::as_gd_res::apply_by_value(res, (*self).into())
+                }
+            }
+            impl ::as_gd_res::FromGdRes for Weather {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok((*res).into())
+                }
+            }
+            impl ::as_gd_res::AsGdResDictElement for Weather {
+                type GdElement = GString;
+                fn to_gd_element(&self) -> Self::GdElement {
+                    ::as_gd_res::simple_enum_to_gd_element(*self)
+                }
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::simple_enum_from_gd_element(element)
+                }
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    ::as_gd_res::apply_by_value(res, self.to_gd_element())
+                }
+                fn default_gd_element() -> ::godot::prelude::Variant {
+                    ::godot::prelude::ToGodot::to_variant(&Weather::default().to_gd_element())
+                }
+            }
+            impl ::as_gd_res::AsGdResArray for Weather {
+                type GdArray = ::as_gd_res::GdEnumArray<Self>;
+            }
+            impl ::as_gd_res::ToGdResArray for Weather {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    ::as_gd_res::GdEnumArray::new(
+                        values
+                            .into_iter()
+                            .map(::as_gd_res::AsGdResDictElement::to_gd_element)
+                            .collect(),
+                    )
+                }
+                fn apply_to_gd_array<'a>(
+                    values: impl IntoIterator<Item = &'a Self>,
+                    res: &mut Self::GdArray
+                ) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_array(values))
+                }
+            }
+            impl ::as_gd_res::FromGdResArray for Weather {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Vec<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract_with(res, ctx)
+                }
+            }
+            impl ::as_gd_res::ExtractGd for WeatherAsGdEnum {
+                type Extracted = Weather;
+                fn extract(&self) -> Self::Extracted {
+                    (*self).into()
+                }
+            }
+            impl ::as_gd_res::TryExtractGd for WeatherAsGdEnum {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok((*self).into())
+                }
+            }
+            #[derive(::godot::prelude::GodotConvert, Clone, Copy, Debug, PartialEq, Eq, Default)]
+            #[godot(via = GString)]
+            pub enum WeatherAsGdEnumOpt {
+                #[default]
+                None,
+                Sunny,
+                Rain,
+            }
+            impl ::as_gd_res::AsGdResOpt for Weather {
+                type GdOption = WeatherAsGdEnumOpt;
+            }
+            impl ::as_gd_res::ToGdResOpt for Weather {
+                fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                    match value.copied() {
+                        Some(Weather::Sun) => WeatherAsGdEnumOpt::Sunny,
+                        Some(Weather::Rain) => WeatherAsGdEnumOpt::Rain,
+                        None => WeatherAsGdEnumOpt::None,
+                    }
+                }
+                fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+                }
+            }
+            impl ::as_gd_res::FromGdResOpt for Weather {
+                fn try_from_gd_opt(
+                    res: &Self::GdOption,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(res))
+                }
+            }
+            impl ::as_gd_res::ExtractGd for WeatherAsGdEnumOpt {
+                type Extracted = Option<Weather>;
+                fn extract(&self) -> Self::Extracted {
+                    match *self {
+                        WeatherAsGdEnumOpt::None => None,
+                        WeatherAsGdEnumOpt::Sunny => Some(Weather::Sun),
+                        WeatherAsGdEnumOpt::Rain => Some(Weather::Rain),
+                    }
+                }
+            }
+            impl ::as_gd_res::TryExtractGd for WeatherAsGdEnumOpt {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(self))
+                }
+            }
+            impl ::godot::prelude::Var for WeatherAsGdEnumOpt {
+                fn get_property(&self) -> GString {
+                    match ::as_gd_res::ExtractGd::extract(self) {
+                        Some(value) => GString::from(value.display_name()),
+                        None => GString::from("None"),
+                    }
+                }
+                fn set_property(&mut self, value: GString) {
+                    let value = value.to_string();
+                    if value == "None" {
+                        *self = WeatherAsGdEnumOpt::None;
+                        return;
+                    }
+                    match value.parse::<Weather>() {
+                        Ok(value) => *self = <Weather as ::as_gd_res::ToGdResOpt>::to_gd_opt(Some(&value)),
+                        Err(err) => ::godot::global::godot_error!("{}", err),
+                    }
+                }
+                fn var_hint() -> ::godot::meta::PropertyHintInfo {
+                    ::as_gd_res::enum_property_hint("None,Clear skies,Rain")
+                }
+            }
+            impl ::godot::prelude::Export for WeatherAsGdEnumOpt {}
+            impl ::as_gd_res::tres::FromTres for Weather {
+                fn from_tres(
+                    value: &::as_gd_res::tres::TresValue,
+                    _doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    match value.as_str() {
+                        Some("Sunny") => Ok(Weather::Sun),
+                        Some("Rain") => Ok(Weather::Rain),
+                        Some("Clear") => Ok(Weather::Sun),
+                        Some("Rainy") => Ok(Weather::Rain),
+                        Some("Storm") => Ok(Weather::Rain),
+                        Some("Clear skies") => Ok(Weather::Sun),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "expected a `{}` variant name, found `{}`",
+                            "Weather", value
+                        ))),
+                    }
+                }
+                fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok(Weather::default())
+                }
+                fn from_tres_opt(
+                    value: &::as_gd_res::tres::TresValue,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    if matches!(value, ::as_gd_res::tres::TresValue::Null) || value.as_str() == Some("None")
+                    {
+                        return Ok(None);
+                    }
+                    <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+                }
+            }
+            impl ::as_gd_res::tres::ToTres for Weather {
+                fn to_tres(
+                    &self,
+                    _writer: &mut ::as_gd_res::tres::TresWriter
+                ) -> ::as_gd_res::tres::TresValue {
+                    let stored = match *self {
+                        Weather::Sun => "Sunny",
+                        Weather::Rain => "Rain",
+                    };
+                    ::as_gd_res::tres::TresValue::String(stored.to_string())
+                }
+                fn tres_type() -> String {
+                    "String".to_string()
+                }
+                fn to_tres_opt(
+                    value: Option<&Self>,
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> ::as_gd_res::tres::TresValue {
+                    match value {
+                        Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                        None => ::as_gd_res::tres::TresValue::String("None".to_string()),
+                    }
+                }
+            }
+            impl Weather {
+                /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+                pub fn display_name(&self) -> &'static str {
+                    match *self {
+                        Weather::Sun => "Clear skies",
+                        Weather::Rain => "Rain",
+                    }
+                }
+            }
+
+            impl ::as_gd_res::SimpleEnumVariants for Weather {
+                const VARIANTS: &'static [Self] = &[Weather::Sun, Weather::Rain,];
+                fn name(&self) -> &'static str {
+                    match *self {
+                        Weather::Sun => "Sunny",
+                        Weather::Rain => "Rain",
+                    }
+                }
+                fn index(&self) -> usize {
+                    match *self {
+                        Weather::Sun => 0,
+                        Weather::Rain => 1,
+                    }
+                }
+            }
+            impl ::std::str::FromStr for Weather {
+                type Err = ::as_gd_res::ExtractError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "Sunny" => Ok(Weather::Sun),
+                        "Rain" => Ok(Weather::Rain),
+                        "Clear" => Ok(Weather::Sun),
+                        "Rainy" => Ok(Weather::Rain),
+                        "Storm" => Ok(Weather::Rain),
+                        "Clear skies" => Ok(Weather::Sun),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "`{}` is not a variant of `{}`",
+                            s, "Weather"
+                        ))),
+                    }
+                }
+            }
+            impl ::std::fmt::Display for Weather {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+                }
+            }
+            impl From<Weather> for WeatherAsGdEnum {
+                fn from(value: Weather) -> WeatherAsGdEnum {
+                    match value {
+                        Weather::Sun => WeatherAsGdEnum::Sunny,
+                        Weather::Rain => WeatherAsGdEnum::Rain,
+                    }
+                }
+            }
+            impl From<WeatherAsGdEnum> for Weather {
+                fn from(value: WeatherAsGdEnum) -> Weather {
+                    match value {
+                        WeatherAsGdEnum::Sunny => Weather::Sun,
+                        WeatherAsGdEnum::Rain => Weather::Rain,
+                    }
+                }
+            }
+            impl Default for WeatherAsGdEnum {
+                fn default() -> Self {
+                    Weather::default().into()
+                }
+            }
+        }
+    };
+
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+// With `repr = int`, labels only change the hint: Godot still stores the discriminants.
+#[test]
+fn test_repr_int_display_labels() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_enum(repr = int)]
+        pub enum Priority {
+            #[default]
+            #[as_gd_enum(display = "Low priority")]
+            Low = 1,
+            High = 10,
+        }
+    };
+    let expected = quote! {
+        pub use mod_priorityasgdenum::*;
+        mod mod_priorityasgdenum {
+            use super::Priority;
+            use ::godot::prelude::GString;
+            #[derive(::godot::prelude::GodotConvert, Clone, Copy, Debug, PartialEq, Eq)]
+            #[godot(via = i64)]
+            pub enum PriorityAsGdEnum {
+                Low = 1,
+                High = 10,
+            }
+            impl ::godot::prelude::Var for PriorityAsGdEnum {
+                fn get_property(&self) -> i64 {
+                    ::godot::prelude::ToGodot::to_godot(self)
+                }
+                fn set_property(&mut self, value: i64) {
+                    match <Self as ::godot::prelude::FromGodot>::try_from_godot(value) {
+                        Ok(value) => *self = value,
+                        Err(err) => ::godot::global::godot_error!("{}", err),
+                    }
+                }
+                fn var_hint() -> ::godot::meta::PropertyHintInfo {
+                    ::as_gd_res::enum_property_hint("Low priority:1,High:10")
+                }
+            }
+            impl ::godot::prelude::Export for PriorityAsGdEnum {}
+            impl ::as_gd_res::AsGdEnumSimple for Priority {
+                type GdEnumType = PriorityAsGdEnum;
+                const ENUM_HINT: &'static str = "Low priority:1,High:10";
+                const STORED_TYPE: ::godot::builtin::VariantType = ::godot::builtin::VariantType::INT;
+            }
+            impl ::as_gd_res::AsGdRes for Priority {
+                type ResType = ::as_gd_res::GdEnumInt<Self>;
+            }
+            impl ::as_gd_res::ToGdRes for Priority {
+                fn to_gd_res(&self) -> Self::ResType {
+                    ::as_gd_res::GdEnumInt::new(*self)
+                }
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    ::as_gd_res::apply_by_value(res, ::as_gd_res::GdEnumInt::new(*self))
+                }
+            }
+            impl ::as_gd_res::FromGdRes for Priority {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract(res)
+                }
+            }
+            impl ::as_gd_res::AsGdResDictElement for Priority {
+                type GdElement = i64;
+                fn to_gd_element(&self) -> Self::GdElement {
+                    ::as_gd_res::simple_enum_to_gd_element(*self)
+                }
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::simple_enum_from_gd_element(element)
+                }
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    ::as_gd_res::apply_by_value(res, self.to_gd_element())
+                }
+                fn default_gd_element() -> ::godot::prelude::Variant {
+                    ::godot::prelude::ToGodot::to_variant(&Priority::default().to_gd_element())
+                }
+            }
+            impl ::as_gd_res::AsGdResArray for Priority {
+                type GdArray = ::as_gd_res::GdEnumArray<Self>;
+            }
+            impl ::as_gd_res::ToGdResArray for Priority {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    ::as_gd_res::GdEnumArray::new(
+                        values
+                            .into_iter()
+                            .map(::as_gd_res::AsGdResDictElement::to_gd_element)
+                            .collect(),
+                    )
+                }
+                fn apply_to_gd_array<'a>(
+                    values: impl IntoIterator<Item = &'a Self>,
+                    res: &mut Self::GdArray
+                ) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_array(values))
+                }
+            }
+            impl ::as_gd_res::FromGdResArray for Priority {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Vec<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract_with(res, ctx)
+                }
+            }
+            impl ::as_gd_res::ExtractGd for PriorityAsGdEnum {
+                type Extracted = Priority;
+                fn extract(&self) -> Self::Extracted {
+                    (*self).into()
+                }
+            }
+            impl ::as_gd_res::TryExtractGd for PriorityAsGdEnum {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok((*self).into())
+                }
+            }
+            impl ::as_gd_res::AsGdResOpt for Priority {
+                type GdOption = ::as_gd_res::GdEnumIntOpt<Self>;
+            }
+            impl ::as_gd_res::ToGdResOpt for Priority {
+                fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                    ::as_gd_res::GdEnumIntOpt::new(value.copied())
+                }
+                fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+                }
+            }
+            impl ::as_gd_res::FromGdResOpt for Priority {
+                fn try_from_gd_opt(
+                    res: &Self::GdOption,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract(res)
+                }
+            }
+            impl ::as_gd_res::tres::FromTres for Priority {
+                fn from_tres(
+                    value: &::as_gd_res::tres::TresValue,
+                    _doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    match value.as_int() {
+                        Some(1) => Ok(Priority::Low),
+                        Some(10) => Ok(Priority::High),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "expected a `{}` discriminant, found `{}`",
+                            "Priority", value
+                        ))),
+                    }
+                }
+                fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok(Priority::default())
+                }
+                fn from_tres_opt(
+                    value: &::as_gd_res::tres::TresValue,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    if matches!(value, ::as_gd_res::tres::TresValue::Null) || value.as_int() == Some(-1) {
+                        return Ok(None);
+                    }
+                    <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+                }
+            }
+            impl ::as_gd_res::tres::ToTres for Priority {
+                fn to_tres(
+                    &self,
+                    _writer: &mut ::as_gd_res::tres::TresWriter
+                ) -> ::as_gd_res::tres::TresValue {
+                    let stored = match *self {
+                        Priority::Low => 1,
+                        Priority::High => 10,
+                    };
+                    ::as_gd_res::tres::TresValue::Int(stored)
+                }
+                fn tres_type() -> String {
+                    "int".to_string()
+                }
+                fn to_tres_opt(
+                    value: Option<&Self>,
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> ::as_gd_res::tres::TresValue {
+                    match value {
+                        Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                        None => ::as_gd_res::tres::TresValue::Int(-1),
+                    }
+                }
+            }
+            impl Priority {
+                /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+                pub fn display_name(&self) -> &'static str {
+                    match *self {
+                        Priority::Low => "Low priority",
+                        Priority::High => "High",
+                    }
+                }
+            }
+            impl ::as_gd_res::SimpleEnumVariants for Priority {
+                const VARIANTS: &'static [Self] = &[Priority::Low, Priority::High,];
+                fn name(&self) -> &'static str {
+                    match *self {
+                        Priority::Low => "Low",
+                        Priority::High => "High",
+                    }
+                }
+                fn index(&self) -> usize {
+                    match *self {
+                        Priority::Low => 0,
+                        Priority::High => 1,
+                    }
+                }
+            }
+            impl ::std::str::FromStr for Priority {
+                type Err = ::as_gd_res::ExtractError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "Low" => Ok(Priority::Low),
+                        "High" => Ok(Priority::High),
+                        "Low priority" => Ok(Priority::Low),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "`{}` is not a variant of `{}`",
+                            s, "Priority"
+                        ))),
+                    }
+                }
+            }
+            impl ::std::fmt::Display for Priority {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+                }
+            }
+            impl From<Priority> for PriorityAsGdEnum {
+                fn from(value: Priority) -> PriorityAsGdEnum {
+                    match value {
+                        Priority::Low => PriorityAsGdEnum::Low,
+                        Priority::High => PriorityAsGdEnum::High,
+                    }
+                }
+            }
+            impl From<PriorityAsGdEnum> for Priority {
+                fn from(value: PriorityAsGdEnum) -> Priority {
+                    match value {
+                        PriorityAsGdEnum::Low => Priority::Low,
+                        PriorityAsGdEnum::High => Priority::High,
+                    }
+                }
+            }
+            impl Default for PriorityAsGdEnum {
+                fn default() -> Self {
+                    Priority::default().into()
+                }
+            }
+        }
+    };
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_display_label_separator_error() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Weather {
+            #[as_gd_enum(display = "Sun, clear")]
+            Sun,
+        }
+    };
+    let expected = quote! {
+        ::core::compile_error! { "`display` labels can't contain `,` or `:`" }
+    };
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_duplicate_godot_name_error() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Weather {
+            Sun,
+            #[as_gd_enum(rename = "Sun")]
+            Sunny,
+        }
+    };
+    let expected = quote! {
+        compile_error!("`Sun` is used as the Godot-side name or label of more than one variant");
+    };
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_invalid_godot_name_error() {
+    let input: syn::DeriveInput = parse_quote! {
+        pub enum Weather {
+            #[as_gd_enum(rename = "Partly cloudy")]
+            Cloudy,
+        }
+    };
+    let expected = quote! {
+        ::core::compile_error! { "Godot-side variant names must be valid identifiers" }
+    };
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_repr_int_alias_error() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_enum(repr = int)]
+        pub enum Priority {
+            #[as_gd_enum(alias = "Normal")]
+            Low,
+        }
+    };
+    let expected = quote! {
+        compile_error!("`alias` is not supported with `#[as_gd_enum(repr = int)]`, which stores discriminants");
+    };
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
+}
+
+#[test]
+fn test_variants_class() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[as_gd_enum(variants_class)]
+        pub enum Surface {
+            #[default]
+            Ground,
+            Ice,
+        }
+    };
+    let expected = quote! {
+        pub use mod_surfaceasgdenum::*;
+        mod mod_surfaceasgdenum {
+            use super::Surface;
+            use ::godot::prelude::GString;
+            #[derive(::godot::prelude::GodotConvert, ::godot::prelude::Var, ::godot::prelude::Export, Clone, Copy, Debug, PartialEq, Eq)]
+            #[godot(via = GString)]
+            pub enum SurfaceAsGdEnum {
+                Ground,
+                Ice,
+            }
+            impl ::as_gd_res::AsGdEnumSimple for Surface {
+                type GdEnumType = SurfaceAsGdEnum;
+                const ENUM_HINT: &'static str = "Ground,Ice";
+                const STORED_TYPE: ::godot::builtin::VariantType = ::godot::builtin::VariantType::STRING;
+            }
+            impl ::as_gd_res::AsGdRes for Surface {
+                type ResType = SurfaceAsGdEnum;
+            }
+            impl ::as_gd_res::ToGdRes for Surface {
+                fn to_gd_res(&self) -> Self::ResType {
+                    (*self).into()
+                }
+                fn apply_to_gd_res(&self, res: &mut Self::ResType) -> bool {
+                    ::as_gd_res::apply_by_value(res, (*self).into())
+                }
+            }
+            impl ::as_gd_res::FromGdRes for Surface {
+                fn try_from_gd_res_with(
+                    res: &Self::ResType,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok((*res).into())
+                }
+            }
+            impl ::as_gd_res::AsGdResDictElement for Surface {
+                type GdElement = GString;
+                fn to_gd_element(&self) -> Self::GdElement {
+                    ::as_gd_res::simple_enum_to_gd_element(*self)
+                }
+                fn try_from_gd_element(
+                    element: &Self::GdElement,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::simple_enum_from_gd_element(element)
+                }
+                fn apply_to_gd_element(&self, res: &mut Self::GdElement) -> bool {
+                    ::as_gd_res::apply_by_value(res, self.to_gd_element())
+                }
+                fn default_gd_element() -> ::godot::prelude::Variant {
+                    ::godot::prelude::ToGodot::to_variant(&Surface::default().to_gd_element())
+                }
+            }
+            impl ::as_gd_res::AsGdResArray for Surface {
+                type GdArray = ::as_gd_res::GdEnumArray<Self>;
+            }
+            impl ::as_gd_res::ToGdResArray for Surface {
+                fn to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>) -> Self::GdArray {
+                    ::as_gd_res::GdEnumArray::new(
+                        values.into_iter().map(::as_gd_res::AsGdResDictElement::to_gd_element).collect(),
+                    )
+                }
+                fn apply_to_gd_array<'a>(values: impl IntoIterator<Item = &'a Self>, res: &mut Self::GdArray) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_array(values))
+                }
+            }
+            impl ::as_gd_res::FromGdResArray for Surface {
+                fn try_from_gd_array(
+                    res: &Self::GdArray,
+                    ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Vec<Self>, ::as_gd_res::ExtractError> {
+                    ::as_gd_res::TryExtractGd::try_extract_with(res, ctx)
+                }
+            }
+            impl ::as_gd_res::ExtractGd for SurfaceAsGdEnum {
+                type Extracted = Surface;
+                fn extract(&self) -> Self::Extracted {
+                    (*self).into()
+                }
+            }
+            impl ::as_gd_res::TryExtractGd for SurfaceAsGdEnum {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok((*self).into())
+                }
+            }
+            #[derive(::godot::prelude::GodotConvert, ::godot::prelude::Var, ::godot::prelude::Export, Clone, Copy, Debug, PartialEq, Eq, Default)]
+            #[godot(via = GString)]
+            pub enum SurfaceAsGdEnumOpt {
+                #[default]
+                None,
+                Ground,
+                Ice,
+            }
+            impl ::as_gd_res::AsGdResOpt for Surface {
+                type GdOption = SurfaceAsGdEnumOpt;
+            }
+            impl ::as_gd_res::ToGdResOpt for Surface {
+                fn to_gd_opt(value: Option<&Self>) -> Self::GdOption {
+                    match value.copied() {
+                        Some(Surface::Ground) => SurfaceAsGdEnumOpt::Ground,
+                        Some(Surface::Ice) => SurfaceAsGdEnumOpt::Ice,
+                        None => SurfaceAsGdEnumOpt::None,
+                    }
+                }
+                fn apply_to_gd_opt(value: Option<&Self>, res: &mut Self::GdOption) -> bool {
+                    ::as_gd_res::apply_by_value(res, Self::to_gd_opt(value))
+                }
+            }
+            impl ::as_gd_res::FromGdResOpt for Surface {
+                fn try_from_gd_opt(
+                    res: &Self::GdOption,
+                    _ctx: &mut ::as_gd_res::ExtractContext,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(res))
+                }
+            }
+            impl ::as_gd_res::ExtractGd for SurfaceAsGdEnumOpt {
+                type Extracted = Option<Surface>;
+                fn extract(&self) -> Self::Extracted {
+                    match *self {
+                        SurfaceAsGdEnumOpt::None => None,
+                        SurfaceAsGdEnumOpt::Ground => Some(Surface::Ground),
+                        SurfaceAsGdEnumOpt::Ice => Some(Surface::Ice),
+                    }
+                }
+            }
+            impl ::as_gd_res::TryExtractGd for SurfaceAsGdEnumOpt {
+                fn try_extract(&self) -> Result<Self::Extracted, ::as_gd_res::ExtractError> {
+                    Ok(::as_gd_res::ExtractGd::extract(self))
+                }
+            }
+            impl ::as_gd_res::tres::FromTres for Surface {
+                fn from_tres(
+                    value: &::as_gd_res::tres::TresValue,
+                    _doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Self, ::as_gd_res::ExtractError> {
+                    match value.as_str() {
+                        Some("Ground") => Ok(Surface::Ground),
+                        Some("Ice") => Ok(Surface::Ice),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "expected a `{}` variant name, found `{}`",
+                            "Surface", value
+                        ))),
+                    }
+                }
+                fn from_tres_missing() -> Result<Self, ::as_gd_res::ExtractError> {
+                    Ok(Surface::default())
+                }
+                fn from_tres_opt(
+                    value: &::as_gd_res::tres::TresValue,
+                    doc: &::as_gd_res::tres::TresDocument,
+                ) -> Result<Option<Self>, ::as_gd_res::ExtractError> {
+                    if matches!(value, ::as_gd_res::tres::TresValue::Null) || value.as_str() == Some("None")
+                    {
+                        return Ok(None);
+                    }
+                    <Self as ::as_gd_res::tres::FromTres>::from_tres(value, doc).map(Some)
+                }
+            }
+            impl ::as_gd_res::tres::ToTres for Surface {
+                fn to_tres(&self, _writer: &mut ::as_gd_res::tres::TresWriter) -> ::as_gd_res::tres::TresValue {
+                    let stored = match *self {
+                        Surface::Ground => "Ground",
+                        Surface::Ice => "Ice",
+                    };
+                    ::as_gd_res::tres::TresValue::String(stored.to_string())
+                }
+                fn tres_type() -> String {
+                    "String".to_string()
+                }
+                fn to_tres_opt(
+                    value: Option<&Self>,
+                    writer: &mut ::as_gd_res::tres::TresWriter,
+                ) -> ::as_gd_res::tres::TresValue {
+                    match value {
+                        Some(value) => <Self as ::as_gd_res::tres::ToTres>::to_tres(value, writer),
+                        None => ::as_gd_res::tres::TresValue::String("None".to_string()),
+                    }
+                }
+            }
+            impl Surface {
+                /// The label from `#[as_gd_enum(display = "...")]`, or the Godot-side name.
+                pub fn display_name(&self) -> &'static str {
+                    match *self {
+                        Surface::Ground => "Ground",
+                        Surface::Ice => "Ice",
+                    }
+                }
+            }
+            impl ::as_gd_res::SimpleEnumVariants for Surface {
+                const VARIANTS: &'static [Self] = &[Surface::Ground, Surface::Ice,];
+                fn name(&self) -> &'static str {
+                    match *self {
+                        Surface::Ground => "Ground",
+                        Surface::Ice => "Ice",
+                    }
+                }
+                fn index(&self) -> usize {
+                    match *self {
+                        Surface::Ground => 0,
+                        Surface::Ice => 1,
+                    }
+                }
+            }
+            impl ::std::str::FromStr for Surface {
+                type Err = ::as_gd_res::ExtractError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "Ground" => Ok(Surface::Ground),
+                        "Ice" => Ok(Surface::Ice),
+                        _ => Err(::as_gd_res::ExtractError::invalid(format!(
+                            "`{}` is not a variant of `{}`",
+                            s, "Surface"
+                        ))),
+                    }
+                }
+            }
+            impl ::std::fmt::Display for Surface {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(::as_gd_res::SimpleEnumVariants::name(self))
+                }
+            }
+            use ::godot::classes::RefCounted;
+            #[derive(::godot::prelude::GodotClass)]
+            #[class(init, base = RefCounted)]
+            pub struct SurfaceAsGdEnumVariants {}
+            #[::godot::prelude::godot_api]
+            impl SurfaceAsGdEnumVariants {
+                /// The Godot-side names of all variants, in declaration order.
+                #[func]
+                fn names() -> ::godot::prelude::PackedStringArray {
+                    <Surface as ::as_gd_res::SimpleEnumVariants>::VARIANTS
+                        .iter()
+                        .map(|v| GString::from(::as_gd_res::SimpleEnumVariants::name(v)))
+                        .collect()
+                }
+                /// The display labels of all variants, in declaration order.
+                #[func]
+                fn display_names() -> ::godot::prelude::PackedStringArray {
+                    <Surface as ::as_gd_res::SimpleEnumVariants>::VARIANTS
+                        .iter()
+                        .map(|v| GString::from(v.display_name()))
+                        .collect()
+                }
+                /// The index of the variant with this name, alias or label, or -1.
+                #[func]
+                fn index_of(name: GString) -> i64 {
+                    name.to_string()
+                        .parse::<Surface>()
+                        .map_or(-1, |v| ::as_gd_res::SimpleEnumVariants::index(&v) as i64)
+                }
+                /// The name of the variant at `index`, or an empty string.
+                #[func]
+                fn name_at(index: i64) -> GString {
+                    usize::try_from(index)
+                        .ok()
+                        .and_then(<Surface as ::as_gd_res::SimpleEnumVariants>::from_index)
+                        .map_or_else(GString::new, |v| GString::from(::as_gd_res::SimpleEnumVariants::name(&v)))
+                }
+            }
+            impl From<Surface> for SurfaceAsGdEnum {
+                fn from(value: Surface) -> SurfaceAsGdEnum {
+                    match value {
+                        Surface::Ground => SurfaceAsGdEnum::Ground,
+                        Surface::Ice => SurfaceAsGdEnum::Ice,
+                    }
+                }
+            }
+            impl From<SurfaceAsGdEnum> for Surface {
+                fn from(value: SurfaceAsGdEnum) -> Surface {
+                    match value {
+                        SurfaceAsGdEnum::Ground => Surface::Ground,
+                        SurfaceAsGdEnum::Ice => Surface::Ice,
+                    }
+                }
+            }
+            impl Default for SurfaceAsGdEnum {
+                fn default() -> Self {
+                    Surface::default().into()
+                }
+            }
+        }
+    };
+
+    assert_eq!(
+        expand_as_gd_res(input, true).to_string(),
+        expected.to_string()
+    );
 }
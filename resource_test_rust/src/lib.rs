@@ -1,6 +1,8 @@
 use as_gd_res::AsGdEnumSimple;
 use as_gd_res::AsGdRes;
 use as_gd_res::ExtractGd;
+use as_gd_res::ToGdRes;
+use as_gd_res::TryExtractGd;
 use as_gd_res::engine_type_impls::NodePathString;
 use as_gd_res::engine_type_impls::PackedScenePath;
 use as_gd_res::engine_type_impls::RustCurve;
@@ -10,9 +12,10 @@ use godot::{
     classes::INode,
     global::godot_print,
     init::ExtensionLibrary,
-    obj::WithBaseField,
-    prelude::{Base, GodotClass, Node, gdextension, godot_api},
+    obj::{NewGd, WithBaseField},
+    prelude::{Base, Gd, GodotClass, Node, RefCounted, ToGodot, Variant, gdextension, godot_api},
 };
+use std::sync::Arc;
 
 struct ResourceTestExtension;
 
@@ -79,10 +82,87 @@ impl INode for TestNode {
             self.deeper_nested_generic_struct.extract()
         );
 
+        let fixture = SimpleData {
+            name: "built in rust".to_string(),
+            value: 7,
+            int_vec: vec![5, 6],
+            path_to_node: NodePathString("ChildNode1".to_string()),
+        };
+        godot_print!(
+            "simple_data_round_trip: {:#?}",
+            fixture.to_gd_res().extract()
+        );
+
+        // Two fields holding the same resource extract to one shared `Arc`.
+        let mut pair = SharedPairResource::from_rust(&SharedPair {
+            first: Arc::new(fixture.clone()),
+            second: Arc::new(fixture.clone()),
+        });
+        let first = pair.get("first");
+        pair.set("second", &first);
+        let shared = pair.bind().extract();
+        godot_print!(
+            "shared_sub_resource_is_one_arc: {}",
+            Arc::ptr_eq(&shared.first, &shared.second)
+        );
+
+        // A resource that contains itself is reported instead of overflowing the stack.
+        let mut chain = ChainResource::from_rust(&Chain {
+            value: 1,
+            next: None,
+        });
+        let itself = chain.to_variant();
+        chain.set("next", &itself);
+        let cycle = chain.bind().try_extract().map_err(|e| e.kind().clone());
+        godot_print!("self_containing_resource: {:?}", cycle);
+        chain.set("next", &Variant::nil());
+
+        // Applying an unchanged value keeps the sub-resources and emits nothing; a
+        // changed field emits `changed` once, on the resource that holds it.
+        let mut pair = SharedPairResource::from_rust(&shared);
+        let sub_resource_ids = |pair: &Gd<SharedPairResource>| {
+            ["first", "second"]
+                .map(|field| pair.get(field).to::<Gd<SimpleDataResource>>().instance_id())
+        };
+        let ids_before = sub_resource_ids(&pair);
+        let counter = ChangedCounter::new_gd();
+        pair.connect("changed", &counter.callable("on_changed"));
+        shared.apply_to(&mut pair);
+        godot_print!(
+            "apply_unchanged_keeps_sub_resources: {}",
+            sub_resource_ids(&pair) == ids_before
+        );
+        godot_print!("apply_unchanged_changed_count: {}", counter.bind().count);
+
+        let mut data = SimpleDataResource::from_rust(&fixture);
+        let counter = ChangedCounter::new_gd();
+        data.connect("changed", &counter.callable("on_changed"));
+        SimpleData {
+            value: 8,
+            ..fixture.clone()
+        }
+        .apply_to(&mut data);
+        godot_print!("apply_changed_changed_count: {}", counter.bind().count);
+
         self.base().get_tree().quit();
     }
 }
 
+/// Counts the `changed` signals of the resources it is connected to.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ChangedCounter {
+    count: u32,
+}
+
+#[godot_api]
+impl ChangedCounter {
+    #[func]
+    fn on_changed(&mut self) {
+        self.count += 1;
+    }
+}
+
 /////////// Simple struct
 #[derive(AsGdRes, Debug, Clone)]
 pub struct SimpleData {
@@ -93,9 +173,11 @@ pub struct SimpleData {
 }
 
 /////////// Simple enum
-// NOTE: godot-rust currently doesn't support wrapping enums in Array<_> or Option<_>. (If you want an enum to be optional, you can include a 'None' variant)
+// NOTE: `Option<SimpleEnum>` gets an extra "None" choice in the inspector, and `Vec<SimpleEnum>` is an array of variant names.
+// `variants_class` registers `SimpleEnumAsGdEnumVariants`, so GDScript can call e.g. `SimpleEnumAsGdEnumVariants.names()`.
 
 #[derive(AsGdEnumSimple, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[as_gd_enum(variants_class)]
 pub enum SimpleEnum {
     #[default]
     Fire,
@@ -104,6 +186,15 @@ pub enum SimpleEnum {
     Air,
 }
 
+// NOTE: stored as the discriminant, so a value that isn't a variant survives until `extract()` reports it.
+#[derive(AsGdEnumSimple, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[as_gd_enum(repr = int)]
+pub enum Priority {
+    #[default]
+    Low = 10,
+    High,
+}
+
 /////////// Enum with data
 
 #[derive(AsGdRes, Debug, Clone)]
@@ -130,6 +221,15 @@ pub enum Pickup {
     Heal(HealData),
 }
 
+// NOTE: the unit variant `Nothing` is stored as an empty `LootNothingResource`, and `Chest` as a
+// `LootChestResource` with `gold` and `locked` properties.
+#[derive(AsGdRes, Debug, Clone)]
+pub enum Loot {
+    Nothing,
+    Money(MoneyData),
+    Chest { gold: i32, locked: bool },
+}
+
 /////////// Complicated struct
 #[derive(AsGdRes, Clone, Debug)]
 pub struct Complicated {
@@ -287,3 +387,154 @@ pub struct StructWithDeeperGenericNest<T1, T2> {
     pub nested1: Option<StructWithGenerics<T1, T2>>,
     pub nested2: Option<StructWithGenericsNested<T1, T2>>,
 }
+
+#[derive(AsGdRes, Clone, Debug)]
+pub struct Slot {
+    pub item: String,
+    pub count: i32,
+}
+
+#[derive(AsGdRes, Clone, Debug)]
+#[as_gd_res_types(N = 4)]
+pub struct Loadout<const N: usize> {
+    pub slots: [Slot; N],
+}
+
+/////////// Shared and self-containing resources
+
+#[derive(AsGdRes, Clone, Debug)]
+pub struct SharedPair {
+    pub first: Arc<SimpleData>,
+    pub second: Arc<SimpleData>,
+}
+
+#[derive(AsGdRes, Clone, Debug)]
+pub struct Chain {
+    pub value: i32,
+    pub next: Option<Box<Chain>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROJECT_DIR: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../resource_test_godot_project"
+    );
+
+    // Reading the resource offline should give exactly what `extract()` prints inside Godot.
+    #[test]
+    fn load_complicated_resource_without_godot() {
+        let complicated: Complicated =
+            as_gd_res::tres::load_tres(format!("{}/test_complicated_resource.tres", PROJECT_DIR))
+                .unwrap();
+        let expected =
+            std::fs::read_to_string(format!("{}/rust_print_output_expected.txt", PROJECT_DIR))
+                .unwrap();
+        let printed = format!("crazy_nested_resource: {:#?}\n", complicated);
+        assert!(expected.contains(&printed), "{}", printed);
+    }
+
+    #[test]
+    fn write_complicated_resource_without_godot() {
+        let complicated: Complicated =
+            as_gd_res::tres::load_tres(format!("{}/test_complicated_resource.tres", PROJECT_DIR))
+                .unwrap();
+        let text = as_gd_res::tres::to_tres_string(&complicated);
+        let mut reloaded: Complicated = as_gd_res::tres::from_tres_str(&text).unwrap();
+
+        // Curves are re-sampled when read back, so they only match approximately.
+        let curves = |c: &Complicated| {
+            let mut curves = vec![c.curve.clone()];
+            curves.extend(c.curve_option.clone());
+            curves.extend(c.curve_array.clone());
+            curves
+        };
+        for (a, b) in curves(&complicated).iter().zip(curves(&reloaded)) {
+            for i in 0..=10 {
+                let x = i as f32 / 10.0;
+                assert!((a.try_sample(x).unwrap() - b.try_sample(x).unwrap()).abs() < 1e-4);
+            }
+        }
+        reloaded.curve = complicated.curve.clone();
+        reloaded.curve_option = complicated.curve_option.clone();
+        reloaded.curve_array = complicated.curve_array.clone();
+        assert_eq!(format!("{:#?}", reloaded), format!("{:#?}", complicated));
+    }
+
+    #[test]
+    fn const_generic_loadout_round_trips() {
+        let loadout = Loadout::<4> {
+            slots: std::array::from_fn(|i| Slot {
+                item: format!("item_{}", i),
+                count: i as i32,
+            }),
+        };
+        let text = as_gd_res::tres::to_tres_string(&loadout);
+        let reloaded: Loadout<4> = as_gd_res::tres::from_tres_str(&text).unwrap();
+        assert_eq!(format!("{:?}", reloaded), format!("{:?}", loadout));
+
+        // A `[Slot; N]` field only accepts exactly `N` elements.
+        let three = text.replacen(", SubResource(\"SlotResource_4\")", "", 1);
+        assert!(as_gd_res::tres::from_tres_str::<Loadout<4>>(&three).is_err());
+    }
+
+    #[test]
+    fn unit_and_struct_variants_round_trip() {
+        let chest = Loot::Chest {
+            gold: 30,
+            locked: true,
+        };
+        let text = as_gd_res::tres::to_tres_string(&chest);
+        assert!(
+            text.contains("[gd_resource type=\"LootChestResource\""),
+            "{}",
+            text
+        );
+        assert!(text.contains("gold = 30\nlocked = true"), "{}", text);
+
+        for loot in [Loot::Nothing, Loot::Money(MoneyData { value: 5 }), chest] {
+            let text = as_gd_res::tres::to_tres_string(&loot);
+            let reloaded: Loot = as_gd_res::tres::from_tres_str(&text).unwrap();
+            assert_eq!(format!("{:?}", reloaded), format!("{:?}", loot));
+        }
+    }
+
+    #[test]
+    fn unknown_int_enum_discriminants_fail_to_extract() {
+        use as_gd_res::{GdEnumInt, GdEnumIntOpt};
+
+        assert_eq!(
+            GdEnumInt::new(Priority::High).try_extract().unwrap(),
+            Priority::High
+        );
+        let err = GdEnumInt::<Priority>::from_discriminant(99)
+            .try_extract()
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("discriminant, found `99`"),
+            "{}",
+            err
+        );
+
+        // Only `-1` and the variants' discriminants are valid for `Option<Priority>`.
+        assert_eq!(
+            GdEnumIntOpt::<Priority>::from_discriminant(-1)
+                .try_extract()
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            GdEnumIntOpt::<Priority>::from_discriminant(11)
+                .try_extract()
+                .unwrap(),
+            Some(Priority::High)
+        );
+        assert!(
+            GdEnumIntOpt::<Priority>::from_discriminant(99)
+                .try_extract()
+                .is_err()
+        );
+    }
+}